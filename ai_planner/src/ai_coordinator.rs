@@ -1,7 +1,8 @@
 use crate::constants::coordinator::{cooldowns, costs, defense, diplomacy, territory, trade};
 use crate::{AIAction, AICoordinator};
 use core_sim::{
-    BuildingType, CivId, DiplomaticAction, GameResource as Resource, GameState, UnitType,
+    BuildingRegistry, BuildingType, CivId, DiplomaticAction, GameResource as Resource, GameState,
+    UnitRegistry, UnitType,
 };
 use std::collections::HashMap;
 
@@ -66,12 +67,20 @@ impl AICoordinatorSystem {
         &self,
         decisions: &HashMap<CivId, Vec<AIAction>>,
         game_state: &mut GameState,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
     ) -> Vec<ExecutionResult> {
         let mut results = Vec::new();
 
         for (civ_id, actions) in decisions {
             for action in actions {
-                let result = self.execute_single_action(*civ_id, action, game_state);
+                let result = self.execute_single_action(
+                    *civ_id,
+                    action,
+                    game_state,
+                    unit_registry,
+                    building_registry,
+                );
                 results.push(result);
             }
         }
@@ -84,6 +93,8 @@ impl AICoordinatorSystem {
         civ_id: CivId,
         action: &AIAction,
         game_state: &mut GameState,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
     ) -> ExecutionResult {
         match action {
             AIAction::Expand {
@@ -96,15 +107,27 @@ impl AICoordinatorSystem {
                 unit_type,
                 position,
                 ..
-            } => self.execute_build_unit(civ_id, unit_type.clone(), *position, game_state),
+            } => self.execute_build_unit(
+                civ_id,
+                unit_type.clone(),
+                *position,
+                game_state,
+                unit_registry,
+            ),
             AIAction::BuildBuilding {
                 building_type,
                 position,
                 ..
-            } => self.execute_build_building(civ_id, building_type.clone(), *position, game_state),
+            } => self.execute_build_building(
+                civ_id,
+                building_type.clone(),
+                *position,
+                game_state,
+                building_registry,
+            ),
             AIAction::Trade {
                 partner, resource, ..
-            } => self.execute_trade(civ_id, *partner, resource.clone(), game_state),
+            } => self.execute_trade(civ_id, *partner, *resource, game_state),
             AIAction::Attack {
                 target,
                 target_position,
@@ -173,9 +196,10 @@ impl AICoordinatorSystem {
         unit_type: UnitType,
         position: core_sim::Position,
         game_state: &mut GameState,
+        unit_registry: &UnitRegistry,
     ) -> ExecutionResult {
         if let Some(civ_data) = game_state.civilizations.get_mut(&civ_id) {
-            let cost = costs::BASE_UNIT_COST;
+            let cost = unit_registry.definition(&unit_type).gold_cost;
 
             if civ_data.civilization.economy.gold >= cost {
                 civ_data.civilization.economy.gold -= cost;
@@ -185,6 +209,7 @@ impl AICoordinatorSystem {
                     civ_id,
                     unit_type.clone(),
                     position,
+                    unit_registry,
                 );
 
                 civ_data.civilization.military.units.push(unit.clone());
@@ -215,28 +240,27 @@ impl AICoordinatorSystem {
         building_type: BuildingType,
         _position: core_sim::Position,
         game_state: &mut GameState,
+        building_registry: &BuildingRegistry,
     ) -> ExecutionResult {
         if let Some(civ_data) = game_state.civilizations.get_mut(&civ_id) {
-            let cost = costs::BASE_BUILDING_COST;
+            let cost = building_registry.definition(&building_type).gold_cost;
 
             if civ_data.civilization.economy.gold >= cost {
-                civ_data.civilization.economy.gold -= cost;
-
                 for city in &mut civ_data.cities {
-                    city.buildings.push(core_sim::Building {
-                        building_type: building_type.clone(),
-                        level: 1,
-                    });
+                    if !city.add_building(building_type.clone(), building_registry) {
+                        continue;
+                    }
+                    civ_data.civilization.economy.gold -= cost;
 
                     return ExecutionResult::Success {
                         civ_id,
-                        action_description: format!("Built {:?} in {}", building_type, city.name),
+                        action_description: format!("Built {} in {}", building_type, city.name),
                     };
                 }
 
                 ExecutionResult::Failed {
                     civ_id,
-                    reason: "No city found for building".to_string(),
+                    reason: "No city can build this building".to_string(),
                 }
             } else {
                 ExecutionResult::Failed {
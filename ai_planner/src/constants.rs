@@ -15,6 +15,8 @@ pub mod utility {
         pub const AVAILABLE_TILES_STUB: i32 = 4; // Placeholder for available expansion tiles
         pub const MAX_EXPANSION_FACTOR: f32 = 8.0; // Divisor for land hunger calculation
         pub const PROXIMITY_THRESHOLD: f32 = 20.0; // Distance threshold for nearby civilizations
+        pub const MISSING_RESOURCE_UTILITY_BONUS: f32 = 0.25; // Added when a lacking resource is in reach
+        pub const RESOURCE_SITE_SEARCH_DISTANCE: i32 = 12; // Max distance from the capital to settle for a resource
     }
 
     /// Economic evaluation constants
@@ -165,8 +167,6 @@ pub mod coordinator {
     /// Economic costs for various actions
     pub mod costs {
         pub const BASE_RESEARCH_COST: f32 = 50.0;
-        pub const BASE_UNIT_COST: f32 = 30.0;
-        pub const BASE_BUILDING_COST: f32 = 25.0;
     }
 
     /// Military unit default statistics
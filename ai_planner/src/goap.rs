@@ -427,7 +427,7 @@ impl GOAPAction {
                 })
             }
             GOAPActionType::BuildMilitary => Some(AIAction::BuildUnit {
-                unit_type: UnitType::Infantry,
+                unit_type: UnitType::new("Infantry"),
                 position: capital,
                 priority: 1.0 - self.cost / 10.0,
             }),
@@ -445,7 +445,7 @@ impl GOAPAction {
                 None
             }
             GOAPActionType::BuildEconomic => Some(AIAction::BuildBuilding {
-                building_type: BuildingType::Market,
+                building_type: BuildingType::new("Market"),
                 position: capital,
                 priority: 1.0 - self.cost / 10.0,
             }),
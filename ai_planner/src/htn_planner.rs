@@ -125,7 +125,7 @@ impl HTNPlanner {
         match action_type {
             PrimitiveActionType::BuildArmy => {
                 Some(AIAction::BuildUnit {
-                    unit_type: UnitType::Infantry,
+                    unit_type: UnitType::new("Infantry"),
                     position: capital,
                     priority: priorities::ESTABLISH_CITY_PRIORITY,
                 })
@@ -159,7 +159,7 @@ impl HTNPlanner {
             }
             PrimitiveActionType::BuildInfrastructure => {
                 Some(AIAction::BuildBuilding {
-                    building_type: BuildingType::Workshop,
+                    building_type: BuildingType::new("Workshop"),
                     position: capital,
                     priority: 0.6,
                 })
@@ -69,29 +69,42 @@ impl UtilityAI {
         vec![
             UtilityFunction::new(
                 "expand_territory",
-                Box::new(|_civ_id, civ_data, _game_state| {
+                Box::new(|_civ_id, civ_data, game_state| {
                     let personality = &civ_data.civilization.personality;
                     let land_hunger = personality.land_hunger;
 
-                    let _capital = civ_data.civilization.capital.unwrap_or(Position::new(
+                    let capital = civ_data.civilization.capital.unwrap_or(Position::new(
                         defaults::DEFAULT_CAPITAL_X,
                         defaults::DEFAULT_CAPITAL_Y,
                     ));
                     let available_tiles = expansion::AVAILABLE_TILES_STUB;
 
-                    land_hunger
-                        * (available_tiles as f32 / expansion::MAX_EXPANSION_FACTOR)
-                            .min(thresholds::MAX_UTILITY_SCORE)
+                    // Land holding a resource the civilization lacks is worth settling for
+                    let resource_bonus =
+                        if nearest_missing_resource_site(civ_data, game_state, capital).is_some() {
+                            expansion::MISSING_RESOURCE_UTILITY_BONUS
+                        } else {
+                            0.0
+                        };
+
+                    (land_hunger * (available_tiles as f32 / expansion::MAX_EXPANSION_FACTOR)
+                        + resource_bonus)
+                        .min(thresholds::MAX_UTILITY_SCORE)
                 }),
-                Box::new(|_civ_id, civ_data, _game_state, utility| {
-                    let _capital = civ_data.civilization.capital.unwrap_or(Position::new(
+                Box::new(|_civ_id, civ_data, game_state, utility| {
+                    let capital = civ_data.civilization.capital.unwrap_or(Position::new(
                         defaults::DEFAULT_CAPITAL_X,
                         defaults::DEFAULT_CAPITAL_Y,
                     ));
-                    let available_positions = vec![Position::new(
-                        defaults::DEFAULT_CAPITAL_X,
-                        defaults::DEFAULT_CAPITAL_Y + defaults::DEFAULT_EXPANSION_Y_OFFSET,
-                    )];
+                    let available_positions = match nearest_missing_resource_site(
+                        civ_data, game_state, capital,
+                    ) {
+                        Some(resource_site) => vec![resource_site],
+                        None => vec![Position::new(
+                            defaults::DEFAULT_CAPITAL_X,
+                            defaults::DEFAULT_CAPITAL_Y + defaults::DEFAULT_EXPANSION_Y_OFFSET,
+                        )],
+                    };
 
                     if let Some(&target) = available_positions.first() {
                         Some(AIAction::Expand {
@@ -181,9 +194,9 @@ impl UtilityAI {
                     let unit_type = if civ_data.civilization.military.units.len()
                         < military::INITIAL_UNIT_COUNT_THRESHOLD
                     {
-                        UnitType::Infantry
+                        UnitType::new("Infantry")
                     } else {
-                        UnitType::Archer
+                        UnitType::new("Archer")
                     };
 
                     Some(AIAction::BuildUnit {
@@ -219,11 +232,11 @@ impl UtilityAI {
                     let building_type = if civ_data.cities.iter().any(|city| {
                         city.buildings
                             .iter()
-                            .any(|b| matches!(b.building_type, BuildingType::Market))
+                            .any(|b| b.building_type.name() == "Market")
                     }) {
-                        BuildingType::Workshop
+                        BuildingType::new("Workshop")
                     } else {
-                        BuildingType::Market
+                        BuildingType::new("Market")
                     };
 
                     Some(AIAction::BuildBuilding {
@@ -284,7 +297,8 @@ impl UtilityAI {
                     if let Some(partner) = best_partner {
                         Some(AIAction::Trade {
                             partner,
-                            resource: Resource::Gold,
+                            resource: most_valuable_resource(civ_data, game_state)
+                                .unwrap_or(Resource::Gold),
                             priority: utility,
                         })
                     } else {
@@ -366,6 +380,35 @@ impl UtilityAI {
     }
 }
 
+/// The unclaimed resource site closest to `origin` holding a resource the civilization
+/// does not have access to yet
+fn nearest_missing_resource_site(
+    civ_data: &CivilizationData,
+    game_state: &GameState,
+    origin: Position,
+) -> Option<Position> {
+    game_state
+        .resource_sites
+        .iter()
+        .filter(|(_, resource)| !civ_data.civilization.economy.has_resource(*resource))
+        .map(|(position, _)| (*position, origin.manhattan_distance_to(position)))
+        .filter(|(_, distance)| *distance <= expansion::RESOURCE_SITE_SEARCH_DISTANCE)
+        .min_by_key(|(position, distance)| (*distance, position.x, position.y))
+        .map(|(position, _)| position)
+}
+
+/// The resource the civilization has access to that fetches the highest market price
+fn most_valuable_resource(civ_data: &CivilizationData, game_state: &GameState) -> Option<Resource> {
+    Resource::ALL
+        .into_iter()
+        .filter(|resource| civ_data.civilization.economy.has_resource(*resource))
+        .max_by(|a, b| {
+            let price_a = game_state.resource_prices.get(a).copied().unwrap_or_default();
+            let price_b = game_state.resource_prices.get(b).copied().unwrap_or_default();
+            price_a.total_cmp(&price_b)
+        })
+}
+
 impl Default for UtilityAI {
     fn default() -> Self {
         Self::new()
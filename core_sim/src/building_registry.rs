@@ -0,0 +1,156 @@
+use crate::constants::buildings;
+use crate::{BuildingType, Economy, GameResource, Technologies};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single entry of `buildings.ron`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildingDefinition {
+    pub name: String,
+    pub description: String,
+    pub gold_cost: f32,
+    pub production_cost: f32,
+    /// Gold paid each turn per building level
+    pub upkeep: f32,
+    pub production_bonus: f32,
+    pub defense_bonus: f32,
+    pub food_yield: f32,
+    pub gold_yield: f32,
+    pub research_yield: f32,
+    /// Share of the base bonuses each level past the first adds
+    #[serde(default = "default_level_scaling")]
+    pub level_scaling: f32,
+    #[serde(default = "default_max_level")]
+    pub max_level: u32,
+    #[serde(default)]
+    pub required_technologies: Vec<String>,
+    /// Strategic resources the civilization must own a source of
+    #[serde(default)]
+    pub required_resources: Vec<GameResource>,
+    /// Fortifications count towards a city's siege defense
+    #[serde(default)]
+    pub fortification: bool,
+    /// Share of the food a city needs to grow that this building saves
+    #[serde(default)]
+    pub growth_threshold_reduction: f32,
+}
+
+fn default_level_scaling() -> f32 {
+    1.0
+}
+
+fn default_max_level() -> u32 {
+    1
+}
+
+impl BuildingDefinition {
+    /// Multiplier applied to the base bonuses and yields at `level`
+    pub fn level_multiplier(&self, level: u32) -> f32 {
+        1.0 + level.saturating_sub(1) as f32 * self.level_scaling
+    }
+
+    pub fn production_bonus_at(&self, level: u32) -> f32 {
+        self.production_bonus * self.level_multiplier(level)
+    }
+
+    pub fn defense_bonus_at(&self, level: u32) -> f32 {
+        self.defense_bonus * self.level_multiplier(level)
+    }
+
+    pub fn food_yield_at(&self, level: u32) -> f32 {
+        self.food_yield * self.level_multiplier(level)
+    }
+
+    pub fn gold_yield_at(&self, level: u32) -> f32 {
+        self.gold_yield * self.level_multiplier(level)
+    }
+
+    pub fn research_yield_at(&self, level: u32) -> f32 {
+        self.research_yield * self.level_multiplier(level)
+    }
+
+    pub fn growth_threshold_reduction_at(&self, level: u32) -> f32 {
+        self.growth_threshold_reduction * self.level_multiplier(level)
+    }
+
+    pub fn upkeep_at(&self, level: u32) -> f32 {
+        self.upkeep * level as f32
+    }
+}
+
+/// Stats for building types the registry does not know about
+static FALLBACK_BUILDING: BuildingDefinition = BuildingDefinition {
+    name: String::new(),
+    description: String::new(),
+    gold_cost: buildings::FALLBACK_GOLD_COST,
+    production_cost: buildings::FALLBACK_PRODUCTION_COST,
+    upkeep: buildings::FALLBACK_UPKEEP,
+    production_bonus: 0.0,
+    defense_bonus: 0.0,
+    food_yield: 0.0,
+    gold_yield: 0.0,
+    research_yield: 0.0,
+    level_scaling: 1.0,
+    max_level: 1,
+    required_technologies: Vec::new(),
+    required_resources: Vec::new(),
+    fortification: false,
+    growth_threshold_reduction: 0.0,
+};
+
+/// Every constructible building, loaded once from `buildings.ron`
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildingRegistry {
+    pub building_types: Vec<BuildingDefinition>,
+}
+
+impl BuildingRegistry {
+    pub fn load_from_ron(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let registry: BuildingRegistry = ron::from_str(&content)?;
+        Ok(registry)
+    }
+
+    pub fn get(&self, building_type: &BuildingType) -> Option<&BuildingDefinition> {
+        self.building_types
+            .iter()
+            .find(|definition| definition.name == building_type.name())
+    }
+
+    /// Definition for `building_type`, or generic fallback stats if it is not registered
+    pub fn definition(&self, building_type: &BuildingType) -> &BuildingDefinition {
+        self.get(building_type).unwrap_or_else(|| {
+            tracing::warn!(
+                "Building type '{}' is not in the building registry, using fallback stats",
+                building_type.name()
+            );
+            &FALLBACK_BUILDING
+        })
+    }
+
+    pub fn building_type_names(&self) -> impl Iterator<Item = &str> {
+        self.building_types
+            .iter()
+            .map(|definition| definition.name.as_str())
+    }
+
+    /// Whether a civilization knows every technology the building requires
+    pub fn has_required_technologies(
+        &self,
+        technologies: &Technologies,
+        building_type: &BuildingType,
+    ) -> bool {
+        self.get(building_type).is_none_or(|definition| {
+            definition
+                .required_technologies
+                .iter()
+                .all(|tech| technologies.knows(tech))
+        })
+    }
+
+    /// Whether a civilization owns a source of every resource the building requires
+    pub fn has_required_resources(&self, economy: &Economy, building_type: &BuildingType) -> bool {
+        self.get(building_type)
+            .is_none_or(|definition| economy.has_resources(&definition.required_resources))
+    }
+}
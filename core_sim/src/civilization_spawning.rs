@@ -0,0 +1,258 @@
+use crate::{
+    constants::{
+        buildings::{CITY_BASE_DEFENSE, CITY_BASE_PRODUCTION, STARTING_BUILDING_TYPE},
+        civilization_management::MIN_DISTANCE_BETWEEN_CIVS,
+        units::STARTING_UNIT_TYPE,
+    },
+    resources::WorldMap,
+    ActiveThisTurn, Building, BuildingRegistry, BuildingType, Capital, CapitalAge, City, CivId,
+    CivPersonality, Civilization, CivilizationDataCollection, CivilizationDataLoader,
+    CivilizationDefinition, Economy, Military, MilitaryUnit, PlayerControlled, Position,
+    ProductionQueue, ProvidesVision, Technologies, TerrainType, UnitRegistry, UnitType,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// Spawn the starting civilizations, capitals and units for a new game.
+///
+/// Shared by the Bevy frontend and the headless simulation runner so both start
+/// from an identical world for the same seed. Returns the number of civilizations spawned.
+pub fn spawn_initial_civilizations(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    rng: &mut rand_pcg::Pcg64,
+    civilization_data: CivilizationDataCollection,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+    ai_only: bool,
+    total_civilizations: u32,
+) -> usize {
+    let selected_civs = select_random_civilizations(civilization_data, total_civilizations, rng);
+
+    let random_positions = CivilizationDataLoader::generate_random_starting_positions(
+        &selected_civs,
+        world_map,
+        rng,
+        MIN_DISTANCE_BETWEEN_CIVS,
+    );
+
+    let mut spawned_count = 0;
+    for (civ_index, civ_def) in selected_civs.into_iter().enumerate() {
+        if spawn_civilization(
+            commands,
+            world_map,
+            &civ_def,
+            civ_index,
+            &random_positions,
+            unit_registry,
+            building_registry,
+            ai_only,
+        ) {
+            spawned_count += 1;
+        }
+    }
+
+    tracing::info!(
+        "Spawned {} civilizations on buildable terrain",
+        spawned_count
+    );
+
+    spawned_count
+}
+
+fn select_random_civilizations(
+    civilization_data: CivilizationDataCollection,
+    total_civilizations: u32,
+    rng: &mut rand_pcg::Pcg64,
+) -> Vec<CivilizationDefinition> {
+    use rand::seq::SliceRandom;
+
+    let mut available_civs = civilization_data.civilizations;
+    available_civs.shuffle(rng);
+    available_civs
+        .into_iter()
+        .take(total_civilizations as usize)
+        .collect()
+}
+
+fn spawn_civilization(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    civ_def: &CivilizationDefinition,
+    civ_index: usize,
+    random_positions: &HashMap<String, Position>,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+    ai_only: bool,
+) -> bool {
+    let Some(&position) = random_positions.get(&civ_def.name) else {
+        tracing::warn!("No starting position found for {}", civ_def.name);
+        return false;
+    };
+
+    if !is_buildable_position(world_map, position) {
+        tracing::warn!(
+            "Skipping {} - starting position ({}, {}) is not buildable",
+            civ_def.name,
+            position.x,
+            position.y
+        );
+        return false;
+    }
+
+    let civ_id = CivId(civ_index as u32);
+    let is_player = !ai_only && civ_index == 0;
+
+    spawn_civilization_entity(commands, civ_def, civ_id, position, is_player);
+    spawn_capital_city(
+        commands,
+        civ_def,
+        civ_id,
+        position,
+        building_registry,
+        is_player,
+    );
+    spawn_starting_unit(
+        commands,
+        civ_id,
+        position,
+        civ_index,
+        unit_registry,
+        is_player,
+    );
+    claim_starting_territory(world_map, civ_id, position, &civ_def.capital_name);
+
+    true
+}
+
+fn is_buildable_position(world_map: &WorldMap, position: Position) -> bool {
+    if let Some(tile) = world_map.get_tile(position) {
+        matches!(
+            tile.terrain,
+            TerrainType::Plains | TerrainType::Coast | TerrainType::Forest
+        )
+    } else {
+        false
+    }
+}
+
+fn spawn_civilization_entity(
+    commands: &mut Commands,
+    civ_def: &CivilizationDefinition,
+    civ_id: CivId,
+    position: Position,
+    is_player: bool,
+) {
+    let color = [civ_def.color.0, civ_def.color.1, civ_def.color.2];
+    let personality = CivPersonality::from(civ_def.personality.clone());
+
+    let civilization = Civilization {
+        id: civ_id,
+        name: civ_def.name.clone(),
+        color,
+        capital: Some(position),
+        personality,
+        technologies: Technologies::with_known(&civ_def.starting_technologies),
+        economy: Economy::default(),
+        military: Military::default(),
+        music_theme: civ_def.music_theme.clone(),
+        sound_theme: civ_def.sound_theme.clone(),
+    };
+
+    let mut civ_entity_commands = commands.spawn((civilization, position, civ_id, ActiveThisTurn));
+
+    if is_player {
+        civ_entity_commands.insert(PlayerControlled);
+        tracing::debug!("Marking {} as player-controlled civilization", civ_def.name);
+    } else {
+        tracing::debug!("Marking {} as AI-controlled civilization", civ_def.name);
+    }
+}
+
+fn spawn_capital_city(
+    commands: &mut Commands,
+    civ_def: &CivilizationDefinition,
+    civ_id: CivId,
+    position: Position,
+    building_registry: &BuildingRegistry,
+    is_player: bool,
+) {
+    let mut city = City {
+        name: civ_def.capital_name.clone(),
+        owner: civ_id,
+        population: 1000,
+        production: CITY_BASE_PRODUCTION,
+        tile_production: 0.0,
+        defense: CITY_BASE_DEFENSE,
+        buildings: vec![Building {
+            building_type: BuildingType::new(STARTING_BUILDING_TYPE),
+            level: 1,
+        }],
+    };
+    city.update_stats(building_registry);
+
+    let capital = Capital {
+        owner: civ_id,
+        age: CapitalAge::Neolithic,
+        sprite_index: CapitalAge::Neolithic.sprite_index(),
+        established_turn: 0,
+    };
+
+    let mut capital_commands = commands.spawn((city, capital, position, civ_id));
+    capital_commands.insert(ProductionQueue::new(civ_id));
+    capital_commands.insert(ProvidesVision::city_vision());
+
+    tracing::debug!(
+        "Spawned capital for civ {:?} at ({}, {})",
+        civ_id,
+        position.x,
+        position.y
+    );
+
+    if is_player {
+        capital_commands.insert(PlayerControlled);
+    }
+}
+
+fn spawn_starting_unit(
+    commands: &mut Commands,
+    civ_id: CivId,
+    position: Position,
+    civ_index: usize,
+    unit_registry: &UnitRegistry,
+    is_player: bool,
+) {
+    let initial_unit = MilitaryUnit::new(
+        civ_index as u32,
+        civ_id,
+        UnitType::new(STARTING_UNIT_TYPE),
+        position,
+        unit_registry,
+    );
+
+    let mut unit_commands = commands.spawn((initial_unit, position, civ_id));
+    unit_commands.insert(ProvidesVision::unit_vision());
+
+    tracing::debug!(
+        "Spawned starting unit for civ {:?} at ({}, {})",
+        civ_id,
+        position.x,
+        position.y
+    );
+
+    if is_player {
+        unit_commands.insert(PlayerControlled);
+    }
+}
+
+fn claim_starting_territory(
+    world_map: &mut WorldMap,
+    civ_id: CivId,
+    position: Position,
+    capital_name: &str,
+) {
+    if let Some(tile) = world_map.get_tile_mut(position) {
+        tile.owner = Some(civ_id);
+        tile.city = Some(capital_name.to_string());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants::combat;
-use crate::{CivId, MilitaryUnit, Position, UnitType};
+use crate::{CivId, MilitaryUnit, Position, UnitClass, UnitRegistry, UnitType};
 use rand::Rng;
 use std::collections::HashMap;
 
@@ -7,10 +7,14 @@ pub struct CombatSystem;
 
 impl CombatSystem {
     /// Resolve combat between units at the same position
+    ///
+    /// `attack_bonus` and `defense_bonus` are extra strength, as fractions, from promotions
+    /// and the defenders' terrain.
     pub fn resolve_combat(
         attacking_units: &mut Vec<MilitaryUnit>,
         defending_units: &mut Vec<MilitaryUnit>,
-        terrain_defense_bonus: f32,
+        attack_bonus: f32,
+        defense_bonus: f32,
         rng: &mut impl Rng,
     ) -> CombatResult {
         // Use effective attack and defense stats
@@ -19,8 +23,8 @@ impl CombatSystem {
         let initial_defender_strength: f32 =
             defending_units.iter().map(|u| u.effective_defense()).sum();
 
-        let mut attacker_strength = initial_attacker_strength;
-        let mut defender_strength = initial_defender_strength * (1.0 + terrain_defense_bonus);
+        let mut attacker_strength = initial_attacker_strength * (1.0 + attack_bonus);
+        let mut defender_strength = initial_defender_strength * (1.0 + defense_bonus);
 
         let mut rounds = 0;
         let max_rounds = combat::MAX_COMBAT_ROUNDS;
@@ -40,15 +44,14 @@ impl CombatSystem {
             if attacker_strength > defender_strength {
                 (
                     CombatWinner::Attacker,
-                    attacker_strength / initial_attacker_strength,
+                    attacker_strength / (initial_attacker_strength * (1.0 + attack_bonus)),
                     0.0,
                 )
             } else if defender_strength > attacker_strength {
                 (
                     CombatWinner::Defender,
                     0.0,
-                    defender_survival_rate
-                        / (initial_defender_strength * (1.0 + terrain_defense_bonus)),
+                    defender_strength / (initial_defender_strength * (1.0 + defense_bonus)),
                 )
             } else {
                 (
@@ -140,6 +143,7 @@ impl CombatSystem {
                     (unit.morale + combat::VICTOR_MORALE_INCREASE).min(combat::MAXIMUM_MORALE);
             } else {
                 unit.reduce_morale(combat::DEFEATED_MORALE_DECREASE);
+                unit.reduce_loyalty(combat::DEFEATED_LOYALTY_DECREASE);
             }
         }
     }
@@ -160,27 +164,67 @@ impl CombatSystem {
         terrain_bonus + (fortification_level as f32 * combat::FORTIFICATION_DEFENSE_BONUS)
     }
 
-    pub fn can_attack(attacker: &MilitaryUnit, defender_pos: Position, max_range: u32) -> bool {
-        let distance = attacker.position.distance_to(&defender_pos) as u32;
-        let effective_range = max_range.max(attacker.range);
-        distance <= effective_range
+    /// Whether `attacker` standing on `attacker_pos` has the range to strike `defender_pos`
+    pub fn can_attack(
+        attacker: &MilitaryUnit,
+        attacker_pos: Position,
+        defender_pos: Position,
+    ) -> bool {
+        let distance = attacker_pos.manhattan_distance_to(&defender_pos) as u32;
+        distance > 0 && distance <= attacker.range
     }
 
-    pub fn calculate_effectiveness(attacker_type: &UnitType, defender_type: &UnitType) -> f32 {
-        match (attacker_type, defender_type) {
-            (UnitType::Cavalry, UnitType::Archer) => combat::CAVALRY_VS_ARCHER_BONUS,
-            (UnitType::Cavalry, UnitType::Siege) => combat::CAVALRY_VS_SIEGE_BONUS,
+    /// Resolve a ranged attack on the units at one position
+    ///
+    /// Only the defenders take damage, as they cannot strike back at range. `attack_bonus` and
+    /// `defense_bonus` are extra strength, as fractions, as for `resolve_combat`.
+    pub fn resolve_ranged_attack(
+        attacker: &mut MilitaryUnit,
+        defending_units: &mut Vec<MilitaryUnit>,
+        attack_bonus: f32,
+        defense_bonus: f32,
+        rng: &mut impl Rng,
+    ) -> RangedAttackResult {
+        let attacker_strength = attacker.effective_attack() * (1.0 + attack_bonus);
+        let defender_strength: f32 = defending_units
+            .iter()
+            .map(|u| u.effective_defense())
+            .sum::<f32>()
+            * (1.0 + defense_bonus);
 
-            (UnitType::Infantry, UnitType::Cavalry) => combat::INFANTRY_VS_CAVALRY_BONUS,
+        let damage = Self::calculate_damage(attacker_strength, rng);
+        let casualty_rate = if defender_strength > 0.0 {
+            (damage / defender_strength).min(combat::COMPLETE_CASUALTY_THRESHOLD)
+        } else {
+            combat::COMPLETE_CASUALTY_THRESHOLD
+        };
 
-            (UnitType::Archer, UnitType::Infantry) => combat::ARCHER_VS_INFANTRY_BONUS,
+        let mut defender_losses = HashMap::new();
+        Self::apply_casualties(defending_units, casualty_rate, &mut defender_losses);
+        Self::grant_combat_experience_and_effects(std::slice::from_mut(attacker), true);
 
-            (UnitType::Siege, _) => combat::SIEGE_VS_ALL_BONUS,
+        RangedAttackResult {
+            casualty_rate,
+            defender_losses,
+        }
+    }
 
-            (UnitType::Naval, UnitType::Naval) => combat::NAVAL_VS_NAVAL_MULTIPLIER,
+    /// Defense a bombardment knocks out of a city's walls
+    pub fn resolve_bombardment(
+        attacker: &MilitaryUnit,
+        bombardment_multiplier: f32,
+        rng: &mut impl Rng,
+    ) -> f32 {
+        Self::calculate_damage(attacker.effective_attack() * bombardment_multiplier, rng)
+    }
 
-            _ => combat::DEFAULT_TYPE_EFFECTIVENESS,
-        }
+    /// Damage multiplier from the attacker's class against the defender's, as set in `units.ron`
+    pub fn calculate_effectiveness(
+        unit_registry: &UnitRegistry,
+        attacker: &MilitaryUnit,
+        defender: &MilitaryUnit,
+    ) -> f32 {
+        unit_registry.class_modifier(attacker.unit_class, defender.unit_class)
     }
 
     pub fn resolve_siege(
@@ -193,7 +237,7 @@ impl CombatSystem {
         let attacker_strength = Self::calculate_total_strength(attacking_units);
         let siege_units = attacking_units
             .iter()
-            .filter(|u| matches!(u.unit_type, UnitType::Siege))
+            .filter(|u| u.unit_class == UnitClass::Siege)
             .count();
 
         let siege_bonus = siege_units as f32 * combat::SIEGE_UNIT_BONUS;
@@ -239,7 +283,15 @@ pub struct CombatResult {
     pub defender_final_strength: f32,
 }
 
+/// Outcome of a ranged attack, which only ever harms the defenders
 #[derive(Debug, Clone)]
+pub struct RangedAttackResult {
+    /// Share of their health the defenders lost
+    pub casualty_rate: f32,
+    pub defender_losses: HashMap<UnitType, u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CombatWinner {
     Attacker,
     Defender,
@@ -266,3 +318,106 @@ pub enum SiegeResult {
         defender_casualties: f32,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    fn unit(attack: f32, defense: f32, range: u32) -> MilitaryUnit {
+        let mut unit = MilitaryUnit::new(
+            0,
+            CivId(0),
+            UnitType::new("Infantry"),
+            Position::new(0, 0),
+            &UnitRegistry::default(),
+        );
+        unit.attack = attack;
+        unit.defense = defense;
+        unit.range = range;
+        unit
+    }
+
+    #[test]
+    fn test_overwhelming_attackers_destroy_defenders() {
+        let mut rng = Pcg64::seed_from_u64(1);
+        let mut attackers = vec![unit(50.0, 10.0, 1); 3];
+        let mut defenders = vec![unit(1.0, 1.0, 1)];
+
+        let result =
+            CombatSystem::resolve_combat(&mut attackers, &mut defenders, 0.0, 0.0, &mut rng);
+
+        assert_eq!(result.winner, CombatWinner::Attacker);
+        assert!(defenders.is_empty());
+        assert_eq!(
+            result.casualties.defender_losses[&UnitType::new("Infantry")],
+            1
+        );
+        assert_eq!(attackers.len(), 3);
+        assert!(attackers
+            .iter()
+            .all(|unit| unit.experience == combat::WINNER_EXPERIENCE_GAIN));
+    }
+
+    #[test]
+    fn test_defense_bonus_lets_defenders_hold() {
+        let mut rng = Pcg64::seed_from_u64(2);
+        let mut attackers = vec![unit(10.0, 10.0, 1)];
+        let mut defenders = vec![unit(10.0, 10.0, 1)];
+
+        let result =
+            CombatSystem::resolve_combat(&mut attackers, &mut defenders, 0.0, 10.0, &mut rng);
+
+        assert_eq!(result.winner, CombatWinner::Defender);
+        assert!(attackers.is_empty());
+        assert_eq!(defenders.len(), 1);
+        assert_eq!(defenders[0].experience, combat::WINNER_EXPERIENCE_GAIN);
+    }
+
+    #[test]
+    fn test_ranged_attack_spares_the_attacker() {
+        let mut rng = Pcg64::seed_from_u64(3);
+        let mut archer = unit(1.0, 1.0, combat::ARCHER_RANGE);
+        let mut defenders = vec![unit(100.0, 100.0, 1)];
+
+        let result =
+            CombatSystem::resolve_ranged_attack(&mut archer, &mut defenders, 0.0, 0.0, &mut rng);
+
+        assert!(result.casualty_rate > 0.0 && result.casualty_rate < 1.0);
+        assert!(result.defender_losses.is_empty());
+        assert!(defenders[0].health < defenders[0].max_health);
+        assert_eq!(archer.health, archer.max_health);
+        assert_eq!(archer.experience, combat::WINNER_EXPERIENCE_GAIN);
+    }
+
+    #[test]
+    fn test_ranged_attack_can_destroy_weak_defenders() {
+        let mut rng = Pcg64::seed_from_u64(4);
+        let mut catapult = unit(100.0, 1.0, combat::SIEGE_RANGE);
+        let mut defenders = vec![unit(1.0, 1.0, 1)];
+
+        let result =
+            CombatSystem::resolve_ranged_attack(&mut catapult, &mut defenders, 0.0, 0.0, &mut rng);
+
+        assert_eq!(result.casualty_rate, combat::COMPLETE_CASUALTY_THRESHOLD);
+        assert!(defenders.is_empty());
+        assert_eq!(result.defender_losses[&UnitType::new("Infantry")], 1);
+    }
+
+    #[test]
+    fn test_can_attack_within_range_only() {
+        let melee = unit(5.0, 5.0, combat::MELEE_RANGE);
+        let archer = unit(5.0, 5.0, combat::ARCHER_RANGE);
+        let origin = Position::new(3, 3);
+        let adjacent = Position::new(4, 3);
+        let diagonal = Position::new(4, 4);
+        let distant = Position::new(6, 3);
+
+        assert!(!CombatSystem::can_attack(&melee, origin, origin));
+        assert!(CombatSystem::can_attack(&melee, origin, adjacent));
+        assert!(!CombatSystem::can_attack(&melee, origin, diagonal));
+        assert!(CombatSystem::can_attack(&archer, origin, diagonal));
+        assert!(!CombatSystem::can_attack(&archer, origin, distant));
+    }
+}
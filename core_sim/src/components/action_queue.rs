@@ -208,7 +208,7 @@ mod tests {
         let mut queue = ActionQueue::new(civ_id);
 
         let action = AIAction::BuildUnit {
-            unit_type: UnitType::Infantry,
+            unit_type: UnitType::new("Infantry"),
             position: Position { x: 0, y: 0 },
             priority: 5.0,
         };
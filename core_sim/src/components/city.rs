@@ -1,5 +1,8 @@
 use super::civilization::CivId;
+use super::position::Position;
 use super::terrain::TerrainType;
+use crate::building_registry::BuildingRegistry;
+use crate::constants::{buildings, population, settlement, territory};
 use bevy::prelude::Reflect;
 use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::*;
@@ -12,12 +15,14 @@ use serde::{Deserialize, Serialize};
 /// state of cities and should be saved/loaded.
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
-#[require(Save)]
+#[require(Save, BorderPressure, CityGrowth)]
 pub struct City {
     pub name: String,
     pub owner: CivId,
     pub population: u32,
     pub production: f32,
+    /// Production from the tiles the city works, refreshed every turn
+    pub tile_production: f32,
     pub defense: f32,
     pub buildings: Vec<Building>,
 }
@@ -28,55 +33,177 @@ impl City {
             name,
             owner,
             population: 1000,
-            production: 5.0, // Restored to proper turn-based production value
-            defense: 10.0,
+            production: buildings::CITY_BASE_PRODUCTION,
+            tile_production: 0.0,
+            defense: buildings::CITY_BASE_DEFENSE,
             buildings: vec![Building {
-                building_type: BuildingType::Granary,
+                building_type: BuildingType::new(buildings::STARTING_BUILDING_TYPE),
                 level: 1,
             }],
         }
     }
 
-    pub fn add_building(&mut self, building_type: BuildingType) {
+    pub fn building_level(&self, building_type: &BuildingType) -> u32 {
+        self.buildings
+            .iter()
+            .find(|b| &b.building_type == building_type)
+            .map_or(0, |b| b.level)
+    }
+
+    /// Whether the city can construct `building_type` or raise its level further
+    pub fn can_build(
+        &self,
+        building_type: &BuildingType,
+        building_registry: &BuildingRegistry,
+    ) -> bool {
+        self.building_level(building_type) < building_registry.definition(building_type).max_level
+    }
+
+    /// Construct a building, or upgrade it if the city already has one.
+    /// Returns false once the building is at its maximum level.
+    pub fn add_building(
+        &mut self,
+        building_type: BuildingType,
+        building_registry: &BuildingRegistry,
+    ) -> bool {
+        if self.building_level(&building_type) > 0 {
+            return self.upgrade_building(&building_type, building_registry);
+        }
+
         self.buildings.push(Building {
             building_type,
             level: 1,
         });
-        self.update_stats();
+        self.update_stats(building_registry);
+        true
     }
 
-    pub fn upgrade_building(&mut self, building_type: &BuildingType) -> bool {
+    pub fn upgrade_building(
+        &mut self,
+        building_type: &BuildingType,
+        building_registry: &BuildingRegistry,
+    ) -> bool {
+        let max_level = building_registry.definition(building_type).max_level;
         if let Some(building) = self
             .buildings
             .iter_mut()
-            .find(|b| &b.building_type == building_type)
+            .find(|b| &b.building_type == building_type && b.level < max_level)
         {
             building.level += 1;
-            self.update_stats();
+            self.update_stats(building_registry);
             true
         } else {
             false
         }
     }
 
-    fn update_stats(&mut self) {
-        // Recalculate city stats based on buildings
-        let mut total_production = 5.0; // Base production
-        let mut total_defense = 10.0; // Base defense
+    /// Remove a building entirely, returning it if the city had one of that type
+    pub fn remove_building(
+        &mut self,
+        building_type: &BuildingType,
+        building_registry: &BuildingRegistry,
+    ) -> Option<Building> {
+        let index = self
+            .buildings
+            .iter()
+            .position(|b| &b.building_type == building_type)?;
+        let building = self.buildings.remove(index);
+        self.update_stats(building_registry);
+        Some(building)
+    }
+
+    /// Recalculate production and defense from the city's worked tiles and buildings
+    pub fn update_stats(&mut self, building_registry: &BuildingRegistry) {
+        let mut total_production = buildings::CITY_BASE_PRODUCTION + self.tile_production;
+        let mut total_defense = buildings::CITY_BASE_DEFENSE;
 
         for building in &self.buildings {
-            let (prod_bonus, def_bonus) = building.building_type.bonuses();
-            total_production += prod_bonus * building.level as f32;
-            total_defense += def_bonus * building.level as f32;
+            let definition = building_registry.definition(&building.building_type);
+            total_production += definition.production_bonus_at(building.level);
+            total_defense += definition.defense_bonus_at(building.level);
         }
 
         self.production = total_production;
         self.defense = total_defense;
     }
 
+    /// Culture the city adds to its border pressure each turn
+    pub fn culture_per_turn(&self) -> f32 {
+        let building_levels: u32 = self.buildings.iter().map(|b| b.level).sum();
+
+        territory::BASE_CITY_CULTURE
+            + self.population as f32 / 1000.0 * territory::CULTURE_PER_THOUSAND_POPULATION
+            + building_levels as f32 * territory::CULTURE_PER_BUILDING_LEVEL
+    }
+
     pub fn grow_population(&mut self, amount: u32) {
         self.population += amount;
     }
+
+    pub fn shrink_population(&mut self, amount: u32) {
+        self.population = self
+            .population
+            .saturating_sub(amount)
+            .max(population::MIN_CITY_POPULATION);
+    }
+
+    /// Number of citizens, each of whom works one tile besides the city center
+    pub fn citizens(&self) -> u32 {
+        self.population / population::POPULATION_PER_CITIZEN
+    }
+
+    pub fn food_consumption(&self) -> f32 {
+        self.citizens() as f32 * population::FOOD_PER_CITIZEN
+    }
+
+    /// Stored food needed to gain the next citizen, after building reductions
+    pub fn growth_threshold(&self, building_registry: &BuildingRegistry) -> f32 {
+        let reduction: f32 = self
+            .buildings
+            .iter()
+            .map(|b| {
+                building_registry
+                    .definition(&b.building_type)
+                    .growth_threshold_reduction_at(b.level)
+            })
+            .sum();
+        let base = population::GROWTH_FOOD_BASE
+            + self.citizens() as f32 * population::GROWTH_FOOD_PER_CITIZEN;
+
+        base * (1.0 - reduction.min(population::MAX_GROWTH_THRESHOLD_REDUCTION))
+    }
+
+    /// Food from the city's buildings each turn
+    pub fn building_food(&self, building_registry: &BuildingRegistry) -> f32 {
+        self.buildings
+            .iter()
+            .map(|b| {
+                building_registry
+                    .definition(&b.building_type)
+                    .food_yield_at(b.level)
+            })
+            .sum()
+    }
+
+    /// Combined level of the city's fortifications, or 0 if it has none
+    pub fn fortification_level(&self, building_registry: &BuildingRegistry) -> u32 {
+        self.buildings
+            .iter()
+            .filter(|b| building_registry.definition(&b.building_type).fortification)
+            .map(|b| b.level)
+            .sum()
+    }
+}
+
+/// Siege in progress against a city
+///
+/// This is a "Model" component - saved state. Removed once no hostile units remain adjacent.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
+pub struct Siege {
+    pub besieger: CivId,
+    pub turns_besieged: u32,
 }
 
 /// Capital component for tracking civilization capitals through different ages
@@ -140,9 +267,10 @@ impl CapitalAge {
         }
     }
 
-    /// Get the requirements for evolving to the next age
-    pub fn evolution_requirements(&self) -> CapitalEvolutionRequirements {
-        match self {
+    /// Get the requirements for evolving to the next age, or None once the capital
+    /// has reached the final age
+    pub fn evolution_requirements(&self) -> Option<CapitalEvolutionRequirements> {
+        let requirements = match self {
             CapitalAge::Neolithic => CapitalEvolutionRequirements {
                 min_population: 2000,
                 required_technologies: vec!["Bronze Working".to_string()],
@@ -161,14 +289,47 @@ impl CapitalAge {
                 min_buildings: 4,
                 min_turn: 50,
             },
-            // Add more requirements as needed for other ages
-            _ => CapitalEvolutionRequirements {
-                min_population: 10000,
-                required_technologies: vec![],
+            CapitalAge::Classical => CapitalEvolutionRequirements {
+                min_population: 12000,
+                required_technologies: vec!["Philosophy".to_string(), "Mathematics".to_string()],
                 min_buildings: 5,
-                min_turn: 100,
+                min_turn: 80,
             },
-        }
+            CapitalAge::Medieval => CapitalEvolutionRequirements {
+                min_population: 16000,
+                required_technologies: vec!["Engineering".to_string(), "Masonry".to_string()],
+                min_buildings: 6,
+                min_turn: 120,
+            },
+            CapitalAge::Renaissance => CapitalEvolutionRequirements {
+                min_population: 22000,
+                required_technologies: vec!["Sailing".to_string(), "Animal Handling".to_string()],
+                min_buildings: 8,
+                min_turn: 160,
+            },
+            // The tech tree ends in the Classical era, so later ages gate on growth alone
+            CapitalAge::Industrial => CapitalEvolutionRequirements {
+                min_population: 30000,
+                required_technologies: vec![],
+                min_buildings: 10,
+                min_turn: 200,
+            },
+            CapitalAge::Modern => CapitalEvolutionRequirements {
+                min_population: 40000,
+                required_technologies: vec![],
+                min_buildings: 12,
+                min_turn: 250,
+            },
+            CapitalAge::Information => CapitalEvolutionRequirements {
+                min_population: 50000,
+                required_technologies: vec![],
+                min_buildings: 14,
+                min_turn: 300,
+            },
+            CapitalAge::Future => return None, // Max evolution
+        };
+
+        Some(requirements)
     }
 }
 
@@ -190,79 +351,73 @@ pub struct Building {
     pub level: u32,
 }
 
-/// Types of buildings that can be constructed
+/// Name of a building definition in the `BuildingRegistry`, e.g. "Granary"
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
-pub enum BuildingType {
-    Granary,
-    Barracks,
-    Workshop,
-    Library,
-    Walls,
-    Market,
-    Temple,
-}
+pub struct BuildingType(pub String);
 
 impl BuildingType {
-    /// Returns (production_bonus, defense_bonus) per level
-    pub fn bonuses(&self) -> (f32, f32) {
-        match self {
-            BuildingType::Granary => (2.0, 0.0),
-            BuildingType::Barracks => (0.0, 5.0),
-            BuildingType::Workshop => (3.0, 0.0),
-            BuildingType::Library => (1.0, 0.0),
-            BuildingType::Walls => (0.0, 10.0),
-            BuildingType::Market => (1.5, 0.0),
-            BuildingType::Temple => (0.5, 2.0),
-        }
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
     }
 
-    pub fn cost(&self) -> f32 {
-        match self {
-            BuildingType::Granary => 40.0,
-            BuildingType::Barracks => 60.0,
-            BuildingType::Workshop => 80.0,
-            BuildingType::Library => 100.0,
-            BuildingType::Walls => 120.0,
-            BuildingType::Market => 90.0,
-            BuildingType::Temple => 70.0,
-        }
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for BuildingType {
+    fn from(name: &str) -> Self {
+        Self::new(name)
     }
+}
 
-    pub fn maintenance_cost(&self) -> f32 {
-        self.cost() * 0.05 // 5% of build cost per turn
+impl std::fmt::Display for BuildingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
+}
 
-    pub fn production_cost(&self) -> f32 {
-        match self {
-            BuildingType::Granary => 30.0,
-            BuildingType::Barracks => 45.0,
-            BuildingType::Workshop => 60.0,
-            BuildingType::Library => 75.0,
-            BuildingType::Walls => 90.0,
-            BuildingType::Market => 70.0,
-            BuildingType::Temple => 55.0,
+/// Culture a city has built up towards pushing its borders further out
+///
+/// This is a "Model" component - saved state. Required by `City`, so every city has one.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct BorderPressure {
+    pub accumulated: f32,
+    pub radius: i32,
+}
+
+impl Default for BorderPressure {
+    fn default() -> Self {
+        Self {
+            accumulated: 0.0,
+            radius: settlement::CITY_CLAIM_RADIUS,
         }
     }
+}
 
-    pub fn gold_cost(&self) -> f32 {
-        self.cost()
+impl BorderPressure {
+    /// Culture needed to grow the border by one more tile
+    pub fn growth_cost(&self) -> f32 {
+        territory::BORDER_GROWTH_COST_PER_RADIUS * self.radius as f32
     }
+}
 
-    pub fn name(&self) -> &'static str {
-        match self {
-            BuildingType::Granary => "Granary",
-            BuildingType::Barracks => "Barracks",
-            BuildingType::Workshop => "Workshop",
-            BuildingType::Library => "Library",
-            BuildingType::Walls => "Walls",
-            BuildingType::Market => "Market",
-            BuildingType::Temple => "Temple",
-        }
-    }
+/// Food a city has stored towards its next citizen and the tiles its citizens work
+///
+/// This is a "Model" component - saved state. Required by `City`, so every city has one.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct CityGrowth {
+    pub food_stored: f32,
+    /// Food produced minus food eaten last turn
+    pub food_surplus: f32,
+    pub worked_tiles: Vec<Position>,
 }
 
-/// Territory control component
-#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+/// Territory control component, one per owned map tile
+#[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
 #[require(Save)]
 pub struct Territory {
     pub owner: CivId,
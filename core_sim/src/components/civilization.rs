@@ -1,4 +1,5 @@
 use super::position::Position;
+use crate::resources::Resource as GameResource;
 use bevy::prelude::Reflect;
 use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::*;
@@ -91,6 +92,25 @@ impl Default for Technologies {
     }
 }
 
+impl Technologies {
+    /// Research state for a civilization that starts out knowing `starting_technologies`
+    pub fn with_known(starting_technologies: &[String]) -> Self {
+        let mut technologies = Self::default();
+        for technology in starting_technologies {
+            technologies.learn(technology);
+        }
+        technologies
+    }
+
+    pub fn knows(&self, technology: &str) -> bool {
+        self.known.get(technology).copied().unwrap_or(false)
+    }
+
+    pub fn learn(&mut self, technology: &str) {
+        self.known.insert(technology.to_string(), true);
+    }
+}
+
 /// Economic state of a civilization
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
@@ -102,6 +122,9 @@ pub struct Economy {
     pub production: f32,
     #[reflect(skip_serializing)]
     pub trade_routes: Vec<TradeRoute>,
+    /// Number of owned tiles providing each resource
+    #[reflect(skip_serializing)]
+    pub resources: HashMap<GameResource, u32>,
 }
 
 impl Default for Economy {
@@ -112,10 +135,26 @@ impl Default for Economy {
             expenses: 5.0,
             production: 8.0,
             trade_routes: Vec::new(),
+            resources: HashMap::new(),
         }
     }
 }
 
+impl Economy {
+    pub fn resource_count(&self, resource: GameResource) -> u32 {
+        self.resources.get(&resource).copied().unwrap_or(0)
+    }
+
+    pub fn has_resource(&self, resource: GameResource) -> bool {
+        self.resource_count(resource) > 0
+    }
+
+    /// Whether the civilization has access to every resource in `required`
+    pub fn has_resources(&self, required: &[GameResource]) -> bool {
+        required.iter().all(|resource| self.has_resource(*resource))
+    }
+}
+
 /// Trade route between cities/regions
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
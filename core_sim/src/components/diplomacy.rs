@@ -1,10 +1,11 @@
+use bevy::reflect::Reflect;
 use bevy_ecs::component::{Component, Mutable};
 use serde::{Deserialize, Serialize};
 
 use super::civilization::CivId;
 
 /// Diplomatic relationship between two civilizations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct DiplomaticRelation {
     pub civ_a: CivId,
     pub civ_b: CivId,
@@ -19,7 +20,7 @@ impl Component for DiplomaticRelation {
     const STORAGE_TYPE: bevy_ecs::component::StorageType = bevy_ecs::component::StorageType::Table;
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub enum Treaty {
     NonAggression { turns_remaining: u32 },
     Alliance { turns_remaining: u32 },
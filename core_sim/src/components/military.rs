@@ -1,6 +1,7 @@
 use super::civilization::CivId;
 use super::position::Position;
-use crate::constants::unit_stats;
+use crate::constants::{combat, unit_stats, veterancy};
+use crate::unit_registry::UnitRegistry;
 use bevy::prelude::Reflect;
 use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::*;
@@ -25,6 +26,7 @@ pub struct MilitaryUnit {
     pub id: u32,
     pub owner: CivId,
     pub unit_type: UnitType,
+    pub unit_class: UnitClass,
     pub position: Position,
     pub facing: FacingDirection,
 
@@ -45,24 +47,34 @@ pub struct MilitaryUnit {
     pub corruption: f32,
 
     pub experience: f32,
+    /// Names of the promotions chosen from the `UnitRegistry` promotion catalog
+    pub promotions: Vec<String>,
 }
 
 impl MilitaryUnit {
-    pub fn new(id: u32, owner: CivId, unit_type: UnitType, position: Position) -> Self {
-        let max_health = unit_type.base_health();
+    /// Create a unit with the stats `unit_registry` defines for `unit_type`
+    pub fn new(
+        id: u32,
+        owner: CivId,
+        unit_type: UnitType,
+        position: Position,
+        unit_registry: &UnitRegistry,
+    ) -> Self {
+        let definition = unit_registry.definition(&unit_type);
         Self {
             id,
             owner,
+            unit_class: definition.class(),
             unit_type,
             position,
             facing: FacingDirection::default(),
-            attack: unit_type.base_attack(),
-            defense: unit_type.base_defense(),
-            health: max_health,
-            max_health,
-            movement_range: unit_type.movement_points(),
-            movement_remaining: unit_type.movement_points(),
-            range: unit_type.attack_range(),
+            attack: definition.attack,
+            defense: definition.defense,
+            health: definition.health,
+            max_health: definition.health,
+            movement_range: definition.movement_range,
+            movement_remaining: definition.movement_range,
+            range: definition.range,
             fatigue: 0.0,
             supply: 1.0,
             decay: 0.0,
@@ -70,6 +82,7 @@ impl MilitaryUnit {
             loyalty: 1.0,
             corruption: 0.0,
             experience: 0.0,
+            promotions: Vec::new(),
         }
     }
 
@@ -77,6 +90,11 @@ impl MilitaryUnit {
         self.movement_remaining > 0
     }
 
+    /// Whether the unit can strike beyond the tiles next to it
+    pub fn has_ranged_attack(&self) -> bool {
+        self.range > combat::MELEE_RANGE
+    }
+
     pub fn move_to(&mut self, new_position: Position) -> bool {
         if self.can_move() {
             self.position = new_position;
@@ -95,9 +113,19 @@ impl MilitaryUnit {
 
     pub fn gain_experience(&mut self, amount: f32) {
         self.experience += amount;
-        let exp_bonus = 1.0 + (self.experience * unit_stats::EXPERIENCE_BONUS_MULTIPLIER);
-        self.attack = self.unit_type.base_attack() * exp_bonus;
-        self.defense = self.unit_type.base_defense() * exp_bonus;
+    }
+
+    pub fn veterancy_level(&self) -> VeterancyLevel {
+        VeterancyLevel::from_experience(self.experience)
+    }
+
+    /// Promotions the unit has earned through its veterancy level but not chosen yet
+    pub fn pending_promotions(&self) -> usize {
+        (self.veterancy_level().rank() as usize).saturating_sub(self.promotions.len())
+    }
+
+    pub fn has_promotion(&self, promotion: &str) -> bool {
+        self.promotions.iter().any(|name| name == promotion)
     }
 
     pub fn effective_attack(&self) -> f32 {
@@ -105,14 +133,30 @@ impl MilitaryUnit {
         let decay_penalty = 1.0 - (self.decay * unit_stats::DECAY_ATTACK_PENALTY_MULTIPLIER);
         let morale_modifier =
             unit_stats::MORALE_ATTACK_BASE + (self.morale * unit_stats::MORALE_ATTACK_RANGE);
-        self.attack * fatigue_penalty * decay_penalty * morale_modifier
+        self.attack
+            * fatigue_penalty
+            * decay_penalty
+            * morale_modifier
+            * self.supply_modifier()
+            * self.veterancy_level().combat_modifier()
     }
 
     pub fn effective_defense(&self) -> f32 {
+        let fatigue_penalty = 1.0 - (self.fatigue * unit_stats::FATIGUE_DEFENSE_PENALTY_MULTIPLIER);
         let decay_penalty = 1.0 - (self.decay * unit_stats::DECAY_DEFENSE_PENALTY_MULTIPLIER);
         let morale_modifier =
             unit_stats::MORALE_DEFENSE_BASE + (self.morale * unit_stats::MORALE_DEFENSE_RANGE);
-        self.defense * decay_penalty * morale_modifier
+        self.defense
+            * fatigue_penalty
+            * decay_penalty
+            * morale_modifier
+            * self.supply_modifier()
+            * self.veterancy_level().combat_modifier()
+    }
+
+    /// Combat strength multiplier of a unit running short of supplies
+    fn supply_modifier(&self) -> f32 {
+        unit_stats::SUPPLY_COMBAT_BASE + (self.supply * unit_stats::SUPPLY_COMBAT_RANGE)
     }
 
     pub fn is_reliable(&self) -> bool {
@@ -160,107 +204,151 @@ impl MilitaryUnit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
-pub enum UnitType {
-    Infantry,
-    Cavalry,
-    Archer,
-    Siege,
-    Naval,
+/// Rank a unit reaches as it gains combat experience; every rank above recruit earns a
+/// promotion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VeterancyLevel {
+    Recruit,
+    Regular,
+    Veteran,
+    Elite,
 }
 
-impl UnitType {
-    pub fn base_attack(&self) -> f32 {
-        match self {
-            UnitType::Infantry => 8.0,
-            UnitType::Cavalry => 12.0,
-            UnitType::Archer => 6.0,
-            UnitType::Siege => 15.0,
-            UnitType::Naval => 18.0,
+impl VeterancyLevel {
+    pub fn from_experience(experience: f32) -> Self {
+        if experience >= veterancy::ELITE_EXPERIENCE {
+            VeterancyLevel::Elite
+        } else if experience >= veterancy::VETERAN_EXPERIENCE {
+            VeterancyLevel::Veteran
+        } else if experience >= veterancy::REGULAR_EXPERIENCE {
+            VeterancyLevel::Regular
+        } else {
+            VeterancyLevel::Recruit
         }
     }
 
-    pub fn base_defense(&self) -> f32 {
+    /// Levels above recruit
+    pub fn rank(&self) -> u32 {
         match self {
-            UnitType::Infantry => 10.0,
-            UnitType::Cavalry => 6.0,
-            UnitType::Archer => 5.0,
-            UnitType::Siege => 3.0,
-            UnitType::Naval => 12.0,
+            VeterancyLevel::Recruit => 0,
+            VeterancyLevel::Regular => 1,
+            VeterancyLevel::Veteran => 2,
+            VeterancyLevel::Elite => 3,
         }
     }
 
-    pub fn base_health(&self) -> f32 {
-        match self {
-            UnitType::Infantry => 100.0,
-            UnitType::Cavalry => 80.0,
-            UnitType::Archer => 70.0,
-            UnitType::Siege => 60.0,
-            UnitType::Naval => 150.0,
-        }
+    /// Combat strength multiplier this level grants
+    pub fn combat_modifier(&self) -> f32 {
+        1.0 + self.rank() as f32 * veterancy::COMBAT_BONUS_PER_LEVEL
     }
 
-    pub fn attack_range(&self) -> u32 {
+    pub fn name(&self) -> &'static str {
         match self {
-            UnitType::Infantry => 1, // Melee
-            UnitType::Cavalry => 1,  // Melee
-            UnitType::Archer => 2,   // Ranged
-            UnitType::Siege => 3,    // Long range
-            UnitType::Naval => 2,    // Naval bombardment
+            VeterancyLevel::Recruit => "Recruit",
+            VeterancyLevel::Regular => "Regular",
+            VeterancyLevel::Veteran => "Veteran",
+            VeterancyLevel::Elite => "Elite",
         }
     }
+}
 
-    pub fn base_strength(&self) -> f32 {
-        match self {
-            UnitType::Infantry => 10.0,
-            UnitType::Cavalry => 12.0,
-            UnitType::Archer => 8.0,
-            UnitType::Siege => 15.0,
-            UnitType::Naval => 20.0,
-        }
+/// Name of a unit definition in the `UnitRegistry`, e.g. "Infantry"
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub struct UnitType(pub String);
+
+impl UnitType {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
     }
 
-    pub fn movement_points(&self) -> u32 {
-        match self {
-            UnitType::Infantry => 2,
-            UnitType::Cavalry => 3,
-            UnitType::Archer => 2,
-            UnitType::Siege => 1,
-            UnitType::Naval => 4,
-        }
+    pub fn name(&self) -> &str {
+        &self.0
     }
+}
 
-    pub fn cost(&self) -> f32 {
-        match self {
-            UnitType::Infantry => 20.0,
-            UnitType::Cavalry => 35.0,
-            UnitType::Archer => 25.0,
-            UnitType::Siege => 50.0,
-            UnitType::Naval => 60.0,
-        }
+impl From<&str> for UnitType {
+    fn from(name: &str) -> Self {
+        Self::new(name)
     }
+}
 
-    pub fn maintenance_cost(&self) -> f32 {
-        self.cost() * 0.1 // 10% of build cost per turn
+impl std::fmt::Display for UnitType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
+}
 
-    pub fn production_cost(&self) -> f32 {
-        match self {
-            UnitType::Infantry => 15.0,
-            UnitType::Cavalry => 25.0,
-            UnitType::Archer => 20.0,
-            UnitType::Siege => 40.0,
-            UnitType::Naval => 50.0,
+/// Broad battlefield role of a unit, as named by `unit_class` in `units.ron`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect, Default)]
+pub enum UnitClass {
+    #[default]
+    Melee,
+    Ranged,
+    Mounted,
+    Scout,
+    Siege,
+    Naval,
+    Civilian,
+}
+
+impl UnitClass {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Melee" => Some(UnitClass::Melee),
+            "Ranged" => Some(UnitClass::Ranged),
+            "Mounted" => Some(UnitClass::Mounted),
+            "Scout" => Some(UnitClass::Scout),
+            "Siege" => Some(UnitClass::Siege),
+            "Naval" => Some(UnitClass::Naval),
+            "Civilian" => Some(UnitClass::Civilian),
+            _ => None,
         }
     }
 
     pub fn name(&self) -> &'static str {
         match self {
-            UnitType::Infantry => "Infantry",
-            UnitType::Cavalry => "Cavalry",
-            UnitType::Archer => "Archer",
-            UnitType::Siege => "Siege Engine",
-            UnitType::Naval => "Naval Unit",
+            UnitClass::Melee => "Melee",
+            UnitClass::Ranged => "Ranged",
+            UnitClass::Mounted => "Mounted",
+            UnitClass::Scout => "Scout",
+            UnitClass::Siege => "Siege",
+            UnitClass::Naval => "Naval",
+            UnitClass::Civilian => "Civilian",
         }
     }
+
+    pub fn domain(&self) -> MovementDomain {
+        match self {
+            UnitClass::Naval => MovementDomain::Sea,
+            _ => MovementDomain::Land,
+        }
+    }
+}
+
+/// Which tiles a unit can travel over under its own power
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MovementDomain {
+    /// Passable land terrain, see `TerrainRegistry::is_passable`
+    Land,
+    /// Navigable water, see `TerrainRegistry::is_navigable`
+    Sea,
+}
+
+/// A ship able to carry land units across the water
+///
+/// Land units aboard share the ship's tile and are marked `Embarked`. The ship remembers
+/// the tile its cargo was last carried to so `carry_embarked_units` can bring them along
+/// whenever it moves.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
+pub struct Transport {
+    pub capacity: u32,
+    pub anchorage: Position,
 }
+
+/// Marks a land unit carried aboard a friendly `Transport` on its tile
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
+pub struct Embarked;
@@ -1,4 +1,8 @@
+use super::position::Position;
+use bevy::reflect::Reflect;
 use bevy_ecs::component::{Component, Mutable};
+use bevy_ecs::reflect::ReflectComponent;
+use moonshine_save::prelude::*;
 
 // Re-export MovementOrder from position module
 pub use super::position::MovementOrder;
@@ -12,3 +16,44 @@ impl Component for ActiveThisTurn {
     type Mutability = Mutable;
     const STORAGE_TYPE: bevy_ecs::component::StorageType = bevy_ecs::component::StorageType::Table;
 }
+
+/// Order for a settler to travel to `target` and found a city there
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
+pub struct SettleOrder {
+    pub target: Position,
+}
+
+/// Standing order a unit keeps from turn to turn until it moves or is given another
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
+pub enum UnitOrder {
+    /// Dig in, gaining a fortification level every turn spent in place
+    Fortify { level: u32 },
+    /// Stand watch until an enemy unit comes into view
+    Sentry,
+    /// Stand down until given something else to do
+    Sleep,
+    /// Do nothing for the rest of this turn
+    Skip,
+}
+
+impl UnitOrder {
+    pub fn fortification_level(&self) -> u32 {
+        match self {
+            UnitOrder::Fortify { level } => *level,
+            _ => 0,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UnitOrder::Fortify { .. } => "Fortify",
+            UnitOrder::Sentry => "Sentry",
+            UnitOrder::Sleep => "Sleep",
+            UnitOrder::Skip => "Skip turn",
+        }
+    }
+}
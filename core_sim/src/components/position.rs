@@ -1,5 +1,4 @@
 use bevy::prelude::Reflect;
-use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::*;
 use moonshine_save::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -129,7 +128,9 @@ pub mod direction_offsets {
 }
 
 /// Movement order component for pathfinding
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+#[require(Save)]
 pub struct MovementOrder {
     /// Path to follow (sequence of positions)
     pub path: Vec<Position>,
@@ -141,12 +142,6 @@ pub struct MovementOrder {
     pub movement_cost: f32,
 }
 
-// Manual Component implementation to avoid proc macro issues
-impl Component for MovementOrder {
-    type Mutability = Mutable;
-    const STORAGE_TYPE: bevy_ecs::component::StorageType = bevy_ecs::component::StorageType::Table;
-}
-
 impl MovementOrder {
     pub fn new(path: Vec<Position>, destination: Position) -> Self {
         Self {
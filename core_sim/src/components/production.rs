@@ -1,5 +1,8 @@
 use super::civilization::CivId;
+use super::city::BuildingType;
 use super::military::UnitType;
+use crate::building_registry::BuildingRegistry;
+use crate::unit_registry::UnitRegistry;
 use bevy_ecs::component::Mutable;
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -40,11 +43,18 @@ impl ProductionQueue {
         }
     }
 
-    pub fn add_production(&mut self, amount: f32) -> Option<ProductionItem> {
+    pub fn add_production(
+        &mut self,
+        amount: f32,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
+    ) -> Option<ProductionItem> {
         if let Some(ref current) = self.current_production {
             self.accumulated_production += amount;
             
-            if self.accumulated_production >= current.production_cost() {
+            if self.accumulated_production
+                >= current.production_cost(unit_registry, building_registry)
+            {
                 let completed_item = self.current_production.take();
                 self.accumulated_production = 0.0;
                 self.start_next_production();
@@ -58,9 +68,15 @@ impl ProductionQueue {
         }
     }
 
-    pub fn get_progress_percentage(&self) -> f32 {
+    pub fn get_progress_percentage(
+        &self,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
+    ) -> f32 {
         if let Some(ref current) = self.current_production {
-            (self.accumulated_production / current.production_cost()).min(1.0)
+            (self.accumulated_production
+                / current.production_cost(unit_registry, building_registry))
+            .min(1.0)
         } else {
             0.0
         }
@@ -87,25 +103,37 @@ impl ProductionQueue {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ProductionItem {
     Unit(UnitType),
-    Building(super::city::BuildingType),
+    Building(BuildingType),
 }
 
 impl ProductionItem {
-    pub fn production_cost(&self) -> f32 {
+    pub fn production_cost(
+        &self,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
+    ) -> f32 {
         match self {
-            ProductionItem::Unit(unit_type) => unit_type.production_cost(),
-            ProductionItem::Building(building_type) => building_type.production_cost(),
+            ProductionItem::Unit(unit_type) => unit_registry.definition(unit_type).production_cost,
+            ProductionItem::Building(building_type) => {
+                building_registry.definition(building_type).production_cost
+            }
         }
     }
 
-    pub fn gold_cost(&self) -> f32 {
+    pub fn gold_cost(
+        &self,
+        unit_registry: &UnitRegistry,
+        building_registry: &BuildingRegistry,
+    ) -> f32 {
         match self {
-            ProductionItem::Unit(unit_type) => unit_type.cost(),
-            ProductionItem::Building(building_type) => building_type.gold_cost(),
+            ProductionItem::Unit(unit_type) => unit_registry.definition(unit_type).gold_cost,
+            ProductionItem::Building(building_type) => {
+                building_registry.definition(building_type).gold_cost
+            }
         }
     }
 
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             ProductionItem::Unit(unit_type) => unit_type.name(),
             ProductionItem::Building(building_type) => building_type.name(),
@@ -21,48 +21,18 @@ pub enum TerrainType {
 }
 
 impl TerrainType {
-    /// Get movement cost for this terrain type
-    pub fn movement_cost(&self) -> f32 {
+    /// Name used for this terrain type in `terrain.ron` and save files
+    pub fn name(&self) -> &'static str {
         match self {
-            TerrainType::Plains => 1.0,
-            TerrainType::Hills => 2.0,
-            TerrainType::Mountains => 3.0,
-            TerrainType::Forest => 1.5,
-            TerrainType::Desert => 2.0,
-            TerrainType::Coast => 1.0,
-            TerrainType::ShallowCoast => 2.0,
-            TerrainType::Ocean => f32::INFINITY, // Impassable for land units
-            TerrainType::River => 1.0,
-        }
-    }
-
-    /// Get defensive bonus for this terrain type
-    pub fn defensive_bonus(&self) -> f32 {
-        match self {
-            TerrainType::Plains => 0.0,
-            TerrainType::Hills => 0.25,
-            TerrainType::Mountains => 0.5,
-            TerrainType::Forest => 0.2,
-            TerrainType::Desert => 0.0,
-            TerrainType::Coast => 0.1,
-            TerrainType::ShallowCoast => 0.0,
-            TerrainType::Ocean => 0.0,
-            TerrainType::River => 0.15,
-        }
-    }
-
-    /// Get resource yield for this terrain type (food, production, gold)
-    pub fn resource_yield(&self) -> (f32, f32, f32) {
-        match self {
-            TerrainType::Plains => (2.0, 1.0, 0.0),
-            TerrainType::Hills => (0.0, 2.0, 1.0),
-            TerrainType::Mountains => (0.0, 1.0, 2.0),
-            TerrainType::Forest => (1.0, 2.0, 0.0),
-            TerrainType::Desert => (0.0, 0.0, 1.0),
-            TerrainType::Coast => (2.0, 0.0, 2.0),
-            TerrainType::ShallowCoast => (1.0, 0.0, 1.0),
-            TerrainType::Ocean => (1.0, 0.0, 1.0),
-            TerrainType::River => (3.0, 0.0, 1.0),
+            TerrainType::Plains => "Plains",
+            TerrainType::Hills => "Hills",
+            TerrainType::Mountains => "Mountains",
+            TerrainType::Forest => "Forest",
+            TerrainType::Desert => "Desert",
+            TerrainType::Coast => "Coast",
+            TerrainType::ShallowCoast => "ShallowCoast",
+            TerrainType::Ocean => "Ocean",
+            TerrainType::River => "River",
         }
     }
 
@@ -102,17 +72,7 @@ impl Serialize for TerrainType {
     where
         S: serde::Serializer,
     {
-        match self {
-            TerrainType::Plains => serializer.serialize_str("Plains"),
-            TerrainType::Hills => serializer.serialize_str("Hills"),
-            TerrainType::Mountains => serializer.serialize_str("Mountains"),
-            TerrainType::Forest => serializer.serialize_str("Forest"),
-            TerrainType::Desert => serializer.serialize_str("Desert"),
-            TerrainType::Coast => serializer.serialize_str("Coast"),
-            TerrainType::ShallowCoast => serializer.serialize_str("ShallowCoast"),
-            TerrainType::Ocean => serializer.serialize_str("Ocean"),
-            TerrainType::River => serializer.serialize_str("River"),
-        }
+        serializer.serialize_str(self.name())
     }
 }
 
@@ -92,19 +92,18 @@ pub mod map_generation {
 // MOVEMENT AND TERRAIN STATS
 // ============================================================================
 
-/// Movement and terrain configuration
-pub mod terrain_stats {
-    /// Base movement cost for most terrain
-    pub const BASE_MOVEMENT_COST: f32 = 1.0;
-
-    /// Movement cost for ocean terrain
-    pub const OCEAN_MOVEMENT_COST: f32 = 3.0;
-
-    /// Base defense bonus for most terrain
-    pub const BASE_DEFENSE_BONUS: f32 = 0.0;
-
-    /// Ocean defense bonus
-    pub const OCEAN_DEFENSE_BONUS: f32 = 0.0;
+/// Terrain registry constants
+pub mod terrain {
+    /// Path to the terrain definitions, relative to the workspace root
+    pub const TERRAIN_DATA_PATH: &str = "dominion_earth/assets/data/terrain.ron";
+
+    /// Stats used for terrain types missing from the registry
+    pub const FALLBACK_MOVEMENT_COST: f32 = 1.0;
+    pub const FALLBACK_DEFENSE_BONUS: f32 = 0.0;
+    pub const FALLBACK_FOOD_YIELD: f32 = 1.0;
+    pub const FALLBACK_PRODUCTION_YIELD: f32 = 1.0;
+    pub const FALLBACK_GOLD_YIELD: f32 = 0.0;
+    pub const FALLBACK_COLOR: (f32, f32, f32) = (0.5, 0.5, 0.5);
 }
 
 /// Movement validation constants
@@ -156,6 +155,21 @@ pub mod economy {
 
     /// Default amount when resource not found
     pub const DEFAULT_RESOURCE_AMOUNT: f32 = 0.0;
+
+    /// Gold every city yields regardless of size
+    pub const CITY_BASE_GOLD: f32 = 2.0;
+
+    /// Gold yielded per 1000 citizens
+    pub const GOLD_PER_THOUSAND_POPULATION: f32 = 1.0;
+
+    /// Share of a tile's terrain gold yield that reaches the treasury
+    pub const TILE_GOLD_YIELD_RATE: f32 = 0.25;
+
+    /// Extra gold per turn for owning a Gold or Spices tile
+    pub const LUXURY_RESOURCE_GOLD_BONUS: f32 = 2.0;
+
+    /// Share of a building's cost refunded when it is sold off
+    pub const BUILDING_SALE_REFUND_RATE: f32 = 0.5;
 }
 
 // ============================================================================
@@ -217,6 +231,311 @@ pub mod civilization_management {
 
     /// Starting unit ID counter value
     pub const STARTING_UNIT_ID_COUNTER: u32 = 0;
+
+    /// Minimum distance between civilization starting positions
+    pub const MIN_DISTANCE_BETWEEN_CIVS: u32 = 5;
+
+    /// Path to the civilization definitions, relative to the workspace root
+    pub const CIVILIZATIONS_DATA_PATH: &str = "dominion_earth/assets/data/civilizations.ron";
+}
+
+// ============================================================================
+// DIPLOMACY
+// ============================================================================
+
+/// Diplomatic relation and treaty constants
+pub mod diplomacy {
+    /// Relation value every pair of civilizations starts with (range -100 to 100)
+    pub const INITIAL_RELATION_VALUE: f32 = 0.0;
+
+    /// Scales `calculate_war_likelihood` into a per-turn chance of declaring war
+    pub const WAR_DECLARATION_CHANCE_PER_TURN: f32 = 0.1;
+
+    /// Relation penalty applied when war is declared
+    pub const WAR_DECLARATION_RELATION_PENALTY: f32 = 50.0;
+
+    /// Minimum number of turns a war lasts before peace can be proposed
+    pub const MIN_WAR_DURATION_TURNS: u32 = 10;
+
+    /// Per-turn chance that a civilization at war proposes peace, scaled by `honor_treaties`
+    pub const PEACE_PROPOSAL_CHANCE_PER_TURN: f32 = 0.1;
+
+    /// Turns a peace negotiation takes to resolve
+    pub const PEACE_NEGOTIATION_TURNS: u32 = 3;
+
+    /// Turns a trade pact negotiation takes to resolve
+    pub const TRADE_PACT_NEGOTIATION_TURNS: u32 = 3;
+}
+
+// ============================================================================
+// RESEARCH
+// ============================================================================
+
+/// Technology research constants
+pub mod research {
+    /// Path to the technology tree, relative to the workspace root
+    pub const TECHNOLOGIES_DATA_PATH: &str = "dominion_earth/assets/data/technologies.ron";
+
+    /// Research points every civilization earns per turn
+    pub const BASE_RESEARCH_PER_TURN: f32 = 2.0;
+
+    /// Research points per 1000 citizens
+    pub const RESEARCH_PER_THOUSAND_POPULATION: f32 = 1.0;
+}
+
+// ============================================================================
+// UNIT DEFINITIONS
+// ============================================================================
+
+/// Unit registry constants
+pub mod units {
+    /// Path to the unit definitions, relative to the workspace root
+    pub const UNITS_DATA_PATH: &str = "dominion_earth/assets/data/units.ron";
+
+    /// Unit every civilization starts the game with
+    pub const STARTING_UNIT_TYPE: &str = "Infantry";
+
+    /// Share of a unit's gold cost paid as upkeep each turn
+    pub const UNIT_MAINTENANCE_RATE: f32 = 0.1;
+
+    /// Stats used for unit types missing from the registry
+    pub const FALLBACK_ATTACK: f32 = 5.0;
+    pub const FALLBACK_DEFENSE: f32 = 5.0;
+    pub const FALLBACK_HEALTH: f32 = 50.0;
+    pub const FALLBACK_MOVEMENT_RANGE: u32 = 1;
+    pub const FALLBACK_RANGE: u32 = 1;
+    pub const FALLBACK_GOLD_COST: f32 = 20.0;
+    pub const FALLBACK_PRODUCTION_COST: f32 = 15.0;
+}
+
+// ============================================================================
+// BUILDING DEFINITIONS
+// ============================================================================
+
+/// Building registry constants
+pub mod buildings {
+    /// Path to the building definitions, relative to the workspace root
+    pub const BUILDINGS_DATA_PATH: &str = "dominion_earth/assets/data/buildings.ron";
+
+    /// Building every capital is founded with
+    pub const STARTING_BUILDING_TYPE: &str = "Granary";
+
+    /// Production of the city itself, before worked tiles and building bonuses
+    pub const CITY_BASE_PRODUCTION: f32 = 2.0;
+    pub const CITY_BASE_DEFENSE: f32 = 10.0;
+
+    /// Stats used for building types missing from the registry
+    pub const FALLBACK_GOLD_COST: f32 = 50.0;
+    pub const FALLBACK_PRODUCTION_COST: f32 = 40.0;
+    pub const FALLBACK_UPKEEP: f32 = 2.5;
+}
+
+// ============================================================================
+// CITY FOUNDING
+// ============================================================================
+
+/// Rules for settlers founding new cities
+pub mod settlement {
+    /// Unit type the AI trains when it wants to expand
+    pub const SETTLER_UNIT_TYPE: &str = "Settler";
+
+    /// Special ability (see `units.ron`) that lets a unit found a city
+    pub const FOUND_CITY_ABILITY: &str = "FoundCity";
+
+    /// Minimum Manhattan distance between two cities
+    pub const MIN_CITY_DISTANCE: i32 = 4;
+
+    /// Tiles within this Manhattan distance of a new city join its territory
+    pub const CITY_CLAIM_RADIUS: i32 = 1;
+
+    /// How far around an expansion target to look for a valid city site
+    pub const SITE_SEARCH_RADIUS: i32 = 6;
+
+    /// Population of a newly founded city
+    pub const FOUNDED_CITY_POPULATION: u32 = 1000;
+}
+
+// ============================================================================
+// POPULATION
+// ============================================================================
+
+/// Food, growth and starvation of city populations
+pub mod population {
+    /// Population that makes up one citizen; each citizen works one tile
+    pub const POPULATION_PER_CITIZEN: u32 = 1000;
+
+    /// Food every citizen eats each turn
+    pub const FOOD_PER_CITIZEN: f32 = 2.0;
+
+    /// Stored food a city needs to gain a citizen, plus the per-citizen increase
+    pub const GROWTH_FOOD_BASE: f32 = 15.0;
+    pub const GROWTH_FOOD_PER_CITIZEN: f32 = 6.0;
+
+    /// Buildings can never cut the food needed to grow by more than this share
+    pub const MAX_GROWTH_THRESHOLD_REDUCTION: f32 = 0.75;
+
+    /// Starvation never shrinks a city below this population
+    pub const MIN_CITY_POPULATION: u32 = 1000;
+}
+
+// ============================================================================
+// TERRITORY
+// ============================================================================
+
+/// Culture-driven border growth around cities
+pub mod territory {
+    /// Culture every city produces each turn
+    pub const BASE_CITY_CULTURE: f32 = 1.0;
+
+    /// Extra culture per 1000 population
+    pub const CULTURE_PER_THOUSAND_POPULATION: f32 = 0.5;
+
+    /// Extra culture per building level
+    pub const CULTURE_PER_BUILDING_LEVEL: f32 = 0.5;
+
+    /// Culture needed to push a border of radius `r` out to `r + 1` is this times `r`
+    pub const BORDER_GROWTH_COST_PER_RADIUS: f32 = 10.0;
+
+    /// Borders never grow past this Manhattan distance from their city
+    pub const MAX_BORDER_RADIUS: i32 = 4;
+}
+
+// ============================================================================
+// MARKET
+// ============================================================================
+
+/// World market repricing and economic events
+pub mod market {
+    /// Price, as a multiple of the base price, of a resource every civilization holds
+    pub const ABUNDANT_PRICE_FACTOR: f32 = 0.5;
+
+    /// Extra price multiple when no civilization holds the resource
+    pub const SCARCITY_PRICE_WEIGHT: f32 = 1.0;
+
+    /// Extra price multiple per trade route carrying the resource, per civilization
+    pub const TRADE_DEMAND_PRICE_WEIGHT: f32 = 0.5;
+
+    /// Prices stay within these multiples of the base price
+    pub const MIN_PRICE_FACTOR: f32 = 0.25;
+    pub const MAX_PRICE_FACTOR: f32 = 4.0;
+
+    /// Share of the gap to the target price closed each turn
+    pub const PRICE_ADJUSTMENT_RATE: f32 = 0.25;
+
+    /// Chance each turn that a new economic event starts
+    pub const ECONOMIC_EVENT_CHANCE_PER_TURN: f64 = 0.05;
+
+    /// Price multiplier range of a resource boom
+    pub const BOOM_MAGNITUDE_MIN: f32 = 1.3;
+    pub const BOOM_MAGNITUDE_MAX: f32 = 2.0;
+
+    /// Price multiplier range of a resource crash
+    pub const CRASH_MAGNITUDE_MIN: f32 = 0.4;
+    pub const CRASH_MAGNITUDE_MAX: f32 = 0.75;
+
+    /// Trade route value multiplier range of a trade disruption
+    pub const DISRUPTION_MAGNITUDE_MIN: f32 = 0.3;
+    pub const DISRUPTION_MAGNITUDE_MAX: f32 = 0.7;
+
+    /// Turns an economic event lasts
+    pub const EVENT_DURATION_MIN: u32 = 5;
+    pub const EVENT_DURATION_MAX: u32 = 15;
+}
+
+// ============================================================================
+// TRADE
+// ============================================================================
+
+/// Trade routes between the cities of civilizations with a trade agreement
+pub mod trade {
+    /// Routes a civilization can send out at once
+    pub const MAX_TRADE_ROUTES_PER_CIV: usize = 3;
+
+    /// Longest route caravans will travel, in tiles
+    pub const MAX_TRADE_ROUTE_LENGTH: usize = 30;
+
+    /// Gold a fully secure route pays each end per turn, before distance and goods
+    pub const BASE_TRADE_ROUTE_VALUE: f32 = 1.0;
+
+    /// Extra gold per tile of route, scaled by the traded resource's price
+    pub const TRADE_VALUE_PER_TILE: f32 = 0.05;
+
+    /// Resource price at which the per-tile value applies unscaled
+    pub const REFERENCE_RESOURCE_PRICE: f32 = 10.0;
+
+    /// Security lost each turn for every hostile unit standing on the route
+    pub const SECURITY_LOSS_PER_HOSTILE_UNIT: f32 = 0.25;
+
+    /// Security regained each turn the route is clear of hostile units
+    pub const SECURITY_RECOVERY_PER_TURN: f32 = 0.1;
+
+    /// A pillaged route hands the raider this many turns of its full value
+    pub const PILLAGE_LOOT_TURNS: f32 = 3.0;
+}
+
+// ============================================================================
+// LOGISTICS
+// ============================================================================
+
+/// Supply lines, marching fatigue and attrition of units in the field
+pub mod logistics {
+    /// Fatigue gained for every movement point spent
+    pub const FATIGUE_PER_MOVEMENT_POINT: f32 = 0.05;
+
+    /// Fatigue shed each turn by units outside friendly territory
+    pub const FIELD_FATIGUE_RECOVERY: f32 = 0.05;
+
+    /// Supply used each turn by a unit just outside friendly territory
+    pub const BASE_SUPPLY_DRAIN: f32 = 0.05;
+
+    /// Extra supply used each turn for every tile further from friendly territory
+    pub const SUPPLY_DRAIN_PER_TILE: f32 = 0.02;
+
+    /// Supply lines stop getting longer beyond this many tiles
+    pub const MAX_SUPPLY_DISTANCE: i32 = 10;
+
+    /// Below this supply a unit starts to suffer attrition
+    pub const LOW_SUPPLY_THRESHOLD: f32 = 0.3;
+
+    /// Share of maximum health lost each turn by a unit with no supply left
+    pub const ATTRITION_HEALTH_LOSS: f32 = 0.1;
+
+    /// Decay gained each turn by a unit with no supply left
+    pub const ATTRITION_DECAY: f32 = 0.05;
+}
+
+// ============================================================================
+// LOYALTY
+// ============================================================================
+
+/// Loyalty and corruption of units, and what unreliable units do about it
+pub mod loyalty {
+    /// Loyalty lost each turn while the civilization cannot pay its upkeep
+    pub const UNPAID_LOYALTY_LOSS: f32 = 0.1;
+
+    /// Loyalty lost each turn for every tile a unit campaigns beyond its borders
+    pub const CAMPAIGN_LOYALTY_LOSS_PER_TILE: f32 = 0.005;
+
+    /// Loyalty regained each turn by paid units inside their borders
+    pub const LOYALTY_RECOVERY_PER_TURN: f32 = 0.05;
+
+    /// Corruption a unit drifts towards for every tile it stands from its capital
+    pub const CORRUPTION_PER_TILE_FROM_CAPITAL: f32 = 0.025;
+
+    /// Share of the gap to that corruption closed each turn
+    pub const CORRUPTION_ADJUSTMENT_RATE: f32 = 0.1;
+
+    /// Chance each turn that an unreliable unit deserts
+    pub const DESERTION_CHANCE: f32 = 0.1;
+
+    /// Chance each turn that an unreliable unit defects to a neighboring civilization
+    pub const DEFECTION_CHANCE: f32 = 0.05;
+
+    /// Furthest a foreign border can be for a unit to defect to its owner, in tiles
+    pub const DEFECTION_RANGE: i32 = 5;
+
+    /// Loyalty a defector starts out with under its new owner
+    pub const DEFECTOR_LOYALTY: f32 = 0.6;
 }
 
 pub mod combat {
@@ -231,6 +550,7 @@ pub mod combat {
     pub const COMBAT_FATIGUE_INCREASE: f32 = 0.1;
     pub const VICTOR_MORALE_INCREASE: f32 = 0.1;
     pub const DEFEATED_MORALE_DECREASE: f32 = 0.15;
+    pub const DEFEATED_LOYALTY_DECREASE: f32 = 0.1;
     pub const HEAVY_CASUALTY_THRESHOLD: f32 = 0.5;
     pub const HEAVY_CASUALTY_MORALE_PENALTY: f32 = 0.3;
     pub const MAXIMUM_MORALE: f32 = 1.0;
@@ -240,13 +560,6 @@ pub mod combat {
     pub const ARCHER_RANGE: u32 = 2;
     pub const SIEGE_RANGE: u32 = 3;
     pub const NAVAL_BOMBARDMENT_RANGE: u32 = 2;
-    pub const CAVALRY_VS_ARCHER_BONUS: f32 = 1.5;
-    pub const CAVALRY_VS_SIEGE_BONUS: f32 = 1.3;
-    pub const INFANTRY_VS_CAVALRY_BONUS: f32 = 1.3;
-    pub const ARCHER_VS_INFANTRY_BONUS: f32 = 1.2;
-    pub const SIEGE_VS_ALL_BONUS: f32 = 1.4;
-    pub const NAVAL_VS_NAVAL_MULTIPLIER: f32 = 1.0;
-    pub const DEFAULT_TYPE_EFFECTIVENESS: f32 = 1.0;
     pub const SIEGE_UNIT_BONUS: f32 = 0.5;
     pub const FORTIFICATION_DEFENSE_BONUS: f32 = 0.3;
     pub const STARVATION_PENALTY_TURNS_THRESHOLD: u32 = 10;
@@ -257,6 +570,11 @@ pub mod combat {
     pub const SIEGE_BOMBARDMENT_DEFENDER_CASUALTIES: f32 = 0.05;
     pub const PROLONGED_SIEGE_TURNS_THRESHOLD: u32 = 20;
     pub const PROLONGED_SIEGE_SURRENDER_CHANCE: f32 = 0.3;
+
+    /// Special ability (see `units.ron`) that lets a unit bombard cities from range
+    pub const BOMBARDMENT_ABILITY: &str = "CityBombardment";
+    /// Damage multiplier against city defenses when the ability does not set one
+    pub const DEFAULT_BOMBARDMENT_MULTIPLIER: f32 = 1.0;
 }
 
 pub mod unit_stats {
@@ -270,5 +588,40 @@ pub mod unit_stats {
     pub const LOYALTY_THRESHOLD: f32 = 0.5;
     pub const CORRUPTION_THRESHOLD: f32 = 0.7;
     pub const SUPPLY_MODIFIER_MINIMUM: f32 = 0.5;
-    pub const EXPERIENCE_BONUS_MULTIPLIER: f32 = 0.05;
+    pub const FATIGUE_DEFENSE_PENALTY_MULTIPLIER: f32 = 0.25;
+    pub const SUPPLY_COMBAT_BASE: f32 = 0.6;
+    pub const SUPPLY_COMBAT_RANGE: f32 = 0.4;
+}
+
+/// Veterancy levels units rise through as they gain combat experience
+pub mod veterancy {
+    /// Experience needed to reach each level above recruit
+    pub const REGULAR_EXPERIENCE: f32 = 0.15;
+    pub const VETERAN_EXPERIENCE: f32 = 0.45;
+    pub const ELITE_EXPERIENCE: f32 = 0.9;
+
+    /// Extra combat strength for every level above recruit
+    pub const COMBAT_BONUS_PER_LEVEL: f32 = 0.1;
+}
+
+/// Standing orders units keep from turn to turn
+pub mod unit_orders {
+    /// Turns a unit can spend digging in; each one counts as a fortification level
+    pub const MAX_FORTIFICATION_LEVEL: u32 = 2;
+}
+
+/// Naval movement and transport of land units
+pub mod naval {
+    /// Special ability (see `units.ron`) that lets a ship carry land units
+    pub const TRANSPORT_ABILITY: &str = "CarryLandUnits";
+
+    /// Land units a transport carries when its ability does not set a capacity
+    pub const DEFAULT_TRANSPORT_CAPACITY: u32 = 2;
+}
+
+/// Tile occupancy, stacking and zones of control
+pub mod occupancy {
+    /// Military units of one civilization a tile can hold when the game config does not
+    /// set a stacking limit
+    pub const DEFAULT_MAX_UNITS_PER_TILE: u32 = 3;
 }
@@ -1,8 +1,8 @@
-use crate::{CivId, Treaty, DiplomaticRelation, DiplomaticState, CivPersonality};
-use crate::resources::{Negotiation, DiplomaticProposal, DiplomaticEventType};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use crate::constants::diplomacy;
+use crate::resources::{DiplomaticEventType, DiplomaticProposal, Negotiation};
+use crate::{CivId, CivPersonality, DiplomaticAction, DiplomaticRelation, DiplomaticState, Treaty};
 use rand::Rng;
+use std::collections::HashMap;
 
 /// Diplomatic system for AI decision making
 pub struct DiplomaticSystem;
@@ -18,7 +18,7 @@ impl DiplomaticSystem {
         rng: &mut impl Rng,
     ) {
         // Process ongoing negotiations
-        Self::process_negotiations(diplomatic_state, civs, rng);
+        Self::process_negotiations(diplomatic_state, civs, turn, rng);
 
         // Update relation values based on recent events
         Self::update_relation_values(diplomatic_state, civs, turn);
@@ -27,33 +27,165 @@ impl DiplomaticSystem {
         Self::update_treaty_durations(diplomatic_state);
 
         // Generate new diplomatic events
-        Self::generate_diplomatic_events(diplomatic_state, civs, military_strengths, economic_powers, rng);
+        Self::generate_diplomatic_events(
+            diplomatic_state,
+            civs,
+            military_strengths,
+            economic_powers,
+            rng,
+        );
+
+        // Declare new wars and open peace talks in existing ones
+        Self::process_war_and_peace(diplomatic_state, civs, military_strengths, turn, rng);
+
+        // Events generated above don't know the turn yet
+        for event in diplomatic_state.diplomatic_events.iter_mut() {
+            if event.turn == 0 {
+                event.turn = turn;
+            }
+        }
     }
 
     fn process_negotiations(
         diplomatic_state: &mut DiplomaticState,
         civs: &HashMap<CivId, CivPersonality>,
+        turn: u32,
         rng: &mut impl Rng,
     ) {
-        diplomatic_state.ongoing_negotiations.retain_mut(|negotiation| {
+        let negotiations = std::mem::take(&mut diplomatic_state.ongoing_negotiations);
+
+        for mut negotiation in negotiations {
             negotiation.turns_remaining = negotiation.turns_remaining.saturating_sub(1);
-            
+
             if negotiation.turns_remaining == 0 {
                 // Evaluate if the negotiation succeeds
                 let success_chance = Self::calculate_negotiation_success_chance(
-                    negotiation,
+                    &negotiation,
                     civs,
                     &diplomatic_state.relations,
                 );
-                
+
                 if rng.gen::<f32>() < success_chance {
-                    Self::accept_proposal(negotiation, diplomatic_state);
+                    Self::accept_proposal(&negotiation, diplomatic_state, turn);
                 }
-                false // Remove completed negotiation
             } else {
-                true // Keep ongoing negotiation
+                diplomatic_state.ongoing_negotiations.push(negotiation);
             }
+        }
+    }
+
+    fn process_war_and_peace(
+        diplomatic_state: &mut DiplomaticState,
+        civs: &HashMap<CivId, CivPersonality>,
+        military_strengths: &HashMap<CivId, f32>,
+        turn: u32,
+        rng: &mut impl Rng,
+    ) {
+        let mut civ_ids: Vec<CivId> = civs.keys().copied().collect();
+        civ_ids.sort_by_key(|civ_id| civ_id.0);
+
+        for &civ_a in &civ_ids {
+            for &civ_b in &civ_ids {
+                if civ_a == civ_b {
+                    continue;
+                }
+
+                if diplomatic_state.is_at_war(civ_a, civ_b) {
+                    Self::consider_peace(diplomatic_state, civs, civ_a, civ_b, turn, rng);
+                } else {
+                    let war_likelihood = Self::calculate_war_likelihood(
+                        civ_a,
+                        civ_b,
+                        diplomatic_state,
+                        civs,
+                        military_strengths,
+                    );
+                    if rng.gen::<f32>()
+                        < war_likelihood * diplomacy::WAR_DECLARATION_CHANCE_PER_TURN
+                    {
+                        Self::declare_war(diplomatic_state, civ_a, civ_b, turn);
+                    }
+                }
+            }
+        }
+    }
+
+    fn declare_war(
+        diplomatic_state: &mut DiplomaticState,
+        aggressor: CivId,
+        target: CivId,
+        turn: u32,
+    ) {
+        let Some(relation) = diplomatic_state.get_relation_mut(aggressor, target) else {
+            return;
+        };
+
+        relation
+            .treaties
+            .retain(|treaty| matches!(treaty, Treaty::TradePact { .. }));
+        relation.treaties.push(Treaty::War { started_turn: turn });
+        relation.relation_value =
+            (relation.relation_value - diplomacy::WAR_DECLARATION_RELATION_PENALTY).max(-100.0);
+
+        diplomatic_state.ongoing_negotiations.retain(|negotiation| {
+            DiplomaticState::relation_key(negotiation.initiator, negotiation.target)
+                != DiplomaticState::relation_key(aggressor, target)
         });
+
+        diplomatic_state
+            .diplomatic_events
+            .push(crate::DiplomaticEvent {
+                event_type: DiplomaticEventType::WarDeclared,
+                involved_civs: vec![aggressor, target],
+                turn,
+            });
+    }
+
+    fn consider_peace(
+        diplomatic_state: &mut DiplomaticState,
+        civs: &HashMap<CivId, CivPersonality>,
+        civ_a: CivId,
+        civ_b: CivId,
+        turn: u32,
+        rng: &mut impl Rng,
+    ) {
+        let war_started = diplomatic_state
+            .get_relation(civ_a, civ_b)
+            .and_then(|relation| {
+                relation.treaties.iter().find_map(|treaty| match treaty {
+                    Treaty::War { started_turn } => Some(*started_turn),
+                    _ => None,
+                })
+            });
+        let Some(started_turn) = war_started else {
+            return;
+        };
+
+        if turn.saturating_sub(started_turn) < diplomacy::MIN_WAR_DURATION_TURNS {
+            return;
+        }
+
+        let already_negotiating = diplomatic_state
+            .ongoing_negotiations
+            .iter()
+            .any(|negotiation| {
+                matches!(negotiation.proposal, DiplomaticProposal::PeaceTreaty)
+                    && DiplomaticState::relation_key(negotiation.initiator, negotiation.target)
+                        == DiplomaticState::relation_key(civ_a, civ_b)
+            });
+        if already_negotiating {
+            return;
+        }
+
+        let honor = civs.get(&civ_a).map(|p| p.honor_treaties).unwrap_or(0.0);
+        if rng.gen::<f32>() < honor * diplomacy::PEACE_PROPOSAL_CHANCE_PER_TURN {
+            diplomatic_state.ongoing_negotiations.push(Negotiation {
+                initiator: civ_a,
+                target: civ_b,
+                proposal: DiplomaticProposal::PeaceTreaty,
+                turns_remaining: diplomacy::PEACE_NEGOTIATION_TURNS,
+            });
+        }
     }
 
     fn calculate_negotiation_success_chance(
@@ -63,13 +195,15 @@ impl DiplomaticSystem {
     ) -> f32 {
         let initiator_personality = civs.get(&negotiation.initiator);
         let target_personality = civs.get(&negotiation.target);
-        
+
         if let (Some(initiator), Some(target)) = (initiator_personality, target_personality) {
-            let relation_key = (negotiation.initiator, negotiation.target);
-            let current_relation = relations.get(&relation_key)
+            let relation_key =
+                DiplomaticState::relation_key(negotiation.initiator, negotiation.target);
+            let current_relation = relations
+                .get(&relation_key)
                 .map(|r| r.relation_value)
                 .unwrap_or(0.0);
-            
+
             let base_chance = match negotiation.proposal {
                 DiplomaticProposal::TradePact => 0.6,
                 DiplomaticProposal::NonAggressionPact => 0.4,
@@ -78,27 +212,32 @@ impl DiplomaticSystem {
                 DiplomaticProposal::TechnologyExchange(_) => 0.3,
                 DiplomaticProposal::ResourceTrade(_, _) => 0.7,
             };
-            
+
             let relation_modifier = current_relation / 100.0; // -1.0 to 1.0
             let personality_modifier = match negotiation.proposal {
                 DiplomaticProposal::TradePact => target.industry_focus * 0.5,
                 DiplomaticProposal::NonAggressionPact => target.honor_treaties * 0.3,
-                DiplomaticProposal::Alliance => (target.honor_treaties + target.interventionism) * 0.25,
+                DiplomaticProposal::Alliance => {
+                    (target.honor_treaties + target.interventionism) * 0.25
+                }
                 DiplomaticProposal::PeaceTreaty => target.honor_treaties * 0.4,
                 DiplomaticProposal::TechnologyExchange(_) => target.tech_focus * 0.4,
                 DiplomaticProposal::ResourceTrade(_, _) => target.industry_focus * 0.3,
             };
-            
+
             (base_chance + relation_modifier * 0.5 + personality_modifier * 0.3).clamp(0.0, 1.0)
         } else {
             0.0
         }
     }
 
-    fn accept_proposal(negotiation: &Negotiation, diplomatic_state: &mut DiplomaticState) {
-        let relation_key = (negotiation.initiator, negotiation.target);
-        let relation = diplomatic_state.relations.get_mut(&relation_key);
-        
+    fn accept_proposal(
+        negotiation: &Negotiation,
+        diplomatic_state: &mut DiplomaticState,
+        turn: u32,
+    ) {
+        let relation = diplomatic_state.get_relation_mut(negotiation.initiator, negotiation.target);
+
         if let Some(relation) = relation {
             match &negotiation.proposal {
                 DiplomaticProposal::TradePact => {
@@ -106,33 +245,41 @@ impl DiplomaticSystem {
                     relation.relation_value += 10.0;
                 }
                 DiplomaticProposal::NonAggressionPact => {
-                    relation.treaties.push(Treaty::NonAggression { turns_remaining: 50 });
+                    relation.treaties.push(Treaty::NonAggression {
+                        turns_remaining: 50,
+                    });
                     relation.relation_value += 15.0;
                 }
                 DiplomaticProposal::Alliance => {
-                    relation.treaties.push(Treaty::Alliance { turns_remaining: 100 });
+                    relation.treaties.push(Treaty::Alliance {
+                        turns_remaining: 100,
+                    });
                     relation.relation_value += 25.0;
                 }
                 DiplomaticProposal::PeaceTreaty => {
                     // Remove war status
-                    relation.treaties.retain(|treaty| !matches!(treaty, Treaty::War { .. }));
+                    relation
+                        .treaties
+                        .retain(|treaty| !matches!(treaty, Treaty::War { .. }));
                     relation.relation_value += 20.0;
                 }
                 _ => {}
             }
         }
-        
+
         // Add diplomatic event
-        diplomatic_state.diplomatic_events.push(crate::DiplomaticEvent {
-            event_type: match negotiation.proposal {
-                DiplomaticProposal::Alliance => DiplomaticEventType::AllianceFormed,
-                DiplomaticProposal::PeaceTreaty => DiplomaticEventType::PeaceSigned,
-                DiplomaticProposal::TradePact => DiplomaticEventType::TradeAgreementSigned,
-                _ => DiplomaticEventType::TradeAgreementSigned, // Generic for other agreements
-            },
-            involved_civs: vec![negotiation.initiator, negotiation.target],
-            turn: 0, // Will be set by caller
-        });
+        diplomatic_state
+            .diplomatic_events
+            .push(crate::DiplomaticEvent {
+                event_type: match negotiation.proposal {
+                    DiplomaticProposal::Alliance => DiplomaticEventType::AllianceFormed,
+                    DiplomaticProposal::PeaceTreaty => DiplomaticEventType::PeaceSigned,
+                    DiplomaticProposal::TradePact => DiplomaticEventType::TradeAgreementSigned,
+                    _ => DiplomaticEventType::TradeAgreementSigned, // Generic for other agreements
+                },
+                involved_civs: vec![negotiation.initiator, negotiation.target],
+                turn,
+            });
     }
 
     fn update_relation_values(
@@ -147,35 +294,40 @@ impl DiplomaticSystem {
             } else if relation.relation_value < 0.0 {
                 relation.relation_value = (relation.relation_value + 0.5).min(0.0);
             }
-            
+
             // Personality-based relationship drift
-            if let (Some(civ_a_personality), Some(civ_b_personality)) = 
-                (civs.get(&relation.civ_a), civs.get(&relation.civ_b)) {
-                
-                let compatibility = Self::calculate_personality_compatibility(civ_a_personality, civ_b_personality);
+            if let (Some(civ_a_personality), Some(civ_b_personality)) =
+                (civs.get(&relation.civ_a), civs.get(&relation.civ_b))
+            {
+                let compatibility =
+                    Self::calculate_personality_compatibility(civ_a_personality, civ_b_personality);
                 relation.relation_value += compatibility * 0.1;
             }
-            
+
             // Clamp relation values
             relation.relation_value = relation.relation_value.clamp(-100.0, 100.0);
         }
     }
 
-    fn calculate_personality_compatibility(personality_a: &CivPersonality, personality_b: &CivPersonality) -> f32 {
+    fn calculate_personality_compatibility(
+        personality_a: &CivPersonality,
+        personality_b: &CivPersonality,
+    ) -> f32 {
         let mut compatibility = 0.0;
-        
+
         // Similar tech focus improves relations
         compatibility += 1.0 - (personality_a.tech_focus - personality_b.tech_focus).abs();
-        
+
         // Different interventionism levels can cause friction
-        compatibility -= (personality_a.interventionism - personality_b.interventionism).abs() * 0.5;
-        
+        compatibility -=
+            (personality_a.interventionism - personality_b.interventionism).abs() * 0.5;
+
         // High honor_treaties on both sides improves relations
         compatibility += (personality_a.honor_treaties + personality_b.honor_treaties) * 0.25;
-        
+
         // Opposing militarism levels cause tension
         compatibility -= (personality_a.militarism - personality_b.militarism).abs() * 0.3;
-        
+
         compatibility.clamp(-1.0, 1.0)
     }
 
@@ -209,23 +361,29 @@ impl DiplomaticSystem {
         rng: &mut impl Rng,
     ) {
         // Randomly generate diplomatic incidents
-        if rng.gen_bool(0.02) { // 2% chance per turn
-            let civ_ids: Vec<_> = civs.keys().cloned().collect();
+        if rng.gen_bool(0.02) {
+            // 2% chance per turn
+            let mut civ_ids: Vec<_> = civs.keys().cloned().collect();
+            civ_ids.sort_by_key(|civ_id| civ_id.0);
             if civ_ids.len() >= 2 {
                 let civ_a = civ_ids[rng.gen_range(0..civ_ids.len())];
                 let civ_b = civ_ids[rng.gen_range(0..civ_ids.len())];
-                
+
                 if civ_a != civ_b {
                     let event = Self::generate_random_diplomatic_event(
-                        civ_a, civ_b, civs, military_strengths, economic_powers, rng
+                        civ_a,
+                        civ_b,
+                        civs,
+                        military_strengths,
+                        economic_powers,
+                        rng,
                     );
-                    
+
                     if let Some(event) = event {
                         diplomatic_state.diplomatic_events.push(event.clone());
-                        
+
                         // Apply event effects to relations
-                        let relation_key = (civ_a, civ_b);
-                        if let Some(relation) = diplomatic_state.relations.get_mut(&relation_key) {
+                        if let Some(relation) = diplomatic_state.get_relation_mut(civ_a, civ_b) {
                             Self::apply_event_to_relation(&event, relation);
                         }
                     }
@@ -244,14 +402,14 @@ impl DiplomaticSystem {
     ) -> Option<crate::DiplomaticEvent> {
         let personality_a = civs.get(&civ_a)?;
         let personality_b = civs.get(&civ_b)?;
-        
+
         let event_types = [
             DiplomaticEventType::DiplomaticInsult,
             DiplomaticEventType::TradeAgreementSigned,
         ];
-        
+
         let event_type = event_types[rng.gen_range(0..event_types.len())].clone();
-        
+
         // Check if event makes sense given personalities
         let event_probability = match event_type {
             DiplomaticEventType::DiplomaticInsult => {
@@ -262,7 +420,7 @@ impl DiplomaticSystem {
             }
             _ => 0.1,
         };
-        
+
         if rng.gen::<f32>() < event_probability {
             Some(crate::DiplomaticEvent {
                 event_type,
@@ -304,49 +462,48 @@ impl DiplomaticSystem {
         civs: &HashMap<CivId, CivPersonality>,
         military_strengths: &HashMap<CivId, f32>,
     ) -> f32 {
-        let relation_key = (civ_a, civ_b);
-        let relation = diplomatic_state.relations.get(&relation_key);
-        
+        let relation = diplomatic_state.get_relation(civ_a, civ_b);
+
         if let Some(relation) = relation {
             // Check for existing treaties
             for treaty in &relation.treaties {
                 match treaty {
                     Treaty::NonAggression { .. } => return 0.0, // Can't declare war
-                    Treaty::Alliance { .. } => return 0.0, // Allies don't fight
-                    Treaty::War { .. } => return 0.0, // Already at war
+                    Treaty::Alliance { .. } => return 0.0,      // Allies don't fight
+                    Treaty::War { .. } => return 0.0,           // Already at war
                     _ => {}
                 }
             }
-            
+
             let relation_value = relation.relation_value;
             let personality_a = civs.get(&civ_a);
             let personality_b = civs.get(&civ_b);
-            
+
             if let (Some(personality_a), Some(personality_b)) = (personality_a, personality_b) {
                 let mut war_likelihood = 0.0;
-                
+
                 // Bad relations increase war likelihood
                 if relation_value < -20.0 {
                     war_likelihood += (-relation_value - 20.0) / 80.0; // 0.0 to 1.0
                 }
-                
+
                 // Militaristic civilizations more likely to declare war
                 war_likelihood += personality_a.militarism * 0.3;
-                
+
                 // High land hunger increases aggression
                 war_likelihood += personality_a.land_hunger * 0.2;
-                
+
                 // Low honor treaties increases likelihood
                 war_likelihood += (1.0 - personality_a.honor_treaties) * 0.2;
-                
+
                 // Military strength comparison
                 let strength_a = military_strengths.get(&civ_a).unwrap_or(&0.0);
                 let strength_b = military_strengths.get(&civ_b).unwrap_or(&0.0);
-                
+
                 if *strength_a > *strength_b * 1.5 {
                     war_likelihood += 0.3; // Strong vs weak
                 }
-                
+
                 war_likelihood.clamp(0.0, 1.0)
             } else {
                 0.0
@@ -366,16 +523,15 @@ impl DiplomaticSystem {
     ) -> Vec<DiplomaticRecommendation> {
         let mut recommendations = Vec::new();
         let personality = civs.get(&civ_id);
-        
+
         if let Some(personality) = personality {
             for &other_civ in civs.keys() {
                 if other_civ == civ_id {
                     continue;
                 }
-                
-                let relation_key = (civ_id, other_civ);
-                let relation = diplomatic_state.relations.get(&relation_key);
-                
+
+                let relation = diplomatic_state.get_relation(civ_id, other_civ);
+
                 if let Some(relation) = relation {
                     // Recommend trade agreements for economic civs
                     if personality.industry_focus > 0.6 && !relation.trade_agreement {
@@ -385,10 +541,13 @@ impl DiplomaticSystem {
                             priority: personality.industry_focus,
                         });
                     }
-                    
+
                     // Recommend alliances for friendly relations
                     if relation.relation_value > 30.0 && personality.honor_treaties > 0.5 {
-                        let has_alliance = relation.treaties.iter().any(|t| matches!(t, Treaty::Alliance { .. }));
+                        let has_alliance = relation
+                            .treaties
+                            .iter()
+                            .any(|t| matches!(t, Treaty::Alliance { .. }));
                         if !has_alliance {
                             recommendations.push(DiplomaticRecommendation {
                                 target: other_civ,
@@ -397,11 +556,15 @@ impl DiplomaticSystem {
                             });
                         }
                     }
-                    
+
                     // Recommend war for aggressive civs with bad relations
                     if relation.relation_value < -30.0 && personality.militarism > 0.6 {
                         let war_likelihood = Self::calculate_war_likelihood(
-                            civ_id, other_civ, diplomatic_state, civs, military_strengths
+                            civ_id,
+                            other_civ,
+                            diplomatic_state,
+                            civs,
+                            military_strengths,
                         );
                         if war_likelihood > 0.3 {
                             recommendations.push(DiplomaticRecommendation {
@@ -414,7 +577,7 @@ impl DiplomaticSystem {
                 }
             }
         }
-        
+
         recommendations.sort_by(|a, b| b.priority.partial_cmp(&a.priority).unwrap());
         recommendations
     }
@@ -427,13 +590,205 @@ pub struct DiplomaticRecommendation {
     pub priority: f32,
 }
 
-#[derive(Debug, Clone)]
-pub enum DiplomaticAction {
-    ProposeTradePact,
-    ProposeAlliance,
-    ProposeNonAggression,
-    DeclareWar,
-    OfferPeace,
-    SendGift,
-    DemandTribute,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64;
+
+    fn peaceful() -> CivPersonality {
+        CivPersonality {
+            land_hunger: 0.0,
+            militarism: 0.0,
+            honor_treaties: 1.0,
+            ..Default::default()
+        }
+    }
+
+    fn personalities(
+        civ_ids: &[CivId],
+        personality: CivPersonality,
+    ) -> HashMap<CivId, CivPersonality> {
+        civ_ids
+            .iter()
+            .map(|&civ_id| (civ_id, personality.clone()))
+            .collect()
+    }
+
+    fn seeded_state(civ_ids: &[CivId]) -> DiplomaticState {
+        let mut diplomatic_state = DiplomaticState::default();
+        diplomatic_state.seed_relations(civ_ids);
+        diplomatic_state
+    }
+
+    #[test]
+    fn test_seed_relations_adds_each_pair_once() {
+        let mut diplomatic_state = seeded_state(&[CivId(0), CivId(1), CivId(2)]);
+        assert_eq!(diplomatic_state.relations.len(), 3);
+        assert!(diplomatic_state
+            .relations
+            .values()
+            .all(|relation| relation.relation_value == diplomacy::INITIAL_RELATION_VALUE));
+
+        diplomatic_state
+            .get_relation_mut(CivId(2), CivId(0))
+            .unwrap()
+            .relation_value = 40.0;
+        diplomatic_state.seed_relations(&[CivId(0), CivId(1), CivId(2), CivId(3)]);
+
+        assert_eq!(diplomatic_state.relations.len(), 6);
+        let relation = diplomatic_state.get_relation(CivId(0), CivId(2)).unwrap();
+        assert_eq!((relation.civ_a, relation.civ_b), (CivId(0), CivId(2)));
+        assert_eq!(relation.relation_value, 40.0);
+    }
+
+    #[test]
+    fn test_update_diplomacy_signs_peace_when_negotiations_finish() {
+        let civ_ids = [CivId(0), CivId(1)];
+        let mut diplomatic_state = seeded_state(&civ_ids);
+        let relation = diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap();
+        relation.relation_value = 100.0;
+        relation.treaties.push(Treaty::War { started_turn: 0 });
+        diplomatic_state.ongoing_negotiations.push(Negotiation {
+            initiator: CivId(0),
+            target: CivId(1),
+            proposal: DiplomaticProposal::PeaceTreaty,
+            turns_remaining: 1,
+        });
+
+        DiplomaticSystem::update_diplomacy(
+            &mut diplomatic_state,
+            &personalities(&civ_ids, peaceful()),
+            &HashMap::new(),
+            &HashMap::new(),
+            12,
+            &mut Pcg64::seed_from_u64(1),
+        );
+
+        assert!(!diplomatic_state.is_at_war(CivId(0), CivId(1)));
+        assert!(diplomatic_state.ongoing_negotiations.is_empty());
+        let event = &diplomatic_state.diplomatic_events[0];
+        assert!(matches!(event.event_type, DiplomaticEventType::PeaceSigned));
+        assert_eq!(event.turn, 12);
+    }
+
+    #[test]
+    fn test_update_diplomacy_expires_treaties_but_not_wars() {
+        let civ_ids = [CivId(0), CivId(1), CivId(2)];
+        let mut diplomatic_state = seeded_state(&civ_ids);
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap()
+            .treaties = vec![
+            Treaty::NonAggression { turns_remaining: 1 },
+            Treaty::TradePact { turns_remaining: 2 },
+        ];
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(2))
+            .unwrap()
+            .treaties = vec![Treaty::War { started_turn: 5 }];
+
+        DiplomaticSystem::update_diplomacy(
+            &mut diplomatic_state,
+            &personalities(&civ_ids, peaceful()),
+            &HashMap::new(),
+            &HashMap::new(),
+            6,
+            &mut Pcg64::seed_from_u64(2),
+        );
+
+        let treaties = &diplomatic_state
+            .get_relation(CivId(0), CivId(1))
+            .unwrap()
+            .treaties;
+        assert!(matches!(
+            treaties.as_slice(),
+            [Treaty::TradePact { turns_remaining: 1 }]
+        ));
+        assert!(diplomatic_state.is_at_war(CivId(0), CivId(2)));
+        // The war is too young for peace talks
+        assert!(diplomatic_state.ongoing_negotiations.is_empty());
+    }
+
+    #[test]
+    fn test_hostile_civilizations_eventually_declare_war() {
+        let civ_ids = [CivId(0), CivId(1)];
+        let mut diplomatic_state = seeded_state(&civ_ids);
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap()
+            .treaties
+            .push(Treaty::TradePact {
+                turns_remaining: 100,
+            });
+        let warlike = CivPersonality {
+            militarism: 1.0,
+            land_hunger: 1.0,
+            honor_treaties: 0.0,
+            ..Default::default()
+        };
+        let civs = personalities(&civ_ids, warlike);
+        let mut rng = Pcg64::seed_from_u64(3);
+
+        let mut turn = 0;
+        while !diplomatic_state.is_at_war(CivId(0), CivId(1)) && turn < 100 {
+            turn += 1;
+            DiplomaticSystem::update_diplomacy(
+                &mut diplomatic_state,
+                &civs,
+                &HashMap::new(),
+                &HashMap::new(),
+                turn,
+                &mut rng,
+            );
+        }
+
+        assert!(diplomatic_state.is_at_war(CivId(0), CivId(1)));
+        let relation = diplomatic_state.get_relation(CivId(0), CivId(1)).unwrap();
+        // Trade pacts survive the declaration
+        assert!(relation
+            .treaties
+            .iter()
+            .any(|treaty| matches!(treaty, Treaty::TradePact { .. })));
+        let declaration = diplomatic_state.diplomatic_events.last().unwrap();
+        assert!(matches!(
+            declaration.event_type,
+            DiplomaticEventType::WarDeclared
+        ));
+        assert_eq!(declaration.turn, turn);
+    }
+
+    #[test]
+    fn test_pacts_rule_out_war() {
+        let civ_ids = [CivId(0), CivId(1)];
+        let mut diplomatic_state = seeded_state(&civ_ids);
+        let relation = diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap();
+        relation.relation_value = -100.0;
+        let civs = personalities(&civ_ids, CivPersonality::default());
+        let strengths = HashMap::new();
+
+        let likelihood = |diplomatic_state: &DiplomaticState| {
+            DiplomaticSystem::calculate_war_likelihood(
+                CivId(0),
+                CivId(1),
+                diplomatic_state,
+                &civs,
+                &strengths,
+            )
+        };
+
+        assert_eq!(likelihood(&diplomatic_state), 1.0);
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap()
+            .treaties
+            .push(Treaty::NonAggression {
+                turns_remaining: 10,
+            });
+        assert_eq!(likelihood(&diplomatic_state), 0.0);
+    }
 }
@@ -1,294 +1,273 @@
-use crate::{CivId, Position, Economy, GameResource, GlobalEconomy, TradeRoute, Building, BuildingType, City};
-use crate::constants::economy;
-use std::collections::HashMap;
-use serde::{Deserialize, Serialize};
-
-/// Economic calculations and updates
+use crate::constants::{economy, market, trade};
+use crate::resources::Resource;
+use crate::{
+    Building, BuildingRegistry, City, CivId, Economy, MilitaryUnit, TerrainRegistry, UnitRegistry,
+    WorldMap,
+};
+
+/// Economic calculations for the per-turn treasury update
 pub struct EconomicSystem;
 
 impl EconomicSystem {
-    /// Update the economy for all civilizations
-    pub fn update_economy(
-        civilizations: &mut bevy_ecs::system::Query<(bevy_ecs::entity::Entity, &mut Economy, &CivId)>,
-        cities: &bevy_ecs::system::Query<(&City, &Position)>,
-        buildings: &bevy_ecs::system::Query<(&Building, &Position)>,
-        trade_routes: &bevy_ecs::system::Query<&TradeRoute>,
-        global_economy: &mut bevy_ecs::system::ResMut<GlobalEconomy>,
-    ) {
-        // Update resource production for each civilization
-        for (entity, mut economy, civ_id) in civilizations.iter_mut() {
-            Self::calculate_production(&mut economy, civ_id, cities, buildings);
-            Self::calculate_maintenance_costs(&mut economy, buildings, civ_id);
-            Self::update_treasury(&mut economy);
-        }
+    /// Gold a city yields per turn from its size and buildings
+    pub fn city_income(city: &City, building_registry: &BuildingRegistry) -> f32 {
+        let population_income =
+            city.population as f32 / 1000.0 * economy::GOLD_PER_THOUSAND_POPULATION;
+        let building_income: f32 = city
+            .buildings
+            .iter()
+            .map(|building| {
+                building_registry
+                    .definition(&building.building_type)
+                    .gold_yield_at(building.level)
+            })
+            .sum();
 
-        // Update global trade and resource prices
-        Self::update_global_trade(&mut global_economy, trade_routes);
-        Self::update_resource_prices(&mut global_economy);
+        economy::CITY_BASE_GOLD + population_income + building_income
     }
 
-    /// Calculate resource production for a civilization
-    fn calculate_production(
-        economy: &mut Economy,
-        civ_id: &CivId,
-        cities: &bevy_ecs::system::Query<(&City, &Position)>,
-        buildings: &bevy_ecs::system::Query<(&Building, &Position)>,
-    ) {
-        let mut base_production = HashMap::new();
-        base_production.insert(GameResource::Gold, economy::BASE_GOLD_PRODUCTION);
-        base_production.insert(GameResource::Wheat, economy::BASE_WHEAT_PRODUCTION);
-
-        // Add production from cities
-        for (city, _position) in cities.iter() {
-            if city.owner == *civ_id {
-                for (resource, amount) in &city.resource_yields {
-                    *base_production.entry(resource.clone()).or_insert(economy::DEFAULT_RESOURCE_AMOUNT) += amount;
-                }
-            }
-        }
-
-        // Add production from buildings
-        for (building, _position) in buildings.iter() {
-            if building.owner == *civ_id {
-                let building_production = Self::get_building_production(&building.building_type);
-                for (resource, amount) in building_production {
-                    *base_production.entry(resource).or_insert(economy::DEFAULT_RESOURCE_AMOUNT) += amount;
-                }
-            }
-        }
-
-        economy.resource_production = base_production;
-        
-        // Calculate total production value
-        economy.total_production = economy.resource_production.values().sum();
+    /// Gold a civilization yields per turn from the tiles it owns
+    pub fn territory_income(
+        world_map: &WorldMap,
+        terrain_registry: &TerrainRegistry,
+        civ_id: CivId,
+    ) -> f32 {
+        world_map
+            .tiles
+            .iter()
+            .flatten()
+            .filter(|tile| tile.owner == Some(civ_id))
+            .map(|tile| {
+                let (_, _, terrain_gold) = terrain_registry.yields(&tile.terrain);
+                let resource_bonus = match tile.resource {
+                    Some(Resource::Gold) | Some(Resource::Spices) => {
+                        economy::LUXURY_RESOURCE_GOLD_BONUS
+                    }
+                    _ => 0.0,
+                };
+                terrain_gold * economy::TILE_GOLD_YIELD_RATE + resource_bonus
+            })
+            .sum()
     }
 
-    /// Calculate maintenance costs for buildings and units
-    fn calculate_maintenance_costs(
-        economy: &mut Economy,
-        buildings: &bevy_ecs::system::Query<(&Building, &Position)>,
-        civ_id: &CivId,
-    ) {
-        let mut total_maintenance = 0.0;
-
-        // Building maintenance
-        for (building, _position) in buildings.iter() {
-            if building.owner == *civ_id {
-                total_maintenance += Self::get_building_maintenance(&building.building_type);
-            }
-        }
+    /// Gold a civilization yields per turn from its trade routes
+    pub fn trade_income(economy: &Economy) -> f32 {
+        economy.trade_routes.iter().map(|route| route.value).sum()
+    }
 
-        economy.maintenance_costs = total_maintenance;
+    /// Gold a fully secure trade route of `route_length` tiles carrying goods worth
+    /// `resource_price` pays each end per turn
+    pub fn trade_route_volume(route_length: usize, resource_price: f32) -> f32 {
+        trade::BASE_TRADE_ROUTE_VALUE
+            + route_length as f32
+                * trade::TRADE_VALUE_PER_TILE
+                * (resource_price / trade::REFERENCE_RESOURCE_PRICE)
     }
 
-    /// Update civilization treasury
-    fn update_treasury(economy: &mut Economy) {
-        let income = economy.resource_production.get(&GameResource::Gold).unwrap_or(&0.0);
-        let net_income = income - economy.maintenance_costs;
-        economy.treasury = (economy.treasury + net_income).max(0.0);
+    /// Price `resource` is heading towards when `holder_share` of civilizations hold it and
+    /// `trade_share` trade routes per civilization carry it
+    pub fn market_price_target(
+        resource: Resource,
+        holder_share: f32,
+        trade_share: f32,
+        event_multiplier: f32,
+    ) -> f32 {
+        let factor = market::ABUNDANT_PRICE_FACTOR
+            + market::SCARCITY_PRICE_WEIGHT * (1.0 - holder_share)
+            + market::TRADE_DEMAND_PRICE_WEIGHT * trade_share;
+        (resource.base_price() * factor * event_multiplier).clamp(
+            resource.base_price() * market::MIN_PRICE_FACTOR,
+            resource.base_price() * market::MAX_PRICE_FACTOR,
+        )
     }
 
-    /// Update global trade and resource circulation
-    fn update_global_trade(
-        global_economy: &mut GlobalEconomy,
-        trade_routes: &bevy_ecs::system::Query<&TradeRoute>,
-    ) {
-        let mut total_trade_volume = 0.0;
-
-        for trade_route in trade_routes.iter() {
-            if trade_route.active {
-                total_trade_volume += trade_route.gold_per_turn;
-            }
-        }
+    /// Upkeep of every building in a city
+    pub fn building_upkeep(city: &City, building_registry: &BuildingRegistry) -> f32 {
+        city.buildings
+            .iter()
+            .map(|building| Self::single_building_upkeep(building, building_registry))
+            .sum()
+    }
 
-        global_economy.total_trade_volume = total_trade_volume;
+    /// Upkeep of a single building at its current level
+    pub fn single_building_upkeep(
+        building: &Building,
+        building_registry: &BuildingRegistry,
+    ) -> f32 {
+        building_registry
+            .definition(&building.building_type)
+            .upkeep_at(building.level)
     }
 
-    /// Update resource prices based on supply and demand
-    fn update_resource_prices(global_economy: &mut GlobalEconomy) {
-        for (resource, price) in global_economy.resource_prices.iter_mut() {
-            let base_price = Self::get_base_resource_price(resource);
-            let demand_factor = global_economy.resource_demand.get(resource).unwrap_or(&1.0);
-            let supply_factor = global_economy.resource_supply.get(resource).unwrap_or(&1.0);
-            
-            let new_price = base_price * (demand_factor / supply_factor.max(0.1));
-            *price = (*price * 0.8 + new_price * 0.2).max(base_price * 0.5).min(base_price * 3.0);
-        }
+    /// Upkeep of a single unit
+    pub fn unit_upkeep(unit: &MilitaryUnit, unit_registry: &UnitRegistry) -> f32 {
+        unit_registry.definition(&unit.unit_type).maintenance_cost()
     }
 
-    fn get_base_resource_price(resource: &GameResource) -> f32 {
-        match resource {
-            GameResource::Iron => 12.0,
-            GameResource::Gold => 50.0,
-            GameResource::Horses => 20.0,
-            GameResource::Wheat => 5.0,
-            GameResource::Fish => 3.0,
-            GameResource::Stone => 8.0,
-            GameResource::Wood => 6.0,
-            GameResource::Spices => 25.0,
-        }
+    /// Gold recovered by selling a building off
+    pub fn building_sale_value(building: &Building, building_registry: &BuildingRegistry) -> f32 {
+        building_registry
+            .definition(&building.building_type)
+            .gold_cost
+            * building.level as f32
+            * economy::BUILDING_SALE_REFUND_RATE
     }
 
-    /// Get building production yields
-    fn get_building_production(building_type: &BuildingType) -> HashMap<GameResource, f32> {
-        let mut production = HashMap::new();
-        
-        match building_type {
-            BuildingType::Granary => {
-                production.insert(GameResource::Wheat, 2.0);
-            }
-            BuildingType::Market => {
-                production.insert(GameResource::Gold, 3.0);
-            }
-            BuildingType::Workshop => {
-                production.insert(GameResource::Wood, 1.5);
-                production.insert(GameResource::Stone, 1.0);
-            }
-            BuildingType::Library => {
-                production.insert(GameResource::Gold, 1.0);
-            }
-            BuildingType::Barracks => {}
-            BuildingType::Temple => {
-                production.insert(GameResource::Gold, 1.5);
-            }
-            BuildingType::Walls => {}
-        }
-        
-        production
+    /// Record this turn's income and expenses and settle the treasury
+    pub fn apply_turn(economy: &mut Economy, income: f32, expenses: f32) {
+        economy.income = income;
+        economy.expenses = expenses;
+        economy.gold += income - expenses;
     }
 
-    /// Get building maintenance costs
-    fn get_building_maintenance(building_type: &BuildingType) -> f32 {
-        match building_type {
-            BuildingType::Granary => 1.0,
-            BuildingType::Market => 2.0,
-            BuildingType::Workshop => 2.5,
-            BuildingType::Library => 2.0,
-            BuildingType::Barracks => 1.5,
-            BuildingType::Temple => 1.0,
-            BuildingType::Walls => 0.5,
-        }
+    /// Net gold change per turn
+    pub fn net_income(economy: &Economy) -> f32 {
+        economy.income - economy.expenses
     }
 
     /// Check if civilization can afford something
     pub fn can_afford(economy: &Economy, cost: f32) -> bool {
-        economy.treasury >= cost
+        economy.gold >= cost
     }
 
     /// Spend gold from treasury
     pub fn spend_gold(economy: &mut Economy, amount: f32) -> bool {
         if Self::can_afford(economy, amount) {
-            economy.treasury -= amount;
+            economy.gold -= amount;
             true
         } else {
             false
         }
     }
 
-    /// Calculate strategic resource availability
-    pub fn get_strategic_resources(economy: &Economy) -> Vec<GameResource> {
-        let strategic_resources = [
-            GameResource::Iron,
-            GameResource::Gold,
-            GameResource::Horses,
-            GameResource::Wheat,
-            GameResource::Fish,
-            GameResource::Stone,
-            GameResource::Wood,
-            GameResource::Spices,
-        ];
-
-        strategic_resources
-            .iter()
-            .filter(|resource| {
-                economy.resource_production.get(resource).unwrap_or(&0.0) > &0.0
-            })
-            .cloned()
-            .collect()
+    /// Whether the treasury has run dry
+    pub fn is_bankrupt(economy: &Economy) -> bool {
+        economy.gold < 0.0
     }
+}
 
-    /// Calculate total civilization wealth
-    pub fn calculate_total_wealth(
-        economy: &Economy,
-        global_economy: &GlobalEconomy,
-    ) -> f32 {
-        let mut total_wealth = economy.treasury;
-
-        // Add value of resource stockpiles
-        for (resource, amount) in &economy.resource_production {
-            if let Some(price) = global_economy.resource_prices.get(resource) {
-                total_wealth += amount * price * 10.0; // Assume 10 turns of stockpile
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::terrain_registry;
+    use crate::{BuildingDefinition, BuildingType, Position, TerrainType};
+
+    fn market() -> BuildingRegistry {
+        BuildingRegistry {
+            building_types: vec![BuildingDefinition {
+                name: "Marketplace".to_string(),
+                description: String::new(),
+                gold_cost: 40.0,
+                production_cost: 30.0,
+                upkeep: 1.5,
+                production_bonus: 0.0,
+                defense_bonus: 0.0,
+                food_yield: 0.0,
+                gold_yield: 3.0,
+                research_yield: 0.0,
+                level_scaling: 0.5,
+                max_level: 3,
+                required_technologies: Vec::new(),
+                required_resources: Vec::new(),
+                fortification: false,
+                growth_threshold_reduction: 0.0,
+            }],
         }
-
-        total_wealth
     }
 
-    /// Calculate economic power rating
-    pub fn calculate_economic_power(
-        economy: &Economy,
-        global_economy: &GlobalEconomy,
-    ) -> f32 {
-        let wealth = Self::calculate_total_wealth(economy, global_economy);
-        let production = economy.total_production;
-        let efficiency = if economy.maintenance_costs > 0.0 {
-            production / economy.maintenance_costs
-        } else {
-            production
-        };
+    fn city_with_market(population: u32, level: u32) -> City {
+        let mut city = City::new("Test".to_string(), CivId(0));
+        city.population = population;
+        city.buildings = vec![Building {
+            building_type: BuildingType::new("Marketplace"),
+            level,
+        }];
+        city
+    }
 
-        (wealth * 0.3 + production * 0.5 + efficiency * 0.2) / 100.0
+    #[test]
+    fn test_city_income_grows_with_population_and_buildings() {
+        let building_registry = market();
+        let mut city = city_with_market(3000, 1);
+        let with_market = EconomicSystem::city_income(&city, &building_registry);
+        assert_eq!(
+            with_market,
+            economy::CITY_BASE_GOLD + 3.0 * economy::GOLD_PER_THOUSAND_POPULATION + 3.0
+        );
+
+        city.buildings[0].level = 3;
+        let upgraded = EconomicSystem::city_income(&city, &building_registry);
+        assert_eq!(upgraded - with_market, 3.0);
+
+        city.buildings.clear();
+        let bare = EconomicSystem::city_income(&city, &building_registry);
+        assert_eq!(with_market - bare, 3.0);
     }
 
-    /// Get trade route profitability
-    pub fn calculate_trade_route_value(
-        from_civ: &CivId,
-        to_civ: &CivId,
-        distance: f32,
-        global_economy: &GlobalEconomy,
-    ) -> f32 {
-        let base_value = 5.0;
-        let distance_penalty = (distance / 10.0).min(2.0);
-        let trade_multiplier = global_economy.total_trade_volume / 1000.0 + 1.0;
-        
-        (base_value * trade_multiplier - distance_penalty).max(1.0)
+    #[test]
+    fn test_building_upkeep_and_sale_value_scale_with_level() {
+        let building_registry = market();
+        let city = city_with_market(1000, 2);
+
+        let upkeep = EconomicSystem::building_upkeep(&city, &building_registry);
+        let sale_value =
+            EconomicSystem::building_sale_value(&city.buildings[0], &building_registry);
+
+        assert_eq!(upkeep, 3.0);
+        assert_eq!(sale_value, 80.0 * economy::BUILDING_SALE_REFUND_RATE);
     }
 
-    /// Create economic report for a civilization
-    pub fn generate_economic_report(economy: &Economy) -> EconomicReport {
-        EconomicReport {
-            treasury: economy.treasury,
-            total_production: economy.total_production,
-            maintenance_costs: economy.maintenance_costs,
-            net_income: economy.resource_production.get(&GameResource::Gold).unwrap_or(&0.0) - economy.maintenance_costs,
-            resource_production: economy.resource_production.clone(),
+    #[test]
+    fn test_territory_income_counts_owned_tiles_and_luxuries() {
+        let terrain_registry = terrain_registry();
+        let mut world_map = WorldMap::new(3, 1);
+        let tiles = [
+            (TerrainType::River, CivId(0)),
+            (TerrainType::Desert, CivId(0)),
+            (TerrainType::Coast, CivId(1)),
+        ];
+        for (x, (terrain, owner)) in tiles.into_iter().enumerate() {
+            let tile = world_map.get_tile_mut(Position::new(x as i32, 0)).unwrap();
+            tile.terrain = terrain;
+            tile.owner = Some(owner);
         }
+
+        let (_, _, river_gold) = terrain_registry.yields(&TerrainType::River);
+        let (_, _, desert_gold) = terrain_registry.yields(&TerrainType::Desert);
+        let owned_income = (river_gold + desert_gold) * economy::TILE_GOLD_YIELD_RATE;
+        let income = |world_map: &WorldMap| {
+            EconomicSystem::territory_income(world_map, &terrain_registry, CivId(0))
+        };
+        assert!(owned_income > 0.0);
+        assert_eq!(income(&world_map), owned_income);
+
+        let luxury = world_map.get_tile_mut(Position::new(1, 0)).unwrap();
+        luxury.resource = Some(Resource::Spices);
+        assert_eq!(
+            income(&world_map),
+            owned_income + economy::LUXURY_RESOURCE_GOLD_BONUS
+        );
     }
-}
 
-/// Economic report for UI display
-#[derive(Debug, Clone)]
-pub struct EconomicReport {
-    pub treasury: f32,
-    pub total_production: f32,
-    pub maintenance_costs: f32,
-    pub net_income: f32,
-    pub resource_production: HashMap<GameResource, f32>,
-}
+    #[test]
+    fn test_apply_turn_settles_the_treasury() {
+        let mut treasury = Economy {
+            gold: 5.0,
+            ..Default::default()
+        };
 
-/// Market prices for UI
-#[derive(Debug, Clone)]
-pub struct MarketPrices {
-    pub resource_prices: HashMap<GameResource, f32>,
-    pub trade_goods: Vec<TradeGood>,
-}
+        EconomicSystem::apply_turn(&mut treasury, 12.0, 20.0);
+
+        assert_eq!(EconomicSystem::net_income(&treasury), -8.0);
+        assert_eq!(treasury.gold, -3.0);
+        assert!(EconomicSystem::is_bankrupt(&treasury));
+        assert!(!EconomicSystem::spend_gold(&mut treasury, 1.0));
 
-/// Tradeable goods
-#[derive(Debug, Clone)]
-pub struct TradeGood {
-    pub name: String,
-    pub resource_type: GameResource,
-    pub base_price: f32,
-    pub current_price: f32,
-    pub availability: f32,
+        EconomicSystem::apply_turn(&mut treasury, 20.0, 12.0);
+
+        assert_eq!(treasury.gold, 5.0);
+        assert!(!EconomicSystem::is_bankrupt(&treasury));
+        assert!(EconomicSystem::spend_gold(&mut treasury, 5.0));
+        assert_eq!(treasury.gold, 0.0);
+    }
 }
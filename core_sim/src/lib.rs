@@ -1,18 +1,29 @@
 use std::collections::HashMap;
 
+pub mod building_registry;
+pub mod civilization_spawning;
+pub mod combat;
 pub mod components;
 pub mod constants;
 pub mod data_loader;
 pub mod debug_utils;
+pub mod diplomacy;
+pub mod economy;
 pub mod influence_map;
 pub mod pathfinding;
 pub mod resource_loading;
 pub mod resources;
 pub mod systems;
+pub mod tech_tree;
+pub mod terrain_registry;
+pub mod unit_registry;
 pub mod world_gen;
 
 pub mod tile;
 
+#[cfg(test)]
+mod test_support;
+
 // Import specific components to avoid ambiguous glob reexports
 pub use components::{
     // Action queue components
@@ -21,7 +32,8 @@ pub use components::{
     ai::{AIAction, AIDecision, CivilizationData, DecisionType},
     // City components
     city::{
-        Building, BuildingType, Capital, CapitalAge, CapitalEvolutionRequirements, City, Territory,
+        BorderPressure, Building, BuildingType, Capital, CapitalAge, CapitalEvolutionRequirements,
+        City, CityGrowth, Siege, Territory,
     },
     // Civilization components
     civilization::{
@@ -32,9 +44,11 @@ pub use components::{
     // Fog of war components
     fog_of_war::{FogOfWarMaps, ProvidesVision, VisibilityMap, VisibilityState},
     // Military components
-    military::{MilitaryUnit, UnitType},
+    military::{
+        Embarked, MilitaryUnit, MovementDomain, Transport, UnitClass, UnitType, VeterancyLevel,
+    },
     // Orders components
-    orders::ActiveThisTurn,
+    orders::{ActiveThisTurn, SettleOrder, UnitOrder},
     // Player components
     player::{PlayerControlled, PlayerMovementOrder, SelectedUnit, UnitSelected},
     // Position components
@@ -55,8 +69,18 @@ pub use components::{
     CivId,
 };
 
+pub use building_registry::{BuildingDefinition, BuildingRegistry};
+pub use civilization_spawning::spawn_initial_civilizations;
+pub use combat::{
+    CombatCasualties, CombatResult, CombatSystem, CombatWinner, RangedAttackResult, SiegeResult,
+};
 pub use data_loader::{CivilizationDataCollection, CivilizationDataLoader, CivilizationDefinition};
 pub use debug_utils::CoreDebugUtils;
+pub use diplomacy::{DiplomaticRecommendation, DiplomaticSystem};
+pub use economy::EconomicSystem;
+pub use tech_tree::{EraDefinition, TechTree, TechnologyDefinition};
+pub use terrain_registry::{TerrainDefinition, TerrainRegistry};
+pub use unit_registry::{PromotionDefinition, PromotionEffect, UnitDefinition, UnitRegistry};
 
 // Import specific systems to avoid ambiguous glob reexports
 pub use systems::{
@@ -65,34 +89,68 @@ pub use systems::{
         process_civilization_action_queues, spawn_action_queues_for_new_civilizations,
     },
     ai_decision::*,
+    capital_evolution::{evolve_capitals, CapitalEvolved},
     combat_resolution::*,
+    diplomacy::{initialize_diplomatic_relations, update_diplomacy},
     economic_update::*,
     fog_of_war::{
         filter_visible_cities, filter_visible_units, get_explored_positions, get_visible_positions,
         initialize_fog_of_war_for_civ, is_position_explored, is_position_visible,
         update_fog_of_war,
     },
+    logistics::{update_unit_logistics, UnitLostToAttrition},
+    loyalty::{update_unit_loyalty, UnitMutinied},
+    market::{update_market, EconomicEventStarted},
     movement::{
-        clear_completed_movement_orders, execute_ai_movement_orders, execute_movement_orders,
+        clear_completed_movement_orders, execute_movement_orders, follow_movement_orders,
+        hostile_zone_of_control, path_arrival_turns, plan_movement_order, reachable_tiles,
     },
+    naval::{carry_embarked_units, find_crossing_point, naval_launch_site, UnitLostAtSea},
+    occupancy::{sync_tile_contents, update_tile_occupancy},
+    population::{grow_populations, PopulationChanged},
     production::{
         check_player_actions_complete, handle_player_production_orders, handle_skip_production,
-        initialize_production_queues, process_production_queues, reset_unit_movement,
-        PlayerProductionOrder, SkipProductionThisTurn,
+        initialize_production_queues, process_production_queues, queue_production,
+        reset_unit_movement, validate_production, PlayerProductionOrder, ProductionError,
+        SkipProductionThisTurn,
+    },
+    promotions::{heal_promoted_units, promote_ai_units, promote_unit, UnitPromoted},
+    ranged_combat::{
+        has_line_of_sight, resolve_ranged_attacks, validate_ranged_attack, RangedAttackRequested,
+        RangedAttackResolved, RangedAttackTarget,
+    },
+    research::{advance_research, TechnologyResearched},
+    settlement::{
+        advance_settlers, find_city_site_near, found_city, is_settler, validate_city_site,
+        CityFounded,
+    },
+    siege::{resolve_sieges, CityCaptured},
+    strategic_resources::update_resource_access,
+    territory::update_territory,
+    trade::{
+        can_trade, establish_trade_route, propose_trade_pact, update_trade_routes,
+        TradeRoutePillaged,
     },
     turn_management::{
         auto_advance_turn_system, handle_ai_turn_completion, handle_ai_turn_processing,
         handle_turn_advance_requests, handle_turn_transition_complete, ProductionUpdated,
         RequestTurnAdvance,
     },
+    unit_orders::{advance_unit_orders, validate_unit_order, wake_sentries, SentryAlerted},
 };
 
 // Import specific items from resources to avoid Resource trait conflict
 pub use resources::{
+    CivTerritories,
     DiplomaticEvent,
     DiplomaticState,
+    EconomicEvent,
+    EconomicEventType,
     GlobalEconomy,
+    GlobalTradeRoute,
     Resource as GameResource, // Rename to avoid conflict with bevy_ecs::Resource
+    TileOccupancy,
+    TileOccupant,
     WorldMap,
 };
 
@@ -102,6 +160,10 @@ pub struct GameState {
     pub turn: u32,
     pub civilizations: HashMap<CivId, components::ai::CivilizationData>,
     pub current_player: Option<CivId>,
+    /// Resource tiles no civilization owns yet
+    pub resource_sites: Vec<(Position, GameResource)>,
+    /// Current world market price of every resource
+    pub resource_prices: HashMap<GameResource, f32>,
 }
 
 // Manual Resource implementation
@@ -113,6 +175,8 @@ impl Default for GameState {
             turn: 1,
             civilizations: HashMap::new(),
             current_player: None,
+            resource_sites: Vec::new(),
+            resource_prices: HashMap::new(),
         }
     }
 }
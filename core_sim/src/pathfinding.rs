@@ -1,10 +1,10 @@
-use crate::{Position, WorldMap, resources::MapTile};
+use crate::{MovementDomain, Position, TerrainRegistry, WorldMap, resources::MapTile};
 use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::cmp::Ordering;
 
 /// A* pathfinding implementation optimized for the game world
 pub struct Pathfinder {
-    cache: HashMap<(Position, Position), Option<Vec<Position>>>,
+    cache: HashMap<(Position, Position, MovementDomain), Option<Vec<Position>>>,
 }
 
 impl Default for Pathfinder {
@@ -24,14 +24,16 @@ impl Pathfinder {
         self.cache.clear();
     }
 
-    pub fn find_path(&mut self, world_map: &WorldMap, start: Position, goal: Position, max_movement: f32) -> Option<Vec<Position>> {
+    /// Find the cheapest path from `start` to `goal` over the tiles units travelling in
+    /// `domain` can enter
+    pub fn find_path(&mut self, world_map: &WorldMap, terrain_registry: &TerrainRegistry, start: Position, goal: Position, domain: MovementDomain, max_movement: f32) -> Option<Vec<Position>> {
         // Check cache first
-        let cache_key = (start, goal);
+        let cache_key = (start, goal, domain);
         if let Some(cached_result) = self.cache.get(&cache_key) {
             return cached_result.clone();
         }
 
-        let result = self.a_star(world_map, start, goal, max_movement);
+        let result = self.a_star(world_map, terrain_registry, start, goal, domain, max_movement, &HashSet::new());
         
         // Cache the result
         self.cache.insert(cache_key, result.clone());
@@ -39,7 +41,13 @@ impl Pathfinder {
         result
     }
 
-    fn a_star(&self, world_map: &WorldMap, start: Position, goal: Position, max_movement: f32) -> Option<Vec<Position>> {
+    /// Find a path that steps around the `blocked` tiles, e.g. ones held by other units.
+    /// Results depend on `blocked`, so they are not cached.
+    pub fn find_path_avoiding(&self, world_map: &WorldMap, terrain_registry: &TerrainRegistry, start: Position, goal: Position, domain: MovementDomain, blocked: &HashSet<Position>) -> Option<Vec<Position>> {
+        self.a_star(world_map, terrain_registry, start, goal, domain, f32::MAX, blocked)
+    }
+
+    fn a_star(&self, world_map: &WorldMap, terrain_registry: &TerrainRegistry, start: Position, goal: Position, domain: MovementDomain, max_movement: f32, blocked: &HashSet<Position>) -> Option<Vec<Position>> {
         let mut open_set = BinaryHeap::new();
         let mut came_from: HashMap<Position, Position> = HashMap::new();
         let mut g_score: HashMap<Position, f32> = HashMap::new();
@@ -63,7 +71,7 @@ impl Pathfinder {
             closed_set.insert(current);
 
             for neighbor in world_map.neighbors(current) {
-                if closed_set.contains(&neighbor) {
+                if closed_set.contains(&neighbor) || blocked.contains(&neighbor) {
                     continue;
                 }
 
@@ -71,12 +79,12 @@ impl Pathfinder {
                     continue;
                 };
 
-                // Skip impassable terrain
-                if neighbor_tile.movement_cost >= 10.0 {
+                // Skip terrain the domain cannot enter
+                if !terrain_registry.can_enter(&neighbor_tile.terrain, domain) {
                     continue;
                 }
 
-                let tentative_g_score = g_score.get(&current).unwrap_or(&f32::INFINITY) + neighbor_tile.movement_cost;
+                let tentative_g_score = g_score.get(&current).unwrap_or(&f32::INFINITY) + terrain_registry.movement_cost(&neighbor_tile.terrain);
 
                 // Check if this path exceeds movement limit
                 if tentative_g_score > max_movement {
@@ -121,7 +129,7 @@ impl Pathfinder {
     }
 
     /// Find all positions reachable within movement range
-    pub fn find_reachable_positions(&self, world_map: &WorldMap, start: Position, max_movement: f32) -> HashSet<Position> {
+    pub fn find_reachable_positions(&self, world_map: &WorldMap, terrain_registry: &TerrainRegistry, start: Position, domain: MovementDomain, max_movement: f32) -> HashSet<Position> {
         let mut reachable = HashSet::new();
         let mut distances: HashMap<Position, f32> = HashMap::new();
         let mut open_set = BinaryHeap::new();
@@ -147,12 +155,12 @@ impl Pathfinder {
                     continue;
                 };
 
-                // Skip impassable terrain
-                if neighbor_tile.movement_cost >= 10.0 {
+                // Skip terrain the domain cannot enter
+                if !terrain_registry.can_enter(&neighbor_tile.terrain, domain) {
                     continue;
                 }
 
-                let new_distance = current_distance + neighbor_tile.movement_cost;
+                let new_distance = current_distance + terrain_registry.movement_cost(&neighbor_tile.terrain);
                 
                 if new_distance <= max_movement {
                     let current_neighbor_distance = *distances.get(&neighbor).unwrap_or(&f32::INFINITY);
@@ -1,11 +1,12 @@
 use crate::constants::{
-    coordinates, economy, game_flow, map_generation, movement_directions, terrain_stats,
+    coordinates, diplomacy, economy, game_flow, map_generation, movement_directions, occupancy,
+    terrain,
 };
-use crate::{CivId, DiplomaticRelation, Position, TerrainType};
+use crate::{CivId, DiplomaticRelation, Position, TerrainRegistry, TerrainType, Treaty};
 use bevy::prelude::Reflect;
 use bevy_ecs::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Global world map resource
 #[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
@@ -75,6 +76,20 @@ impl WorldMap {
             })
             .collect()
     }
+
+    /// Every tile that holds a resource but belongs to no civilization
+    pub fn unclaimed_resource_sites(&self) -> Vec<(Position, Resource)> {
+        (0..self.width as i32)
+            .flat_map(|x| (0..self.height as i32).map(move |y| Position::new(x, y)))
+            .filter_map(|position| {
+                let tile = self.get_tile(position)?;
+                match (tile.owner, tile.resource) {
+                    (None, Some(resource)) => Some((position, resource)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
@@ -95,13 +110,30 @@ impl Default for MapTile {
             owner: None,
             city: None,
             resource: None,
-            movement_cost: terrain_stats::BASE_MOVEMENT_COST,
-            defense_bonus: terrain_stats::BASE_DEFENSE_BONUS,
+            movement_cost: terrain::FALLBACK_MOVEMENT_COST,
+            defense_bonus: terrain::FALLBACK_DEFENSE_BONUS,
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+impl MapTile {
+    /// Unowned, empty tile with movement and defense taken from the terrain registry
+    pub fn new(terrain: TerrainType, terrain_registry: &TerrainRegistry) -> Self {
+        let mut tile = Self::default();
+        tile.set_terrain(terrain, terrain_registry);
+        tile
+    }
+
+    /// Change the terrain and refresh the stats derived from it
+    pub fn set_terrain(&mut self, terrain: TerrainType, terrain_registry: &TerrainRegistry) {
+        let definition = terrain_registry.definition(&terrain);
+        self.movement_cost = definition.movement_cost;
+        self.defense_bonus = definition.defense_bonus;
+        self.terrain = terrain;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
 pub enum Resource {
     Iron,
     Gold,
@@ -113,8 +145,220 @@ pub enum Resource {
     Spices,
 }
 
+impl Resource {
+    pub const ALL: [Resource; 8] = [
+        Resource::Iron,
+        Resource::Gold,
+        Resource::Horses,
+        Resource::Wheat,
+        Resource::Fish,
+        Resource::Stone,
+        Resource::Wood,
+        Resource::Spices,
+    ];
+
+    /// Market price before supply, demand and economic events
+    pub fn base_price(&self) -> f32 {
+        match self {
+            Resource::Iron => economy::IRON_BASE_PRICE,
+            Resource::Gold => economy::GOLD_BASE_PRICE,
+            Resource::Horses => economy::HORSES_BASE_PRICE,
+            Resource::Wheat => economy::WHEAT_BASE_PRICE,
+            Resource::Fish => economy::FISH_BASE_PRICE,
+            Resource::Stone => economy::STONE_BASE_PRICE,
+            Resource::Wood => economy::WOOD_BASE_PRICE,
+            Resource::Spices => economy::SPICES_BASE_PRICE,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Resource::Iron => "Iron",
+            Resource::Gold => "Gold",
+            Resource::Horses => "Horses",
+            Resource::Wheat => "Wheat",
+            Resource::Fish => "Fish",
+            Resource::Stone => "Stone",
+            Resource::Wood => "Wood",
+            Resource::Spices => "Spices",
+        }
+    }
+}
+
+/// The tiles each civilization owns, rebuilt from `MapTile::owner` whenever borders change
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CivTerritories {
+    tiles: HashMap<CivId, HashSet<Position>>,
+}
+
+impl CivTerritories {
+    pub fn from_world_map(world_map: &WorldMap) -> Self {
+        let mut tiles: HashMap<CivId, HashSet<Position>> = HashMap::new();
+        for x in 0..world_map.width as i32 {
+            for y in 0..world_map.height as i32 {
+                let position = Position::new(x, y);
+                if let Some(owner) = world_map.get_tile(position).and_then(|tile| tile.owner) {
+                    tiles.entry(owner).or_default().insert(position);
+                }
+            }
+        }
+        Self { tiles }
+    }
+
+    /// Every tile owned by `civ_id`
+    pub fn tiles_of(&self, civ_id: CivId) -> impl Iterator<Item = &Position> {
+        self.tiles.get(&civ_id).into_iter().flatten()
+    }
+
+    pub fn tile_count(&self, civ_id: CivId) -> usize {
+        self.tiles.get(&civ_id).map_or(0, HashSet::len)
+    }
+
+    pub fn owns(&self, civ_id: CivId, position: Position) -> bool {
+        self.tiles
+            .get(&civ_id)
+            .is_some_and(|tiles| tiles.contains(&position))
+    }
+
+    pub fn owner_of(&self, position: Position) -> Option<CivId> {
+        self.tiles
+            .iter()
+            .find(|(_, tiles)| tiles.contains(&position))
+            .map(|(civ_id, _)| *civ_id)
+    }
+
+    /// Civilizations that own at least one tile
+    pub fn civilizations(&self) -> impl Iterator<Item = &CivId> {
+        self.tiles.keys()
+    }
+}
+
+/// A unit standing on a tile, as recorded in the `TileOccupancy` index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TileOccupant {
+    pub entity: Entity,
+    pub owner: CivId,
+    /// Military units count towards the stacking limit and exert a zone of control, while
+    /// civilians and units embarked on a transport do neither
+    pub military: bool,
+}
+
+/// The units and cities on every tile, rebuilt from their `Position`s by
+/// `update_tile_occupancy` and kept current by the movement systems as units step
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TileOccupancy {
+    /// Military units of one civilization a tile can hold
+    pub max_units_per_tile: u32,
+    units: HashMap<Position, Vec<TileOccupant>>,
+    unit_positions: HashMap<Entity, Position>,
+    cities: HashMap<Position, (Entity, CivId)>,
+}
+
+impl Default for TileOccupancy {
+    fn default() -> Self {
+        Self {
+            max_units_per_tile: occupancy::DEFAULT_MAX_UNITS_PER_TILE,
+            units: HashMap::new(),
+            unit_positions: HashMap::new(),
+            cities: HashMap::new(),
+        }
+    }
+}
+
+impl TileOccupancy {
+    pub fn new(
+        max_units_per_tile: u32,
+        units: impl IntoIterator<Item = (TileOccupant, Position)>,
+        cities: impl IntoIterator<Item = (Entity, CivId, Position)>,
+    ) -> Self {
+        let mut occupancy = Self {
+            max_units_per_tile,
+            cities: cities
+                .into_iter()
+                .map(|(entity, owner, position)| (position, (entity, owner)))
+                .collect(),
+            ..Default::default()
+        };
+        for (occupant, position) in units {
+            occupancy.place_unit(occupant, position);
+        }
+        occupancy
+    }
+
+    pub fn units_at(&self, position: Position) -> &[TileOccupant] {
+        self.units.get(&position).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn city_at(&self, position: Position) -> Option<(Entity, CivId)> {
+        self.cities.get(&position).copied()
+    }
+
+    /// Military units `civ_id` has on `position`
+    pub fn stack_size(&self, civ_id: CivId, position: Position) -> u32 {
+        self.units_at(position)
+            .iter()
+            .filter(|occupant| occupant.owner == civ_id && occupant.military)
+            .count() as u32
+    }
+
+    /// The lowest-numbered civilization other than `civ_id` with units on `position`
+    pub fn foreign_owner_at(&self, civ_id: CivId, position: Position) -> Option<CivId> {
+        self.units_at(position)
+            .iter()
+            .map(|occupant| occupant.owner)
+            .filter(|owner| *owner != civ_id)
+            .min_by_key(|owner| owner.0)
+    }
+
+    /// Record `occupant` as standing on `position`, moving it off the tile it was on
+    pub fn place_unit(&mut self, occupant: TileOccupant, position: Position) {
+        if let Some(previous) = self.unit_positions.insert(occupant.entity, position) {
+            if let Some(occupants) = self.units.get_mut(&previous) {
+                occupants.retain(|other| other.entity != occupant.entity);
+                if occupants.is_empty() {
+                    self.units.remove(&previous);
+                }
+            }
+        }
+        self.units.entry(position).or_default().push(occupant);
+    }
+
+    /// Whether another military unit of `civ_id` fits on `position`
+    pub fn has_room(&self, civ_id: CivId, position: Position) -> bool {
+        self.stack_size(civ_id, position) < self.max_units_per_tile
+    }
+
+    /// Tiles units of `civ_id` cannot enter: ones held by other civilizations' units and
+    /// ones where its own military units already stand `max_units_per_tile` deep
+    pub fn blocked_tiles(&self, civ_id: CivId) -> HashSet<Position> {
+        self.units
+            .keys()
+            .filter(|position| {
+                self.foreign_owner_at(civ_id, **position).is_some()
+                    || !self.has_room(civ_id, **position)
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Tiles next to a military unit of a civilization `is_hostile` picks out, where
+    /// movement stops for the turn
+    pub fn zone_of_control(&self, is_hostile: impl Fn(CivId) -> bool) -> HashSet<Position> {
+        self.units
+            .iter()
+            .filter(|(_, occupants)| {
+                occupants
+                    .iter()
+                    .any(|occupant| occupant.military && is_hostile(occupant.owner))
+            })
+            .flat_map(|(position, _)| position.adjacent_positions())
+            .collect()
+    }
+}
+
 /// Global economy resource
-#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
 pub struct GlobalEconomy {
     pub trade_routes: Vec<GlobalTradeRoute>,
     pub resource_prices: HashMap<Resource, f32>,
@@ -123,15 +367,10 @@ pub struct GlobalEconomy {
 
 impl Default for GlobalEconomy {
     fn default() -> Self {
-        let mut resource_prices = HashMap::new();
-        resource_prices.insert(Resource::Iron, economy::IRON_BASE_PRICE);
-        resource_prices.insert(Resource::Gold, economy::GOLD_BASE_PRICE);
-        resource_prices.insert(Resource::Horses, economy::HORSES_BASE_PRICE);
-        resource_prices.insert(Resource::Wheat, economy::WHEAT_BASE_PRICE);
-        resource_prices.insert(Resource::Fish, economy::FISH_BASE_PRICE);
-        resource_prices.insert(Resource::Stone, economy::STONE_BASE_PRICE);
-        resource_prices.insert(Resource::Wood, economy::WOOD_BASE_PRICE);
-        resource_prices.insert(Resource::Spices, economy::SPICES_BASE_PRICE);
+        let resource_prices = Resource::ALL
+            .iter()
+            .map(|resource| (*resource, resource.base_price()))
+            .collect();
 
         Self {
             trade_routes: Vec::new(),
@@ -141,24 +380,82 @@ impl Default for GlobalEconomy {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl GlobalEconomy {
+    pub fn resource_price(&self, resource: Resource) -> f32 {
+        self.resource_prices
+            .get(&resource)
+            .copied()
+            .unwrap_or(economy::DEFAULT_RESOURCE_AMOUNT)
+    }
+
+    /// Combined price multiplier of the booms and crashes currently affecting `resource`
+    pub fn price_multiplier(&self, resource: Resource) -> f32 {
+        self.economic_events
+            .iter()
+            .filter(|event| {
+                matches!(
+                    event.event_type,
+                    EconomicEventType::ResourceBoom | EconomicEventType::ResourceCrash
+                ) && event.affected_resource == Some(resource)
+            })
+            .map(|event| event.magnitude)
+            .product()
+    }
+
+    /// Combined trade route value multiplier of the trade disruptions currently under way
+    pub fn trade_multiplier(&self) -> f32 {
+        self.economic_events
+            .iter()
+            .filter(|event| matches!(event.event_type, EconomicEventType::TradeDisruption))
+            .map(|event| event.magnitude)
+            .product()
+    }
+}
+
+/// A trade route between a city of `from_civ` and a city of `to_civ`
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct GlobalTradeRoute {
     pub from_civ: CivId,
     pub to_civ: CivId,
     pub resource: Resource,
+    /// Gold each end earns per turn while the route is fully secure
     pub volume: f32,
+    /// 0.0 - 1.0, share of the volume that gets through
     pub security_level: f32,
+    pub from_city: Position,
+    pub to_city: Position,
+    /// Tiles the caravans travel, from `from_city` to `to_city` inclusive
+    pub path: Vec<Position>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl GlobalTradeRoute {
+    /// Gold each end earns this turn
+    pub fn value(&self) -> f32 {
+        self.volume * self.security_level
+    }
+
+    pub fn involves(&self, civ_id: CivId) -> bool {
+        self.from_civ == civ_id || self.to_civ == civ_id
+    }
+
+    /// Whether the route runs between the cities at `city_a` and `city_b`, in either direction
+    pub fn connects(&self, city_a: Position, city_b: Position) -> bool {
+        (self.from_city == city_a && self.to_city == city_b)
+            || (self.from_city == city_b && self.to_city == city_a)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct EconomicEvent {
     pub event_type: EconomicEventType,
     pub affected_resource: Option<Resource>,
+    /// Multiplier applied to the affected price, or to trade route value for disruptions
     pub magnitude: f32,
+    /// Turns left before the event ends
     pub duration: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub enum EconomicEventType {
     ResourceBoom,
     ResourceCrash,
@@ -167,7 +464,8 @@ pub enum EconomicEventType {
 }
 
 /// Diplomatic state resource
-#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
 pub struct DiplomaticState {
     pub relations: HashMap<(CivId, CivId), DiplomaticRelation>,
     pub ongoing_negotiations: Vec<Negotiation>,
@@ -184,7 +482,57 @@ impl Default for DiplomaticState {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl DiplomaticState {
+    /// Relations are stored once per pair, keyed with the lower civ id first
+    pub fn relation_key(civ_a: CivId, civ_b: CivId) -> (CivId, CivId) {
+        if civ_a.0 <= civ_b.0 {
+            (civ_a, civ_b)
+        } else {
+            (civ_b, civ_a)
+        }
+    }
+
+    pub fn get_relation(&self, civ_a: CivId, civ_b: CivId) -> Option<&DiplomaticRelation> {
+        self.relations.get(&Self::relation_key(civ_a, civ_b))
+    }
+
+    pub fn get_relation_mut(
+        &mut self,
+        civ_a: CivId,
+        civ_b: CivId,
+    ) -> Option<&mut DiplomaticRelation> {
+        self.relations.get_mut(&Self::relation_key(civ_a, civ_b))
+    }
+
+    /// Add a neutral relation for every pair of civilizations that does not have one yet
+    pub fn seed_relations(&mut self, civ_ids: &[CivId]) {
+        for (index, &civ_a) in civ_ids.iter().enumerate() {
+            for &civ_b in &civ_ids[index + 1..] {
+                let key = Self::relation_key(civ_a, civ_b);
+                self.relations
+                    .entry(key)
+                    .or_insert_with(|| DiplomaticRelation {
+                        civ_a: key.0,
+                        civ_b: key.1,
+                        relation_value: diplomacy::INITIAL_RELATION_VALUE,
+                        treaties: Vec::new(),
+                        trade_agreement: false,
+                    });
+            }
+        }
+    }
+
+    pub fn is_at_war(&self, civ_a: CivId, civ_b: CivId) -> bool {
+        self.get_relation(civ_a, civ_b).is_some_and(|relation| {
+            relation
+                .treaties
+                .iter()
+                .any(|treaty| matches!(treaty, Treaty::War { .. }))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct Negotiation {
     pub initiator: CivId,
     pub target: CivId,
@@ -192,7 +540,7 @@ pub struct Negotiation {
     pub turns_remaining: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub enum DiplomaticProposal {
     TradePact,
     NonAggressionPact,
@@ -202,14 +550,14 @@ pub enum DiplomaticProposal {
     ResourceTrade(Resource, f32),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub struct DiplomaticEvent {
     pub event_type: DiplomaticEventType,
     pub involved_civs: Vec<CivId>,
     pub turn: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
 pub enum DiplomaticEventType {
     WarDeclared,
     PeaceSigned,
@@ -266,6 +614,8 @@ pub struct GameConfig {
     pub random_seed: u64,
     pub debug_logging: bool,
     pub ai_only: bool,
+    /// Military units of one civilization a tile can hold
+    pub max_units_per_tile: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Reflect)]
@@ -299,6 +649,7 @@ impl Default for GameConfig {
             random_seed,
             debug_logging: false,
             ai_only: false,
+            max_units_per_tile: occupancy::DEFAULT_MAX_UNITS_PER_TILE,
         }
     }
 }
@@ -322,3 +673,57 @@ impl Default for GameRng {
 /// Resource to request a turn advance (set by UI or timer)
 #[derive(Default, Resource)]
 pub struct TurnAdvanceRequest(pub bool);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn occupant(world: &mut World, owner: CivId, military: bool) -> TileOccupant {
+        TileOccupant {
+            entity: world.spawn_empty().id(),
+            owner,
+            military,
+        }
+    }
+
+    #[test]
+    fn test_blocked_tiles_are_foreign_or_full() {
+        let mut world = World::new();
+        let full = Position::new(0, 0);
+        let room_left = Position::new(1, 0);
+        let foreign = Position::new(2, 0);
+        let units = [
+            (occupant(&mut world, CivId(0), true), full),
+            (occupant(&mut world, CivId(0), true), full),
+            (occupant(&mut world, CivId(0), true), room_left),
+            (occupant(&mut world, CivId(0), false), room_left),
+            (occupant(&mut world, CivId(1), true), foreign),
+        ];
+        let occupancy = TileOccupancy::new(2, units, []);
+
+        assert_eq!(
+            occupancy.blocked_tiles(CivId(0)),
+            HashSet::from([full, foreign])
+        );
+        assert_eq!(
+            occupancy.blocked_tiles(CivId(1)),
+            HashSet::from([full, room_left])
+        );
+    }
+
+    #[test]
+    fn test_zone_of_control_surrounds_hostile_military_units() {
+        let mut world = World::new();
+        let garrison = Position::new(2, 2);
+        let units = [
+            (occupant(&mut world, CivId(1), true), garrison),
+            (occupant(&mut world, CivId(1), false), Position::new(5, 5)),
+            (occupant(&mut world, CivId(2), true), Position::new(8, 8)),
+        ];
+        let occupancy = TileOccupancy::new(2, units, []);
+
+        let zone = occupancy.zone_of_control(|owner| owner == CivId(1));
+        assert_eq!(zone, garrison.adjacent_positions().into_iter().collect());
+        assert!(occupancy.zone_of_control(|_| false).is_empty());
+    }
+}
@@ -1,8 +1,27 @@
 use crate::{
-    components::{AIAction, ActionQueue, CivId, Civilization, QueuedAction},
-    resources::CurrentTurn,
+    components::{
+        military::{MovementDomain, UnitClass},
+        position::MovementOrder,
+        production::{ProductionItem, ProductionQueue},
+        AIAction, ActionQueue, CivId, Civilization, QueuedAction,
+    },
+    constants::settlement,
+    resources::{CurrentTurn, DiplomaticState, GlobalEconomy, TileOccupancy},
+    systems::{
+        movement::plan_movement_order,
+        naval::{find_crossing_point, TransportBerths},
+        production::{queue_production, validate_production, ProductionError},
+        ranged_combat::{has_line_of_sight, validate_ranged_attack, RangedAttackRequested},
+        settlement::{find_city_site_near, is_settler},
+        trade::{can_trade, establish_trade_route, propose_trade_pact},
+    },
+    tech_tree::TechTree,
+    BuildingRegistry, BuildingType, Capital, City, CombatSystem, Embarked, GameResource,
+    MilitaryUnit, Position, SettleOrder, TerrainRegistry, Transport, UnitRegistry, UnitType,
+    WorldMap,
 };
 use bevy_ecs::prelude::*;
+use std::collections::HashSet;
 
 /// System to spawn action queues for new civilizations
 pub fn spawn_action_queues_for_new_civilizations(
@@ -17,20 +36,112 @@ pub fn spawn_action_queues_for_new_civilizations(
 
 /// System to process action queues for all civilizations each turn
 pub fn process_civilization_action_queues(
-    mut queue_query: Query<(Entity, &mut ActionQueue, &CivId)>,
+    mut queue_query: Query<(Entity, &mut ActionQueue, &CivId, &mut Civilization)>,
     current_turn: Res<CurrentTurn>,
+    tech_tree: Res<TechTree>,
+    world_map: Res<WorldMap>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
+    terrain_registry: Res<TerrainRegistry>,
+    mut diplomatic_state: ResMut<DiplomaticState>,
+    mut global_economy: ResMut<GlobalEconomy>,
+    occupancy: Res<TileOccupancy>,
+    idle_units: Query<
+        (Entity, &MilitaryUnit, &Position),
+        (Without<SettleOrder>, Without<MovementOrder>),
+    >,
+    units: Query<(
+        Entity,
+        &MilitaryUnit,
+        &Position,
+        Option<&Transport>,
+        Has<Embarked>,
+        Option<&MovementOrder>,
+    )>,
+    cities: Query<(&City, &Position)>,
+    mut capital_queues: Query<(&City, &Position, &mut ProductionQueue), With<Capital>>,
+    mut ranged_attack_requests: MessageWriter<RangedAttackRequested>,
     mut commands: Commands,
 ) {
     let current_turn_number = current_turn.0;
+    let city_sites: Vec<(CivId, Position)> = cities
+        .iter()
+        .map(|(city, position)| (city.owner, *position))
+        .collect();
+    let city_positions: Vec<Position> = city_sites.iter().map(|(_, position)| *position).collect();
+    let mut expansion = ExpansionContext {
+        world_map: &world_map,
+        terrain_registry: &terrain_registry,
+        occupancy: &occupancy,
+        tech_tree: &tech_tree,
+        unit_registry: &unit_registry,
+        building_registry: &building_registry,
+        idle_settlers: idle_units
+            .iter()
+            .filter(|(_, unit, _)| is_settler(unit, &unit_registry))
+            .map(|(entity, unit, position)| (entity, unit.owner, unit.id, *position))
+            .collect(),
+        city_positions: &city_positions,
+        capital_queues: capital_queues.iter_mut().collect(),
+    };
+    let mut maneuver = ManeuverContext {
+        world_map: &world_map,
+        terrain_registry: &terrain_registry,
+        occupancy: &occupancy,
+        idle_units: idle_units
+            .iter()
+            .filter(|(_, unit, _)| unit.unit_class != UnitClass::Civilian)
+            .map(|(entity, unit, position)| (entity, unit, *position))
+            .collect(),
+        transports: units
+            .iter()
+            .filter(|(_, _, _, transport, _, _)| transport.is_some())
+            .map(|(entity, ..)| entity)
+            .collect(),
+        embarked: units
+            .iter()
+            .filter(|(_, _, _, _, embarked, _)| *embarked)
+            .map(|(entity, ..)| entity)
+            .collect(),
+        berths: TransportBerths::new(units.iter().map(
+            |(_, unit, position, transport, embarked, _)| {
+                (unit.owner, *position, transport, embarked)
+            },
+        )),
+        inbound_transports: units
+            .iter()
+            .filter(|(_, _, _, transport, _, _)| transport.is_some())
+            .filter_map(|(_, unit, _, _, _, movement_order)| {
+                Some((unit.owner, movement_order?.destination))
+            })
+            .collect(),
+        ranged_attacks: Vec::new(),
+    };
+    let mut trade = TradeContext {
+        global_economy: &mut global_economy,
+        diplomatic_state: &mut diplomatic_state,
+        world_map: &world_map,
+        terrain_registry: &terrain_registry,
+        cities: &city_sites,
+    };
 
-    for (entity, mut action_queue, _civ_id) in queue_query.iter_mut() {
+    for (entity, mut action_queue, _civ_id, mut civilization) in queue_query.iter_mut() {
         action_queue.reset_turn_processing();
 
         let mut failed_actions = Vec::new();
 
         while action_queue.can_process_more_actions() {
             if let Some(queued_action) = action_queue.dequeue_next_action(current_turn_number) {
-                let execution_result = execute_queued_action(&queued_action, entity, &mut commands);
+                let execution_result = execute_queued_action(
+                    &queued_action,
+                    &mut civilization,
+                    &tech_tree,
+                    &mut expansion,
+                    &mut trade,
+                    &mut maneuver,
+                    entity,
+                    &mut commands,
+                );
 
                 if execution_result.is_ok() {
                     action_queue.increment_turn_processing();
@@ -46,6 +157,8 @@ pub fn process_civilization_action_queues(
             action_queue.requeue_failed_action(failed_action, current_turn_number);
         }
     }
+
+    ranged_attack_requests.write_batch(maneuver.ranged_attacks);
 }
 
 /// System to populate action queues with AI-generated decisions
@@ -70,31 +183,607 @@ pub fn populate_action_queues_from_ai_decisions(
     }
 }
 
+/// World state needed to carry out `AIAction::Expand`, `BuildUnit` and `BuildBuilding`
+struct ExpansionContext<'a> {
+    world_map: &'a WorldMap,
+    terrain_registry: &'a TerrainRegistry,
+    occupancy: &'a TileOccupancy,
+    tech_tree: &'a TechTree,
+    unit_registry: &'a UnitRegistry,
+    building_registry: &'a BuildingRegistry,
+    idle_settlers: Vec<(Entity, CivId, u32, Position)>,
+    city_positions: &'a [Position],
+    capital_queues: Vec<(&'a City, &'a Position, Mut<'a, ProductionQueue>)>,
+}
+
+/// World state needed to carry out `AIAction::Trade`
+struct TradeContext<'a> {
+    global_economy: &'a mut GlobalEconomy,
+    diplomatic_state: &'a mut DiplomaticState,
+    world_map: &'a WorldMap,
+    terrain_registry: &'a TerrainRegistry,
+    cities: &'a [(CivId, Position)],
+}
+
+/// World state needed to send units out for `AIAction::Attack`, `Explore` and `Defend`, and
+/// to ferry them and settlers to other landmasses
+struct ManeuverContext<'a> {
+    world_map: &'a WorldMap,
+    terrain_registry: &'a TerrainRegistry,
+    occupancy: &'a TileOccupancy,
+    idle_units: Vec<(Entity, &'a MilitaryUnit, Position)>,
+    transports: HashSet<Entity>,
+    embarked: HashSet<Entity>,
+    berths: TransportBerths,
+    /// Owner and destination of every transport under way
+    inbound_transports: Vec<(CivId, Position)>,
+    ranged_attacks: Vec<RangedAttackRequested>,
+}
+
 /// Execute a queued action - returns Ok if successful, Err if failed
 fn execute_queued_action(
     queued_action: &QueuedAction,
+    civilization: &mut Civilization,
+    tech_tree: &TechTree,
+    expansion: &mut ExpansionContext,
+    trade: &mut TradeContext,
+    maneuver: &mut ManeuverContext,
     _civ_entity: Entity,
-    _commands: &mut Commands,
+    commands: &mut Commands,
 ) -> Result<(), ActionExecutionError> {
     match &queued_action.action {
-        AIAction::BuildUnit { .. } => Ok(()),
+        AIAction::BuildUnit { unit_type, .. } => queue_in_capital(
+            civilization,
+            ProductionItem::Unit(unit_type.clone()),
+            expansion,
+        ),
 
-        AIAction::Research { .. } => Ok(()),
+        AIAction::Research { technology, .. } => {
+            if civilization.technologies.current_research.as_ref() == Some(technology) {
+                return Ok(());
+            }
+            if tech_tree.start_research(&mut civilization.technologies, technology) {
+                tracing::debug!(
+                    "Civ {} started researching {}",
+                    civilization.id.0,
+                    technology
+                );
+                Ok(())
+            } else {
+                Err(ActionExecutionError::InvalidTarget)
+            }
+        }
 
-        AIAction::Expand { .. } => Ok(()),
+        AIAction::Expand {
+            target_position, ..
+        } => execute_expand(
+            civilization,
+            *target_position,
+            expansion,
+            maneuver,
+            commands,
+        ),
 
-        AIAction::BuildBuilding { .. } => Ok(()),
+        AIAction::BuildBuilding { building_type, .. } => {
+            execute_build_building(civilization, building_type, expansion)
+        }
 
-        AIAction::Trade { .. } => Ok(()),
+        AIAction::Trade {
+            partner, resource, ..
+        } => execute_trade(civilization, *partner, *resource, trade),
 
-        AIAction::Attack { .. } => Ok(()),
+        AIAction::Attack {
+            target_position, ..
+        } => execute_attack(civilization, *target_position, maneuver, commands),
 
         AIAction::Diplomacy { .. } => Ok(()),
 
-        AIAction::Defend { .. } => Ok(()),
+        AIAction::Defend { position, .. } => {
+            execute_maneuver(civilization, *position, false, maneuver, commands)
+        }
+
+        AIAction::Explore {
+            target_position, ..
+        } => execute_maneuver(civilization, *target_position, false, maneuver, commands),
+    }
+}
+
+/// Send an idle settler towards the best site near `target_position`, ferrying it there when
+/// the site is on another landmass, or queue a new settler in the capital when the
+/// civilization has none available
+fn execute_expand(
+    civilization: &mut Civilization,
+    target_position: Position,
+    expansion: &mut ExpansionContext,
+    maneuver: &mut ManeuverContext,
+    commands: &mut Commands,
+) -> Result<(), ActionExecutionError> {
+    let idle_settler = expansion
+        .idle_settlers
+        .iter()
+        .position(|(_, owner, _, _)| *owner == civilization.id);
+
+    if let Some(index) = idle_settler {
+        let site = find_city_site_near(
+            expansion.world_map,
+            civilization.id,
+            target_position,
+            expansion.city_positions,
+        )
+        .ok_or(ActionExecutionError::InvalidTarget)?;
+
+        let (settler_entity, _, settler_id, settler_position) = expansion.idle_settlers[index];
+        let movement_order = plan_movement_order(
+            expansion.world_map,
+            expansion.terrain_registry,
+            settler_position,
+            site,
+            MovementDomain::Land,
+            &expansion.occupancy.blocked_tiles(civilization.id),
+        );
+        if movement_order.is_none() && settler_position != site {
+            execute_crossing(
+                civilization.id,
+                settler_entity,
+                settler_position,
+                site,
+                maneuver,
+                commands,
+            )?;
+            expansion.idle_settlers.swap_remove(index);
+            tracing::debug!(
+                "Civ {} is shipping settler {} towards ({}, {})",
+                civilization.id.0,
+                settler_id,
+                site.x,
+                site.y
+            );
+            return Ok(());
+        }
+
+        let mut settler_commands = commands.entity(settler_entity);
+        if let Some(movement_order) = movement_order {
+            settler_commands.insert(movement_order);
+        }
+        settler_commands.insert(SettleOrder { target: site });
+        expansion.idle_settlers.swap_remove(index);
+        tracing::debug!(
+            "Civ {} sent settler {} to found a city at ({}, {})",
+            civilization.id.0,
+            settler_id,
+            site.x,
+            site.y
+        );
+        return Ok(());
+    }
+
+    queue_in_capital(
+        civilization,
+        ProductionItem::Unit(UnitType::new(settlement::SETTLER_UNIT_TYPE)),
+        expansion,
+    )
+}
+
+/// Queue `building_type` in the civilization's capital. The planner does not consult the tech
+/// tree, so a building the capital cannot build yet is swapped for the first one in the
+/// `BuildingRegistry` that it can.
+fn execute_build_building(
+    civilization: &mut Civilization,
+    building_type: &BuildingType,
+    expansion: &mut ExpansionContext,
+) -> Result<(), ActionExecutionError> {
+    let (city, city_position, _) = expansion
+        .capital_queues
+        .iter()
+        .find(|(_, _, queue)| queue.owner == civilization.id)
+        .ok_or(ActionExecutionError::InvalidTarget)?;
+
+    let building_type = std::iter::once(building_type.clone())
+        .chain(
+            expansion
+                .building_registry
+                .building_types
+                .iter()
+                .map(|definition| BuildingType::new(&definition.name)),
+        )
+        .find(|candidate| {
+            validate_production(
+                &ProductionItem::Building(candidate.clone()),
+                civilization,
+                city,
+                **city_position,
+                expansion.world_map,
+                expansion.terrain_registry,
+                expansion.tech_tree,
+                expansion.unit_registry,
+                expansion.building_registry,
+            )
+            .is_ok()
+        })
+        .ok_or(ActionExecutionError::InvalidTarget)?;
+
+    queue_in_capital(
+        civilization,
+        ProductionItem::Building(building_type),
+        expansion,
+    )
+}
+
+/// Add `item` to the production queue of the civilization's capital through
+/// `queue_production`, as the production menu does. Items already queued are not queued twice.
+fn queue_in_capital(
+    civilization: &mut Civilization,
+    item: ProductionItem,
+    expansion: &mut ExpansionContext,
+) -> Result<(), ActionExecutionError> {
+    let Some((city, city_position, production_queue)) = expansion
+        .capital_queues
+        .iter_mut()
+        .find(|(_, _, queue)| queue.owner == civilization.id)
+    else {
+        return Err(ActionExecutionError::InvalidTarget);
+    };
+
+    if production_queue.current_production.as_ref() == Some(&item)
+        || production_queue.queue.contains(&item)
+    {
+        return Ok(());
+    }
+
+    let item_name = item.name().to_string();
+    queue_production(
+        item,
+        civilization,
+        city,
+        **city_position,
+        production_queue,
+        expansion.world_map,
+        expansion.terrain_registry,
+        expansion.tech_tree,
+        expansion.unit_registry,
+        expansion.building_registry,
+    )
+    .map_err(|error| {
+        tracing::debug!(
+            "Civ {} cannot queue {}: {}",
+            civilization.id.0,
+            item_name,
+            error
+        );
+        match error {
+            ProductionError::InsufficientGold { .. } => ActionExecutionError::InsufficientResources,
+            _ => ActionExecutionError::InvalidTarget,
+        }
+    })?;
+
+    tracing::debug!(
+        "Civ {} queued {} in its capital",
+        civilization.id.0,
+        item_name
+    );
+    Ok(())
+}
+
+/// Have an idle unit of `civilization` that already has `target_position` in range and in
+/// sight make a ranged attack on it, and otherwise send a unit there to attack
+fn execute_attack(
+    civilization: &Civilization,
+    target_position: Position,
+    maneuver: &mut ManeuverContext,
+    commands: &mut Commands,
+) -> Result<(), ActionExecutionError> {
+    let ranged_unit = maneuver.idle_units.iter().position(|(_, unit, position)| {
+        unit.owner == civilization.id
+            && validate_ranged_attack(
+                unit,
+                *position,
+                target_position,
+                maneuver.world_map,
+                maneuver.terrain_registry,
+            )
+            .is_ok()
+    });
+
+    let Some(index) = ranged_unit else {
+        return execute_maneuver(civilization, target_position, true, maneuver, commands);
+    };
+
+    let (unit_entity, unit, _) = maneuver.idle_units.swap_remove(index);
+    maneuver.ranged_attacks.push(RangedAttackRequested {
+        attacker: unit_entity,
+        target_position,
+    });
+    tracing::debug!(
+        "Civ {} ordered unit {} to strike ({}, {}) at range",
+        civilization.id.0,
+        unit.id,
+        target_position.x,
+        target_position.y
+    );
+    Ok(())
+}
+
+/// Send the idle military unit of `civilization` closest to `target_position` that can enter
+/// its terrain there on a goto order, ferrying land units across the water when it lies on
+/// another landmass. Transports are kept back for ferrying. Ranged units sent to `attack`
+/// stop on the first tile they can strike it from.
+fn execute_maneuver(
+    civilization: &Civilization,
+    target_position: Position,
+    attack: bool,
+    maneuver: &mut ManeuverContext,
+    commands: &mut Commands,
+) -> Result<(), ActionExecutionError> {
+    let index = maneuver
+        .idle_units
+        .iter()
+        .enumerate()
+        .filter(|(_, (entity, unit, _))| {
+            unit.owner == civilization.id
+                && !maneuver.transports.contains(entity)
+                && maneuver
+                    .world_map
+                    .get_tile(target_position)
+                    .is_some_and(|tile| {
+                        maneuver
+                            .terrain_registry
+                            .can_enter(&tile.terrain, unit.unit_class.domain())
+                    })
+        })
+        .min_by_key(|(_, (_, unit, position))| {
+            (position.manhattan_distance_to(&target_position), unit.id)
+        })
+        .map(|(index, _)| index)
+        .ok_or(ActionExecutionError::InsufficientResources)?;
+
+    let (unit_entity, unit, position) = maneuver.idle_units[index];
+    if position != target_position {
+        let domain = unit.unit_class.domain();
+        let blocked = maneuver.occupancy.blocked_tiles(civilization.id);
+        let Some(mut movement_order) = plan_movement_order(
+            maneuver.world_map,
+            maneuver.terrain_registry,
+            position,
+            target_position,
+            domain,
+            &blocked,
+        ) else {
+            if domain != MovementDomain::Land {
+                return Err(ActionExecutionError::InvalidTarget);
+            }
+            let idle_unit = maneuver.idle_units.swap_remove(index);
+            let crossing = execute_crossing(
+                civilization.id,
+                unit_entity,
+                position,
+                target_position,
+                maneuver,
+                commands,
+            );
+            if crossing.is_err() {
+                maneuver.idle_units.push(idle_unit);
+            }
+            return crossing;
+        };
+
+        if attack && unit.has_ranged_attack() {
+            let firing_position = movement_order.path.iter().position(|step| {
+                CombatSystem::can_attack(unit, *step, target_position)
+                    && has_line_of_sight(
+                        maneuver.world_map,
+                        maneuver.terrain_registry,
+                        *step,
+                        target_position,
+                    )
+            });
+            if let Some(stop) = firing_position {
+                let path = movement_order.path[..=stop].to_vec();
+                movement_order = MovementOrder::new(path, movement_order.path[stop]);
+            }
+        }
+        commands.entity(unit_entity).insert(movement_order);
+    }
+
+    maneuver.idle_units.swap_remove(index);
+    tracing::debug!(
+        "Civ {} sent unit {} to ({}, {})",
+        civilization.id.0,
+        unit.id,
+        target_position.x,
+        target_position.y
+    );
+    Ok(())
+}
+
+/// Carry the land unit `unit_entity` of `civ_id` at `position` towards `destination` on another
+/// landmass aboard a transport, one leg each time the AI repeats the order
+///
+/// A transport is called to the shore nearest the unit, the unit boards it once it lies
+/// alongside, and the loaded transport sails for the shore nearest `destination`, from where
+/// the unit has a path ashore of its own.
+fn execute_crossing(
+    civ_id: CivId,
+    unit_entity: Entity,
+    position: Position,
+    destination: Position,
+    maneuver: &mut ManeuverContext,
+    commands: &mut Commands,
+) -> Result<(), ActionExecutionError> {
+    let blocked = maneuver.occupancy.blocked_tiles(civ_id);
+    let is_own_transport = |entity: &Entity, unit: &MilitaryUnit| {
+        unit.owner == civ_id && maneuver.transports.contains(entity)
+    };
+
+    if maneuver.embarked.contains(&unit_entity) {
+        let carrier = maneuver
+            .idle_units
+            .iter()
+            .position(|(entity, unit, transport_position)| {
+                is_own_transport(entity, unit) && *transport_position == position
+            });
+        let Some(index) = carrier else {
+            // The transport carrying the unit is already under way
+            return Ok(());
+        };
+
+        let (_, landing) = find_crossing_point(
+            maneuver.world_map,
+            maneuver.terrain_registry,
+            destination,
+            position,
+            &blocked,
+        )
+        .ok_or(ActionExecutionError::InvalidTarget)?;
+        let movement_order = plan_movement_order(
+            maneuver.world_map,
+            maneuver.terrain_registry,
+            position,
+            landing,
+            MovementDomain::Sea,
+            &blocked,
+        )
+        .ok_or(ActionExecutionError::InvalidTarget)?;
+
+        let (transport_entity, transport, _) = maneuver.idle_units.swap_remove(index);
+        commands.entity(transport_entity).insert(movement_order);
+        maneuver.inbound_transports.push((civ_id, landing));
+        tracing::debug!(
+            "Civ {} sent transport {} to land troops at ({}, {})",
+            civ_id.0,
+            transport.id,
+            landing.x,
+            landing.y
+        );
+        return Ok(());
+    }
+
+    let alongside = maneuver
+        .idle_units
+        .iter()
+        .position(|(entity, unit, transport_position)| {
+            is_own_transport(entity, unit)
+                && transport_position.manhattan_distance_to(&position) == 1
+                && maneuver.berths.has_free_berth(civ_id, *transport_position)
+        });
+    if let Some(index) = alongside {
+        let (_, transport, transport_position) = maneuver.idle_units.swap_remove(index);
+        commands.entity(unit_entity).insert(MovementOrder::new(
+            vec![transport_position],
+            transport_position,
+        ));
+        maneuver.berths.board(civ_id, transport_position);
+        tracing::debug!(
+            "Civ {} embarked a unit on transport {} at ({}, {})",
+            civ_id.0,
+            transport.id,
+            transport_position.x,
+            transport_position.y
+        );
+        return Ok(());
+    }
+
+    let transport_inbound = maneuver
+        .inbound_transports
+        .iter()
+        .any(|(owner, water)| *owner == civ_id && water.manhattan_distance_to(&position) == 1);
+    if transport_inbound {
+        return Ok(());
+    }
 
-        AIAction::Explore { .. } => Ok(()),
+    let (index, (shore, pickup)) = maneuver
+        .idle_units
+        .iter()
+        .enumerate()
+        .filter(|(_, (entity, unit, transport_position))| {
+            is_own_transport(entity, unit)
+                && maneuver.berths.has_free_berth(civ_id, *transport_position)
+        })
+        .min_by_key(|(_, (_, unit, transport_position))| {
+            (transport_position.manhattan_distance_to(&position), unit.id)
+        })
+        .and_then(|(index, (_, _, transport_position))| {
+            find_crossing_point(
+                maneuver.world_map,
+                maneuver.terrain_registry,
+                position,
+                *transport_position,
+                &blocked,
+            )
+            .map(|meeting| (index, meeting))
+        })
+        .ok_or(ActionExecutionError::InsufficientResources)?;
+
+    let (transport_entity, transport, transport_position) = maneuver.idle_units[index];
+    let plan = |start: Position, destination: Position, domain: MovementDomain| {
+        plan_movement_order(
+            maneuver.world_map,
+            maneuver.terrain_registry,
+            start,
+            destination,
+            domain,
+            &blocked,
+        )
+    };
+    let transport_order = plan(transport_position, pickup, MovementDomain::Sea);
+    let unit_order = plan(position, shore, MovementDomain::Land);
+    if (transport_order.is_none() && transport_position != pickup)
+        || (unit_order.is_none() && position != shore)
+    {
+        return Err(ActionExecutionError::InvalidTarget);
+    }
+
+    if let Some(transport_order) = transport_order {
+        commands.entity(transport_entity).insert(transport_order);
+        maneuver.inbound_transports.push((civ_id, pickup));
+    }
+    if let Some(unit_order) = unit_order {
+        commands.entity(unit_entity).insert(unit_order);
     }
+    maneuver.idle_units.swap_remove(index);
+    tracing::debug!(
+        "Civ {} called transport {} to ({}, {}) to pick up a unit",
+        civ_id.0,
+        transport.id,
+        pickup.x,
+        pickup.y
+    );
+    Ok(())
+}
+
+/// Open a trade route to `partner` if the two civilizations have a trade agreement, and
+/// propose one otherwise
+fn execute_trade(
+    civilization: &Civilization,
+    partner: CivId,
+    resource: GameResource,
+    trade: &mut TradeContext,
+) -> Result<(), ActionExecutionError> {
+    if !can_trade(trade.diplomatic_state, civilization.id, partner) {
+        return if propose_trade_pact(trade.diplomatic_state, civilization.id, partner) {
+            Ok(())
+        } else {
+            Err(ActionExecutionError::DiplomaticRestriction)
+        };
+    }
+
+    establish_trade_route(
+        trade.global_economy,
+        trade.world_map,
+        trade.terrain_registry,
+        trade.cities,
+        civilization,
+        partner,
+        resource,
+    )
+    .map_err(|reason| {
+        tracing::debug!(
+            "Civ {} cannot trade with civ {}: {}",
+            civilization.id.0,
+            partner.0,
+            reason
+        );
+        ActionExecutionError::InvalidTarget
+    })
 }
 
 /// Error types for action execution
@@ -0,0 +1,79 @@
+use crate::{
+    components::turn_phases::TurnPhase, resources::CurrentTurn, Capital, CapitalAge, City, CivId,
+    Civilization,
+};
+use bevy_ecs::prelude::*;
+
+/// Event emitted when a capital advances into a new age
+#[derive(Message, Debug, Clone)]
+pub struct CapitalEvolved {
+    pub capital_entity: Entity,
+    pub civ_id: CivId,
+    pub city_name: String,
+    pub previous_age: CapitalAge,
+    pub new_age: CapitalAge,
+}
+
+/// System to advance capitals that meet their evolution requirements, at most one age
+/// per turn, during the turn transition
+pub fn evolve_capitals(
+    turn_phase: Res<TurnPhase>,
+    current_turn: Res<CurrentTurn>,
+    civilizations: Query<&Civilization>,
+    mut capitals: Query<(Entity, &mut Capital, &City)>,
+    mut evolved_events: MessageWriter<CapitalEvolved>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for (capital_entity, mut capital, city) in capitals.iter_mut() {
+        let Some(next_age) = capital.age.next_age() else {
+            continue;
+        };
+        let Some(civilization) = civilizations.iter().find(|civ| civ.id == capital.owner) else {
+            continue;
+        };
+
+        if !meets_evolution_requirements(&capital.age, city, civilization, current_turn.0) {
+            continue;
+        }
+
+        let previous_age = std::mem::replace(&mut capital.age, next_age.clone());
+        capital.sprite_index = next_age.sprite_index();
+
+        tracing::info!(
+            "{} of civ {} advanced from the {:?} to the {:?} age",
+            city.name,
+            capital.owner.0,
+            previous_age,
+            next_age
+        );
+        evolved_events.write(CapitalEvolved {
+            capital_entity,
+            civ_id: capital.owner,
+            city_name: city.name.clone(),
+            previous_age,
+            new_age: next_age,
+        });
+    }
+}
+
+fn meets_evolution_requirements(
+    age: &CapitalAge,
+    city: &City,
+    civilization: &Civilization,
+    turn: u32,
+) -> bool {
+    let Some(requirements) = age.evolution_requirements() else {
+        return false;
+    };
+
+    city.population >= requirements.min_population
+        && city.buildings.len() >= requirements.min_buildings
+        && turn >= requirements.min_turn
+        && requirements
+            .required_technologies
+            .iter()
+            .all(|tech| civilization.technologies.knows(tech))
+}
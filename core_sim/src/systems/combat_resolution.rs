@@ -0,0 +1,456 @@
+use crate::{
+    combat::{CombatCasualties, CombatSystem, CombatWinner},
+    components::{orders::UnitOrder, position::MovementOrder, rendering::SpriteEntityReference},
+    constants::combat::MELEE_RANGE,
+    resources::{DiplomaticState, GameRng},
+    CivId, Embarked, MilitaryUnit, MovementDomain, PlayerMovementOrder, Position, TerrainRegistry,
+    TerrainType, UnitRegistry, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashSet;
+
+/// Event for a unit attacking an adjacent tile
+#[derive(Message, Debug, Clone)]
+pub struct AttackRequested {
+    pub attacker: Entity,
+    pub target_position: Position,
+}
+
+/// Event emitted once a battle has been fought
+#[derive(Message, Debug, Clone)]
+pub struct CombatResolved {
+    pub attacker_civ: CivId,
+    pub defender_civ: CivId,
+    pub position: Position,
+    pub winner: CombatWinner,
+    pub casualties: CombatCasualties,
+    pub attacker_destroyed: bool,
+    pub defenders_destroyed: usize,
+}
+
+/// System that turns movement orders targeting a hostile-occupied tile into attacks.
+/// Single-step moves onto tiles held by civs we are not at war with are cancelled instead,
+/// while goto orders are left for `follow_movement_orders` to path around the tile.
+pub fn convert_hostile_moves_into_attacks(
+    mut commands: Commands,
+    player_orders: Query<(Entity, &MilitaryUnit, &PlayerMovementOrder)>,
+    ai_orders: Query<(Entity, &MilitaryUnit, &MovementOrder)>,
+    units: Query<(&MilitaryUnit, &Position)>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut attack_requests: MessageWriter<AttackRequested>,
+) {
+    for (entity, unit, order) in player_orders.iter() {
+        handle_move_into_foreign_tile::<PlayerMovementOrder>(
+            &mut commands,
+            &units,
+            &diplomatic_state,
+            &mut attack_requests,
+            entity,
+            unit,
+            order.target_position,
+            true,
+        );
+    }
+
+    for (entity, unit, order) in ai_orders.iter() {
+        let Some(target_position) = order.next_position() else {
+            continue;
+        };
+        handle_move_into_foreign_tile::<MovementOrder>(
+            &mut commands,
+            &units,
+            &diplomatic_state,
+            &mut attack_requests,
+            entity,
+            unit,
+            target_position,
+            false,
+        );
+    }
+}
+
+fn handle_move_into_foreign_tile<Order: Component>(
+    commands: &mut Commands,
+    units: &Query<(&MilitaryUnit, &Position)>,
+    diplomatic_state: &DiplomaticState,
+    attack_requests: &mut MessageWriter<AttackRequested>,
+    entity: Entity,
+    unit: &MilitaryUnit,
+    target_position: Position,
+    cancel_at_peace: bool,
+) {
+    let mut foreign_owners = units
+        .iter()
+        .filter(|(other, position)| **position == target_position && other.owner != unit.owner)
+        .map(|(other, _)| other.owner);
+
+    let Some(foreign_owner) = foreign_owners.next() else {
+        return;
+    };
+
+    if diplomatic_state.is_at_war(unit.owner, foreign_owner) {
+        commands.entity(entity).remove::<Order>();
+        attack_requests.write(AttackRequested {
+            attacker: entity,
+            target_position,
+        });
+    } else if cancel_at_peace {
+        commands.entity(entity).remove::<Order>();
+        tracing::debug!(
+            "Unit {} cannot enter ({}, {}): civ {} is not at war with civ {}",
+            unit.id,
+            target_position.x,
+            target_position.y,
+            unit.owner.0,
+            foreign_owner.0
+        );
+    }
+}
+
+/// System that fights every requested attack using `CombatSystem::resolve_combat`
+pub fn resolve_attack_requests(
+    mut commands: Commands,
+    mut attack_requests: MessageReader<AttackRequested>,
+    mut units: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &mut Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    unit_registry: Res<UnitRegistry>,
+    unit_orders: Query<&UnitOrder>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut rng: ResMut<GameRng>,
+    mut combat_results: MessageWriter<CombatResolved>,
+) {
+    // Despawns are deferred, so remember this frame's casualties to keep them out of later battles
+    let mut destroyed_units = HashSet::new();
+
+    for request in attack_requests.read() {
+        if destroyed_units.contains(&request.attacker) {
+            continue;
+        }
+        let Ok((_, attacker, attacker_position, _)) = units.get(request.attacker) else {
+            continue;
+        };
+
+        if !attacker.can_move() {
+            tracing::debug!("Unit {} has no movement left to attack", attacker.id);
+            continue;
+        }
+
+        if !attacker.is_reliable() {
+            tracing::info!(
+                "Unit {} of civ {} refuses to attack",
+                attacker.id,
+                attacker.owner.0
+            );
+            continue;
+        }
+
+        if attacker_position.manhattan_distance_to(&request.target_position) as u32 > MELEE_RANGE {
+            tracing::debug!(
+                "Unit {} is too far from ({}, {}) to attack",
+                attacker.id,
+                request.target_position.x,
+                request.target_position.y
+            );
+            continue;
+        }
+
+        let attacker_civ = attacker.owner;
+        let defender_entities: Vec<Entity> = units
+            .iter()
+            .filter(|(entity, unit, position, _)| {
+                **position == request.target_position
+                    && unit.owner != attacker_civ
+                    && diplomatic_state.is_at_war(attacker_civ, unit.owner)
+                    && !destroyed_units.contains(entity)
+            })
+            .map(|(entity, ..)| entity)
+            .collect();
+
+        let Some(&first_defender) = defender_entities.first() else {
+            continue;
+        };
+        let Ok((_, defender, _, _)) = units.get(first_defender) else {
+            continue;
+        };
+        let defender_civ = defender.owner;
+        let defender_entities: Vec<Entity> = defender_entities
+            .into_iter()
+            .filter(|entity| {
+                units
+                    .get(*entity)
+                    .is_ok_and(|(_, unit, _, _)| unit.owner == defender_civ)
+            })
+            .collect();
+
+        let attacker_entities = vec![request.attacker];
+        let mut attacking_units = collect_combatants(&units, &attacker_entities);
+        let mut defending_units = collect_combatants(&units, &defender_entities);
+
+        let attack_bonus =
+            promotion_bonus(&unit_registry, &attacking_units, &defending_units, None);
+        let defense_bonus = defender_bonus(
+            &world_map,
+            &terrain_registry,
+            &unit_registry,
+            &unit_orders,
+            request.target_position,
+            &defender_entities,
+            &defending_units,
+            &attacking_units,
+        );
+
+        let result = CombatSystem::resolve_combat(
+            &mut attacking_units,
+            &mut defending_units,
+            attack_bonus,
+            defense_bonus,
+            &mut rng.0,
+        );
+
+        let attacker_destroyed = apply_combat_outcome(
+            &mut commands,
+            &mut units,
+            &attacker_entities,
+            attacking_units,
+            &mut destroyed_units,
+        ) > 0;
+        let defenders_destroyed = apply_combat_outcome(
+            &mut commands,
+            &mut units,
+            &defender_entities,
+            defending_units,
+            &mut destroyed_units,
+        );
+
+        // Fighting breaks every standing order except the defenders' fortifications
+        if !attacker_destroyed {
+            commands.entity(request.attacker).remove::<UnitOrder>();
+        }
+        break_defender_orders(
+            &mut commands,
+            &unit_orders,
+            &defender_entities,
+            &destroyed_units,
+        );
+
+        if let Ok((_, mut attacker, mut attacker_position, _)) = units.get_mut(request.attacker) {
+            attacker.movement_remaining = 0;
+
+            let tile_cleared = defenders_destroyed == defender_entities.len();
+            if !attacker_destroyed
+                && tile_cleared
+                && can_enter(
+                    &world_map,
+                    &terrain_registry,
+                    request.target_position,
+                    attacker.unit_class.domain(),
+                )
+            {
+                *attacker_position = request.target_position;
+                commands.entity(request.attacker).remove::<Embarked>();
+            }
+        }
+
+        tracing::info!(
+            "Combat at ({}, {}): civ {} attacked civ {} - {:?} after {} rounds",
+            request.target_position.x,
+            request.target_position.y,
+            attacker_civ.0,
+            defender_civ.0,
+            result.winner,
+            result.rounds
+        );
+
+        combat_results.write(CombatResolved {
+            attacker_civ,
+            defender_civ,
+            position: request.target_position,
+            winner: result.winner,
+            casualties: result.casualties,
+            attacker_destroyed,
+            defenders_destroyed,
+        });
+    }
+}
+
+/// Drop the standing orders of the surviving defenders, except their fortifications
+pub(crate) fn break_defender_orders(
+    commands: &mut Commands,
+    unit_orders: &Query<&UnitOrder>,
+    defender_entities: &[Entity],
+    destroyed_units: &HashSet<Entity>,
+) {
+    for entity in defender_entities {
+        let fortified = unit_orders
+            .get(*entity)
+            .is_ok_and(|order| matches!(order, UnitOrder::Fortify { .. }));
+        if !fortified && !destroyed_units.contains(entity) {
+            commands.entity(*entity).remove::<UnitOrder>();
+        }
+    }
+}
+
+/// Extra strength, as a fraction, the `defending_units` on `position` draw from its terrain,
+/// their fortifications and their promotions against the `attacking_units`
+pub(crate) fn defender_bonus(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    unit_registry: &UnitRegistry,
+    unit_orders: &Query<&UnitOrder>,
+    position: Position,
+    defender_entities: &[Entity],
+    defending_units: &[MilitaryUnit],
+    attacking_units: &[MilitaryUnit],
+) -> f32 {
+    let target_terrain = world_map
+        .get_tile(position)
+        .map(|tile| tile.terrain.clone());
+    let terrain_defense_bonus = target_terrain
+        .as_ref()
+        .map(|terrain| terrain_registry.defense_bonus(terrain))
+        .unwrap_or_default();
+
+    // A stack is only as dug in as its least fortified unit
+    let fortification_level = defender_entities
+        .iter()
+        .map(|entity| {
+            unit_orders
+                .get(*entity)
+                .map_or(0, |order| order.fortification_level())
+        })
+        .min()
+        .unwrap_or_default();
+
+    CombatSystem::calculate_defense_bonus(terrain_defense_bonus, fortification_level)
+        + promotion_bonus(
+            unit_registry,
+            defending_units,
+            attacking_units,
+            target_terrain.as_ref(),
+        )
+}
+
+/// Average extra strength the promotions of `units` give them against the first of
+/// `opponents`, including terrain defense when they hold `terrain`
+pub(crate) fn promotion_bonus(
+    unit_registry: &UnitRegistry,
+    units: &[MilitaryUnit],
+    opponents: &[MilitaryUnit],
+    terrain: Option<&TerrainType>,
+) -> f32 {
+    let Some(opponent) = opponents.first() else {
+        return 0.0;
+    };
+    if units.is_empty() {
+        return 0.0;
+    }
+
+    let total: f32 = units
+        .iter()
+        .map(|unit| {
+            unit_registry.class_bonus(unit, opponent.unit_class)
+                + terrain.map_or(0.0, |terrain| {
+                    unit_registry.terrain_defense_bonus(unit, terrain)
+                })
+        })
+        .sum();
+    total / units.len() as f32
+}
+
+fn can_enter(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    position: Position,
+    domain: MovementDomain,
+) -> bool {
+    world_map
+        .get_tile(position)
+        .is_some_and(|tile| terrain_registry.can_enter(&tile.terrain, domain))
+}
+
+/// Clone the units taking part in a battle. Each clone's `id` is replaced by its
+/// index in `entities` so survivors can be matched back after `resolve_combat`
+/// drops the destroyed ones.
+pub(crate) fn collect_combatants(
+    units: &Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &mut Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    entities: &[Entity],
+) -> Vec<MilitaryUnit> {
+    entities
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entity)| {
+            units.get(*entity).ok().map(|(_, unit, _, _)| {
+                let mut combatant = unit.clone();
+                combatant.id = index as u32;
+                combatant
+            })
+        })
+        .collect()
+}
+
+/// Write surviving units back to their entities and despawn the rest.
+/// Returns how many units were destroyed.
+pub(crate) fn apply_combat_outcome(
+    commands: &mut Commands,
+    units: &mut Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &mut Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    entities: &[Entity],
+    survivors: Vec<MilitaryUnit>,
+    destroyed_units: &mut HashSet<Entity>,
+) -> usize {
+    let mut survivors_by_index: Vec<Option<MilitaryUnit>> = vec![None; entities.len()];
+    for survivor in survivors {
+        if let Some(slot) = survivors_by_index.get_mut(survivor.id as usize) {
+            *slot = Some(survivor);
+        }
+    }
+
+    let mut destroyed = 0;
+    for (entity, survivor) in entities.iter().zip(survivors_by_index) {
+        let Ok((_, mut unit, _, sprite_reference)) = units.get_mut(*entity) else {
+            continue;
+        };
+
+        match survivor {
+            Some(mut survivor) => {
+                survivor.id = unit.id;
+                *unit = survivor;
+            }
+            None => {
+                tracing::info!("Unit {} of civ {} was destroyed", unit.id, unit.owner.0);
+                despawn_unit(commands, *entity, sprite_reference);
+                destroyed_units.insert(*entity);
+                destroyed += 1;
+            }
+        }
+    }
+
+    destroyed
+}
+
+/// Despawn a unit together with its sprite, if the frontend has created one
+pub(crate) fn despawn_unit(
+    commands: &mut Commands,
+    entity: Entity,
+    sprite_reference: Option<&SpriteEntityReference>,
+) {
+    if let Some(sprite_reference) = sprite_reference {
+        commands.entity(sprite_reference.sprite_entity).despawn();
+    }
+    commands.entity(entity).despawn();
+}
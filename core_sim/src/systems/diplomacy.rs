@@ -0,0 +1,83 @@
+use crate::{
+    combat::CombatSystem,
+    components::turn_phases::TurnPhase,
+    diplomacy::DiplomaticSystem,
+    resources::{CurrentTurn, DiplomaticEventType, DiplomaticState, GameRng},
+    CivId, CivPersonality, Civilization, MilitaryUnit,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// System to seed neutral relations for every civilization pair, at game start and
+/// whenever new civilizations appear
+pub fn initialize_diplomatic_relations(
+    mut diplomatic_state: ResMut<DiplomaticState>,
+    new_civs: Query<(), Added<Civilization>>,
+    civilizations: Query<&Civilization>,
+) {
+    if new_civs.is_empty() {
+        return;
+    }
+
+    let mut civ_ids: Vec<CivId> = civilizations.iter().map(|civ| civ.id).collect();
+    civ_ids.sort_by_key(|civ_id| civ_id.0);
+    diplomatic_state.seed_relations(&civ_ids);
+}
+
+/// System to advance negotiations, treaties and wars once per turn, during the turn transition
+pub fn update_diplomacy(
+    turn_phase: Res<TurnPhase>,
+    current_turn: Res<CurrentTurn>,
+    mut diplomatic_state: ResMut<DiplomaticState>,
+    civilizations: Query<&Civilization>,
+    units: Query<&MilitaryUnit>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let personalities: HashMap<CivId, CivPersonality> = civilizations
+        .iter()
+        .map(|civ| (civ.id, civ.personality.clone()))
+        .collect();
+
+    let economic_powers: HashMap<CivId, f32> = civilizations
+        .iter()
+        .map(|civ| (civ.id, civ.economy.gold + civ.economy.income))
+        .collect();
+
+    let mut units_by_civ: HashMap<CivId, Vec<MilitaryUnit>> = HashMap::new();
+    for unit in units.iter() {
+        units_by_civ
+            .entry(unit.owner)
+            .or_default()
+            .push(unit.clone());
+    }
+    let military_strengths: HashMap<CivId, f32> = units_by_civ
+        .iter()
+        .map(|(civ_id, units)| (*civ_id, CombatSystem::calculate_total_strength(units)))
+        .collect();
+
+    let events_before = diplomatic_state.diplomatic_events.len();
+
+    DiplomaticSystem::update_diplomacy(
+        &mut diplomatic_state,
+        &personalities,
+        &military_strengths,
+        &economic_powers,
+        current_turn.0,
+        &mut rng.0,
+    );
+
+    for event in &diplomatic_state.diplomatic_events[events_before..] {
+        match event.event_type {
+            DiplomaticEventType::WarDeclared | DiplomaticEventType::PeaceSigned => {
+                tracing::info!("{:?} between {:?}", event.event_type, event.involved_civs);
+            }
+            _ => {
+                tracing::debug!("{:?} between {:?}", event.event_type, event.involved_civs);
+            }
+        }
+    }
+}
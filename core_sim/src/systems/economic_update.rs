@@ -1,4 +1,338 @@
-// Placeholder economic update system
+use crate::{
+    components::{rendering::SpriteEntityReference, turn_phases::TurnPhase},
+    economy::EconomicSystem,
+    systems::combat_resolution::despawn_unit,
+    BuildingRegistry, City, CivId, Civilization, Economy, MilitaryUnit, TerrainRegistry,
+    UnitRegistry, WorldMap,
+};
 use bevy_ecs::prelude::*;
 
-pub struct EconomicUpdateSystem;
\ No newline at end of file
+/// System to collect income and pay upkeep for every civilization once per turn,
+/// during the turn transition
+pub fn update_civilization_economies(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    mut civilizations: Query<&mut Civilization>,
+    mut cities: Query<&mut City>,
+    units: Query<(Entity, &MilitaryUnit, Option<&SpriteEntityReference>)>,
+    world_map: Res<WorldMap>,
+    unit_registry: Res<UnitRegistry>,
+    terrain_registry: Res<TerrainRegistry>,
+    building_registry: Res<BuildingRegistry>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for mut civilization in civilizations.iter_mut() {
+        let civ_id = civilization.id;
+
+        let mut city_income = 0.0;
+        let mut building_upkeep = 0.0;
+        let mut production = 0.0;
+        for city in cities.iter().filter(|city| city.owner == civ_id) {
+            city_income += EconomicSystem::city_income(city, &building_registry);
+            building_upkeep += EconomicSystem::building_upkeep(city, &building_registry);
+            production += city.production;
+        }
+
+        let unit_upkeep: f32 = units
+            .iter()
+            .filter(|(_, unit, _)| unit.owner == civ_id)
+            .map(|(_, unit, _)| EconomicSystem::unit_upkeep(unit, &unit_registry))
+            .sum();
+
+        let income = city_income
+            + EconomicSystem::territory_income(&world_map, &terrain_registry, civ_id)
+            + EconomicSystem::trade_income(&civilization.economy);
+
+        let civilization = &mut *civilization;
+        civilization.military.maintenance_cost = unit_upkeep;
+        civilization.economy.production = production;
+        EconomicSystem::apply_turn(
+            &mut civilization.economy,
+            income,
+            building_upkeep + unit_upkeep,
+        );
+
+        tracing::debug!(
+            "Civ {} treasury: {:.1} gold ({:+.1} per turn)",
+            civ_id.0,
+            civilization.economy.gold,
+            EconomicSystem::net_income(&civilization.economy)
+        );
+
+        if EconomicSystem::is_bankrupt(&civilization.economy) {
+            cover_deficit(
+                &mut commands,
+                &mut civilization.economy,
+                civ_id,
+                &mut cities,
+                &units,
+                &unit_registry,
+                &building_registry,
+            );
+        }
+    }
+}
+
+/// Disband units and sell buildings, alternating between the costliest unit and the
+/// cheapest building, until projected income covers the civilization's expenses.
+///
+/// Disbanding a unit only saves its upkeep from next turn on; selling a building also
+/// refunds part of its cost. Whatever debt remains is paid off from future income.
+fn cover_deficit(
+    commands: &mut Commands,
+    economy: &mut Economy,
+    civ_id: CivId,
+    cities: &mut Query<&mut City>,
+    units: &Query<(Entity, &MilitaryUnit, Option<&SpriteEntityReference>)>,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+) {
+    if EconomicSystem::net_income(economy) >= 0.0 {
+        return;
+    }
+
+    tracing::info!(
+        "Civ {} is bankrupt ({:.1} gold, {:+.1} per turn), cutting costs",
+        civ_id.0,
+        economy.gold,
+        EconomicSystem::net_income(economy)
+    );
+
+    let mut civ_units: Vec<_> = units
+        .iter()
+        .filter(|(_, unit, _)| unit.owner == civ_id)
+        .collect();
+    // Costliest units last, so they are disbanded first
+    civ_units.sort_by(|(_, a, _), (_, b, _)| {
+        EconomicSystem::unit_upkeep(a, unit_registry)
+            .total_cmp(&EconomicSystem::unit_upkeep(b, unit_registry))
+            .then(b.id.cmp(&a.id))
+    });
+
+    let mut sell_building_next = false;
+    while EconomicSystem::net_income(economy) < 0.0 {
+        let cut_costs = (sell_building_next
+            && sell_cheapest_building(economy, civ_id, cities, building_registry))
+            || disband_next_unit(commands, economy, civ_id, &mut civ_units, unit_registry)
+            || sell_cheapest_building(economy, civ_id, cities, building_registry);
+        if !cut_costs {
+            break;
+        }
+        sell_building_next = !sell_building_next;
+    }
+}
+
+/// Disband the last unit in `civ_units`, dropping its upkeep from the civilization's expenses.
+/// Returns `false` once no units are left.
+fn disband_next_unit(
+    commands: &mut Commands,
+    economy: &mut Economy,
+    civ_id: CivId,
+    civ_units: &mut Vec<(Entity, &MilitaryUnit, Option<&SpriteEntityReference>)>,
+    unit_registry: &UnitRegistry,
+) -> bool {
+    let Some((entity, unit, sprite_reference)) = civ_units.pop() else {
+        return false;
+    };
+
+    let upkeep = EconomicSystem::unit_upkeep(unit, unit_registry);
+    economy.expenses -= upkeep;
+    tracing::info!(
+        "Civ {} disbanded unit {} ({}) to save {:.1} gold per turn",
+        civ_id.0,
+        unit.id,
+        unit.unit_type.name(),
+        upkeep
+    );
+    despawn_unit(commands, entity, sprite_reference);
+    true
+}
+
+/// Sell the civilization's cheapest building that costs upkeep, dropping that upkeep from
+/// its expenses and adding the sale value to its treasury.
+/// Returns `false` once no such building is left.
+fn sell_cheapest_building(
+    economy: &mut Economy,
+    civ_id: CivId,
+    cities: &mut Query<&mut City>,
+    building_registry: &BuildingRegistry,
+) -> bool {
+    let Some((mut city, building_type)) = cities
+        .iter_mut()
+        .filter(|city| city.owner == civ_id)
+        .filter_map(|city| {
+            let building = city
+                .buildings
+                .iter()
+                .filter(|building| {
+                    EconomicSystem::single_building_upkeep(building, building_registry) > 0.0
+                })
+                .min_by(|a, b| {
+                    EconomicSystem::building_sale_value(a, building_registry)
+                        .total_cmp(&EconomicSystem::building_sale_value(b, building_registry))
+                })?;
+            let sale_value = EconomicSystem::building_sale_value(building, building_registry);
+            let building_type = building.building_type.clone();
+            Some((city, building_type, sale_value))
+        })
+        .min_by(|(_, _, a), (_, _, b)| a.total_cmp(b))
+        .map(|(city, building_type, _)| (city, building_type))
+    else {
+        return false;
+    };
+
+    let Some(building) = city.remove_building(&building_type, building_registry) else {
+        return false;
+    };
+    let sale_value = EconomicSystem::building_sale_value(&building, building_registry);
+    economy.gold += sale_value;
+    economy.expenses -= EconomicSystem::single_building_upkeep(&building, building_registry);
+    tracing::info!(
+        "Civ {} sold the {} in {} for {:.1} gold",
+        civ_id.0,
+        building_type.name(),
+        city.name,
+        sale_value
+    );
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{building_registry, terrain_registry, unit_registry, world_map};
+    use crate::{
+        Building, BuildingType, CivPersonality, Military, Position, Technologies, UnitType,
+    };
+    use bevy_ecs::system::RunSystemOnce;
+
+    fn civilization(gold: f32) -> Civilization {
+        Civilization {
+            id: CivId(0),
+            name: "Civ 0".to_string(),
+            color: [0.0; 3],
+            capital: None,
+            personality: CivPersonality::default(),
+            technologies: Technologies::default(),
+            economy: Economy {
+                gold,
+                ..Economy::default()
+            },
+            military: Military::default(),
+            music_theme: String::new(),
+            sound_theme: String::new(),
+        }
+    }
+
+    fn building(name: &str) -> Building {
+        Building {
+            building_type: BuildingType::new(name),
+            level: 1,
+        }
+    }
+
+    /// Settles one turn for a civilization with `gold` in its treasury, `units` and a city
+    /// of 1500 people holding `buildings`, returning the world
+    fn settle(gold: f32, buildings: &[&str], units: &[&str]) -> World {
+        let mut world = World::new();
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.insert_resource(world_map(&["."]));
+        world.insert_resource(terrain_registry());
+        world.insert_resource(building_registry());
+        world.spawn(civilization(gold));
+
+        let mut city = City::new("Test".to_string(), CivId(0));
+        city.population = 1500;
+        city.buildings = buildings.iter().map(|&name| building(name)).collect();
+        world.spawn(city);
+
+        let unit_registry = unit_registry();
+        for (id, &name) in units.iter().enumerate() {
+            world.spawn(MilitaryUnit::new(
+                id as u32,
+                CivId(0),
+                UnitType::new(name),
+                Position::new(0, 0),
+                &unit_registry,
+            ));
+        }
+        world.insert_resource(unit_registry);
+
+        world
+            .run_system_once(update_civilization_economies)
+            .unwrap();
+        world
+    }
+
+    fn economy(world: &mut World) -> Economy {
+        let mut civilizations = world.query::<&Civilization>();
+        civilizations.single(world).unwrap().economy.clone()
+    }
+
+    fn remaining_units(world: &mut World) -> Vec<String> {
+        let mut units = world.query::<&MilitaryUnit>();
+        units
+            .iter(world)
+            .map(|unit| unit.unit_type.name().to_string())
+            .collect()
+    }
+
+    fn remaining_buildings(world: &mut World) -> Vec<String> {
+        let mut cities = world.query::<&City>();
+        let city = cities.single(world).unwrap();
+        city.buildings
+            .iter()
+            .map(|building| building.building_type.name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_bankruptcy_alternates_cuts_until_income_covers_expenses() {
+        let buildings = ["Walls", "Granary"];
+        let units = ["Infantry", "Catapult", "Infantry", "Infantry"];
+        let mut world = settle(0.0, &buildings, &units);
+
+        // The catapult goes first, then the granary, an infantry, the walls and another
+        // infantry, leaving the last infantry once the books balance
+        assert_eq!(remaining_units(&mut world), vec!["Infantry"]);
+        assert!(remaining_buildings(&mut world).is_empty());
+
+        let building_registry = building_registry();
+        let unit_registry = unit_registry();
+        let infantry_upkeep = unit_registry
+            .definition(&UnitType::new("Infantry"))
+            .maintenance_cost();
+        let economy = economy(&mut world);
+        let net_income = EconomicSystem::net_income(&economy);
+        assert!((0.0..infantry_upkeep).contains(&net_income));
+
+        // Only the sales reach the treasury, not the upkeep the cuts save
+        let upkeep: f32 = buildings
+            .iter()
+            .map(|name| EconomicSystem::single_building_upkeep(&building(name), &building_registry))
+            .chain(units.iter().map(|&name| {
+                unit_registry
+                    .definition(&UnitType::new(name))
+                    .maintenance_cost()
+            }))
+            .sum();
+        let sales: f32 = buildings
+            .iter()
+            .map(|name| EconomicSystem::building_sale_value(&building(name), &building_registry))
+            .sum();
+        assert!((economy.gold - (economy.income - upkeep + sales)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_debt_is_left_to_future_income_once_it_is_covered() {
+        let mut world = settle(-50.0, &["Monument"], &[]);
+
+        let economy = economy(&mut world);
+        assert!(EconomicSystem::is_bankrupt(&economy));
+        assert!(EconomicSystem::net_income(&economy) > 0.0);
+        assert_eq!(remaining_buildings(&mut world), vec!["Monument"]);
+    }
+}
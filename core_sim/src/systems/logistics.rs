@@ -0,0 +1,139 @@
+use crate::{
+    components::{
+        military::{Embarked, UnitClass},
+        rendering::SpriteEntityReference,
+        turn_phases::TurnPhase,
+    },
+    constants::{combat::MINIMUM_UNIT_HEALTH_THRESHOLD, logistics},
+    resources::CivTerritories,
+    systems::combat_resolution::despawn_unit,
+    CivId, MilitaryUnit, Position, UnitType,
+};
+use bevy_ecs::prelude::*;
+
+/// Event emitted when a unit cut off from its supply lines wastes away
+#[derive(Message, Debug, Clone)]
+pub struct UnitLostToAttrition {
+    pub civ_id: CivId,
+    pub unit_type: UnitType,
+    pub position: Position,
+}
+
+/// System to resupply, tire and wear down units in the field during the turn transition
+///
+/// Units inside their civilization's borders are resupplied and rest. Outside them supply
+/// runs down faster the further the unit is from friendly territory, and a unit low on
+/// supply loses health and decays until it is destroyed. Ships carry their own stores and
+/// units aboard a transport live off the ship, so neither draws on land supply lines.
+pub fn update_unit_logistics(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    civ_territories: Res<CivTerritories>,
+    mut units: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+        Has<Embarked>,
+    )>,
+    mut attrition_events: MessageWriter<UnitLostToAttrition>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for (entity, mut unit, position, sprite_reference, embarked) in units.iter_mut() {
+        if matches!(unit.unit_class, UnitClass::Civilian | UnitClass::Naval) || embarked {
+            continue;
+        }
+
+        let Some(distance) = supply_distance(&civ_territories, unit.owner, *position) else {
+            unit.resupply();
+            unit.rest();
+            continue;
+        };
+
+        unit.consume_supply(
+            logistics::BASE_SUPPLY_DRAIN
+                + logistics::SUPPLY_DRAIN_PER_TILE * (distance - 1).max(0) as f32,
+        );
+        unit.fatigue = (unit.fatigue - logistics::FIELD_FATIGUE_RECOVERY).max(0.0);
+
+        if unit.supply >= logistics::LOW_SUPPLY_THRESHOLD {
+            continue;
+        }
+
+        let shortage = 1.0 - unit.supply / logistics::LOW_SUPPLY_THRESHOLD;
+        unit.health -= unit.max_health * logistics::ATTRITION_HEALTH_LOSS * shortage;
+        unit.add_decay(logistics::ATTRITION_DECAY * shortage);
+
+        if unit.health <= MINIMUM_UNIT_HEALTH_THRESHOLD {
+            tracing::info!(
+                "Unit {} of civ {} was lost to attrition at ({}, {})",
+                unit.id,
+                unit.owner.0,
+                position.x,
+                position.y
+            );
+            attrition_events.write(UnitLostToAttrition {
+                civ_id: unit.owner,
+                unit_type: unit.unit_type.clone(),
+                position: *position,
+            });
+            despawn_unit(&mut commands, entity, sprite_reference);
+        }
+    }
+}
+
+/// Tiles between `position` and the nearest tile `civ_id` owns, capped at the longest supply
+/// line, or `None` inside friendly territory
+pub(crate) fn supply_distance(
+    civ_territories: &CivTerritories,
+    civ_id: CivId,
+    position: Position,
+) -> Option<i32> {
+    if civ_territories.owns(civ_id, position) {
+        return None;
+    }
+
+    let distance = civ_territories
+        .tiles_of(civ_id)
+        .map(|tile| position.manhattan_distance_to(tile))
+        .min()
+        .unwrap_or(logistics::MAX_SUPPLY_DISTANCE);
+    Some(distance.min(logistics::MAX_SUPPLY_DISTANCE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::world_map;
+
+    #[test]
+    fn test_supply_distance_from_nearest_owned_tile() {
+        let width = logistics::MAX_SUPPLY_DISTANCE as usize + 5;
+        let mut world_map = world_map(&[&".".repeat(width)]);
+        for x in 0..2 {
+            world_map.get_tile_mut(Position::new(x, 0)).unwrap().owner = Some(CivId(0));
+        }
+        let civ_territories = CivTerritories::from_world_map(&world_map);
+        let far_away = Position::new(width as i32 - 1, 0);
+
+        assert_eq!(
+            supply_distance(&civ_territories, CivId(0), Position::new(1, 0)),
+            None
+        );
+        assert_eq!(
+            supply_distance(&civ_territories, CivId(0), Position::new(3, 0)),
+            Some(2)
+        );
+        assert_eq!(
+            supply_distance(&civ_territories, CivId(0), far_away),
+            Some(logistics::MAX_SUPPLY_DISTANCE)
+        );
+        assert_eq!(
+            supply_distance(&civ_territories, CivId(1), Position::new(1, 0)),
+            Some(logistics::MAX_SUPPLY_DISTANCE)
+        );
+    }
+}
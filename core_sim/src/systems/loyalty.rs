@@ -0,0 +1,302 @@
+use crate::{
+    components::{
+        military::UnitClass, orders::UnitOrder, position::MovementOrder,
+        rendering::SpriteEntityReference, turn_phases::TurnPhase,
+    },
+    constants::loyalty,
+    economy::EconomicSystem,
+    resources::{CivTerritories, GameRng},
+    systems::{combat_resolution::despawn_unit, logistics::supply_distance},
+    City, CivId, Civilization, MilitaryUnit, PlayerControlled, Position, UnitType,
+};
+use bevy_ecs::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Event emitted when an unreliable unit deserts or goes over to another civilization
+#[derive(Message, Debug, Clone)]
+pub struct UnitMutinied {
+    pub civ_id: CivId,
+    pub unit_type: UnitType,
+    pub position: Position,
+    /// Civilization the unit defected to, or `None` if it deserted
+    pub defected_to: Option<CivId>,
+}
+
+/// System to erode or restore the loyalty of every unit and let unreliable units desert or
+/// defect, during the turn transition
+///
+/// Units lose loyalty while their civilization is bankrupt and while they campaign beyond
+/// its borders, and win it back at home. Corruption drifts towards a level set by the
+/// unit's distance from its capital, or from its nearest city while the civilization has
+/// no capital. Units that are no longer reliable refuse orders and
+/// may desert or defect to the nearest foreign civilization, dropping their orders.
+pub fn update_unit_loyalty(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    civ_territories: Res<CivTerritories>,
+    civilizations: Query<(&Civilization, Has<PlayerControlled>)>,
+    cities: Query<(&City, &Position)>,
+    mut units: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    mut rng: ResMut<GameRng>,
+    mut mutiny_events: MessageWriter<UnitMutinied>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let civ_states: HashMap<CivId, (bool, Option<Position>)> = civilizations
+        .iter()
+        .map(|(civ, _)| {
+            (
+                civ.id,
+                (EconomicSystem::is_bankrupt(&civ.economy), civ.capital),
+            )
+        })
+        .collect();
+    let player_civ_ids: Vec<CivId> = civilizations
+        .iter()
+        .filter(|(_, is_player)| *is_player)
+        .map(|(civ, _)| civ.id)
+        .collect();
+    let mut city_positions: HashMap<CivId, Vec<Position>> = HashMap::new();
+    for (city, position) in cities.iter() {
+        city_positions
+            .entry(city.owner)
+            .or_default()
+            .push(*position);
+    }
+    let mut civ_ids: Vec<CivId> = civ_states.keys().copied().collect();
+    civ_ids.sort_by_key(|civ_id| civ_id.0);
+
+    for (entity, mut unit, position, sprite_reference) in units.iter_mut() {
+        if unit.unit_class == UnitClass::Civilian {
+            continue;
+        }
+        let Some(&(bankrupt, capital)) = civ_states.get(&unit.owner) else {
+            continue;
+        };
+
+        if bankrupt {
+            unit.reduce_loyalty(loyalty::UNPAID_LOYALTY_LOSS);
+        }
+        match supply_distance(&civ_territories, unit.owner, *position) {
+            Some(distance) => {
+                unit.reduce_loyalty(loyalty::CAMPAIGN_LOYALTY_LOSS_PER_TILE * distance as f32)
+            }
+            None if !bankrupt => {
+                unit.loyalty = (unit.loyalty + loyalty::LOYALTY_RECOVERY_PER_TURN).min(1.0)
+            }
+            None => {}
+        }
+
+        let seat_distance = match capital {
+            Some(capital) => Some(position.manhattan_distance_to(&capital)),
+            None => city_positions.get(&unit.owner).and_then(|positions| {
+                positions
+                    .iter()
+                    .map(|city_position| position.manhattan_distance_to(city_position))
+                    .min()
+            }),
+        };
+        if let Some(distance) = seat_distance {
+            let target_corruption =
+                (distance as f32 * loyalty::CORRUPTION_PER_TILE_FROM_CAPITAL).min(1.0);
+            unit.corruption +=
+                (target_corruption - unit.corruption) * loyalty::CORRUPTION_ADJUSTMENT_RATE;
+        }
+
+        if unit.is_reliable() {
+            continue;
+        }
+
+        let roll: f32 = rng.0.gen();
+        if roll < loyalty::DESERTION_CHANCE {
+            tracing::info!(
+                "Unit {} of civ {} deserted at ({}, {})",
+                unit.id,
+                unit.owner.0,
+                position.x,
+                position.y
+            );
+            mutiny_events.write(UnitMutinied {
+                civ_id: unit.owner,
+                unit_type: unit.unit_type.clone(),
+                position: *position,
+                defected_to: None,
+            });
+            despawn_unit(&mut commands, entity, sprite_reference);
+        } else if roll < loyalty::DESERTION_CHANCE + loyalty::DEFECTION_CHANCE {
+            let Some(new_owner) =
+                nearest_foreign_civ(&civ_territories, &civ_ids, unit.owner, *position)
+            else {
+                continue;
+            };
+
+            tracing::info!(
+                "Unit {} of civ {} defected to civ {}",
+                unit.id,
+                unit.owner.0,
+                new_owner.0
+            );
+            mutiny_events.write(UnitMutinied {
+                civ_id: unit.owner,
+                unit_type: unit.unit_type.clone(),
+                position: *position,
+                defected_to: Some(new_owner),
+            });
+            unit.owner = new_owner;
+            unit.loyalty = loyalty::DEFECTOR_LOYALTY;
+            unit.corruption = 0.0;
+
+            let mut unit_commands = commands.entity(entity);
+            unit_commands
+                .insert(new_owner)
+                .remove::<(MovementOrder, UnitOrder)>();
+            if player_civ_ids.contains(&new_owner) {
+                unit_commands.insert(PlayerControlled);
+            } else {
+                unit_commands.remove::<PlayerControlled>();
+            }
+        }
+    }
+}
+
+/// The civilization other than `civ_id` whose borders are closest to `position`, if any lie
+/// within defection range
+fn nearest_foreign_civ(
+    civ_territories: &CivTerritories,
+    civ_ids: &[CivId],
+    civ_id: CivId,
+    position: Position,
+) -> Option<CivId> {
+    civ_ids
+        .iter()
+        .filter(|other| **other != civ_id)
+        .filter_map(|other| {
+            civ_territories
+                .tiles_of(*other)
+                .map(|tile| position.manhattan_distance_to(tile))
+                .min()
+                .map(|distance| (*other, distance))
+        })
+        .filter(|(_, distance)| *distance <= loyalty::DEFECTION_RANGE)
+        .min_by_key(|(other, distance)| (*distance, other.0))
+        .map(|(other, _)| other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CivPersonality, Economy, Military, Technologies, UnitRegistry, WorldMap};
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+    use rand::SeedableRng;
+
+    const MAX_TURNS: u32 = 100;
+
+    /// A seed whose rolls make the unit defect rather than desert
+    const DEFECTION_SEED: u64 = 1;
+
+    fn civilization(id: CivId, gold: f32) -> Civilization {
+        Civilization {
+            id,
+            name: format!("Civ {}", id.0),
+            color: [0.0; 3],
+            capital: Some(Position::new(0, 0)),
+            personality: CivPersonality::default(),
+            technologies: Technologies::default(),
+            economy: Economy {
+                gold,
+                ..Economy::default()
+            },
+            military: Military::default(),
+            music_theme: String::new(),
+            sound_theme: String::new(),
+        }
+    }
+
+    /// Runs turns until the bankrupt civilization 0's unit, campaigning next to civilization
+    /// 1's borders, defects, returning the world and the unit
+    fn defect(player_civ: CivId, seed: u64) -> (World, Entity) {
+        let mut world_map = WorldMap::new(4, 1);
+        world_map.get_tile_mut(Position::new(0, 0)).unwrap().owner = Some(CivId(0));
+        world_map.get_tile_mut(Position::new(3, 0)).unwrap().owner = Some(CivId(1));
+
+        let mut world = World::new();
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.insert_resource(CivTerritories::from_world_map(&world_map));
+        world.insert_resource(GameRng(rand_pcg::Pcg64::seed_from_u64(seed)));
+        world.init_resource::<Messages<UnitMutinied>>();
+        for civ in [civilization(CivId(0), -10.0), civilization(CivId(1), 100.0)] {
+            let is_player = civ.id == player_civ;
+            let mut civ_entity = world.spawn(civ);
+            if is_player {
+                civ_entity.insert(PlayerControlled);
+            }
+        }
+
+        let position = Position::new(2, 0);
+        let unit = MilitaryUnit::new(
+            0,
+            CivId(0),
+            UnitType::new("Infantry"),
+            position,
+            &UnitRegistry::default(),
+        );
+        let mut unit_entity = world.spawn((
+            unit,
+            position,
+            CivId(0),
+            UnitOrder::Sentry,
+            MovementOrder::new(vec![Position::new(1, 0)], Position::new(1, 0)),
+        ));
+        if player_civ == CivId(0) {
+            unit_entity.insert(PlayerControlled);
+        }
+        let entity = unit_entity.id();
+
+        for _ in 0..MAX_TURNS {
+            world.run_system_once(update_unit_loyalty).unwrap();
+            let unit = world
+                .get::<MilitaryUnit>(entity)
+                .expect("the unit should defect before it deserts");
+            if unit.owner != CivId(0) {
+                break;
+            }
+        }
+        (world, entity)
+    }
+
+    #[test]
+    fn test_defectors_join_the_neighboring_player() {
+        let (world, entity) = defect(CivId(1), DEFECTION_SEED);
+
+        let unit = world.get::<MilitaryUnit>(entity).unwrap();
+        assert_eq!(unit.owner, CivId(1));
+        assert_eq!(unit.loyalty, loyalty::DEFECTOR_LOYALTY);
+        assert_eq!(world.get::<CivId>(entity), Some(&CivId(1)));
+        assert!(world.get::<PlayerControlled>(entity).is_some());
+        assert!(world.get::<UnitOrder>(entity).is_none());
+        assert!(world.get::<MovementOrder>(entity).is_none());
+
+        let mutinies = world.resource::<Messages<UnitMutinied>>();
+        let mutiny = mutinies.iter_current_update_messages().last().unwrap();
+        assert_eq!(mutiny.civ_id, CivId(0));
+        assert_eq!(mutiny.defected_to, Some(CivId(1)));
+    }
+
+    #[test]
+    fn test_player_defectors_leave_player_control() {
+        let (world, entity) = defect(CivId(0), DEFECTION_SEED);
+
+        assert_eq!(world.get::<CivId>(entity), Some(&CivId(1)));
+        assert!(world.get::<PlayerControlled>(entity).is_none());
+        assert!(world.get::<UnitOrder>(entity).is_none());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    components::turn_phases::TurnPhase,
+    constants::market,
+    economy::EconomicSystem,
+    resources::{EconomicEvent, EconomicEventType, GameRng, GlobalEconomy},
+    Civilization, GameResource,
+};
+use bevy_ecs::prelude::*;
+use rand::Rng;
+
+/// Event emitted when a boom, crash or trade disruption hits the world market
+#[derive(Message, Debug, Clone)]
+pub struct EconomicEventStarted {
+    pub event_type: EconomicEventType,
+    pub affected_resource: Option<GameResource>,
+    pub magnitude: f32,
+    pub duration: u32,
+}
+
+/// System to run down economic events, start new ones and reprice every resource during the
+/// turn transition
+///
+/// A resource is cheap when most civilizations hold a source of it and dear when few do;
+/// trade routes carrying it push its price up. Prices drift towards that target rather than
+/// jumping to it.
+pub fn update_market(
+    turn_phase: Res<TurnPhase>,
+    mut global_economy: ResMut<GlobalEconomy>,
+    civilizations: Query<&Civilization>,
+    mut rng: ResMut<GameRng>,
+    mut event_writer: MessageWriter<EconomicEventStarted>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    global_economy.economic_events.retain_mut(|event| {
+        event.duration = event.duration.saturating_sub(1);
+        if event.duration == 0 {
+            tracing::info!(
+                "{:?} affecting {:?} has ended",
+                event.event_type,
+                event.affected_resource
+            );
+        }
+        event.duration > 0
+    });
+
+    if rng.0.gen_bool(market::ECONOMIC_EVENT_CHANCE_PER_TURN) {
+        let event = random_economic_event(&mut rng.0);
+        tracing::info!(
+            "{:?} affecting {:?} (x{:.2} for {} turns)",
+            event.event_type,
+            event.affected_resource,
+            event.magnitude,
+            event.duration
+        );
+        event_writer.write(EconomicEventStarted {
+            event_type: event.event_type.clone(),
+            affected_resource: event.affected_resource,
+            magnitude: event.magnitude,
+            duration: event.duration,
+        });
+        global_economy.economic_events.push(event);
+    }
+
+    let civ_count = civilizations.iter().count().max(1) as f32;
+    for resource in GameResource::ALL {
+        let holders = civilizations
+            .iter()
+            .filter(|civ| civ.economy.has_resource(resource))
+            .count();
+        let routes = global_economy
+            .trade_routes
+            .iter()
+            .filter(|route| route.resource == resource)
+            .count();
+
+        let target = EconomicSystem::market_price_target(
+            resource,
+            holders as f32 / civ_count,
+            routes as f32 / civ_count,
+            global_economy.price_multiplier(resource),
+        );
+        let price = global_economy.resource_price(resource);
+        global_economy.resource_prices.insert(
+            resource,
+            price + (target - price) * market::PRICE_ADJUSTMENT_RATE,
+        );
+    }
+}
+
+fn random_economic_event(rng: &mut impl Rng) -> EconomicEvent {
+    let duration = rng.gen_range(market::EVENT_DURATION_MIN..=market::EVENT_DURATION_MAX);
+    let resource = GameResource::ALL[rng.gen_range(0..GameResource::ALL.len())];
+
+    match rng.gen_range(0..3) {
+        0 => EconomicEvent {
+            event_type: EconomicEventType::ResourceBoom,
+            affected_resource: Some(resource),
+            magnitude: rng.gen_range(market::BOOM_MAGNITUDE_MIN..=market::BOOM_MAGNITUDE_MAX),
+            duration,
+        },
+        1 => EconomicEvent {
+            event_type: EconomicEventType::ResourceCrash,
+            affected_resource: Some(resource),
+            magnitude: rng.gen_range(market::CRASH_MAGNITUDE_MIN..=market::CRASH_MAGNITUDE_MAX),
+            duration,
+        },
+        _ => EconomicEvent {
+            event_type: EconomicEventType::TradeDisruption,
+            affected_resource: None,
+            magnitude: rng
+                .gen_range(market::DISRUPTION_MAGNITUDE_MIN..=market::DISRUPTION_MAGNITUDE_MAX),
+            duration,
+        },
+    }
+}
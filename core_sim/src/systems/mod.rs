@@ -1,18 +1,52 @@
 pub mod action_queue;
 pub mod ai_decision;
+pub mod capital_evolution;
 pub mod combat_resolution;
+pub mod diplomacy;
 pub mod economic_update;
 pub mod fog_of_war;
+pub mod logistics;
+pub mod loyalty;
+pub mod market;
 pub mod movement;
+pub mod naval;
+pub mod occupancy;
+pub mod population;
 pub mod production;
+pub mod promotions;
+pub mod ranged_combat;
+pub mod research;
+pub mod settlement;
+pub mod siege;
+pub mod strategic_resources;
+pub mod territory;
+pub mod trade;
 pub mod turn_management;
+pub mod unit_orders;
 
 // Re-export all systems
 pub use action_queue::*;
 pub use ai_decision::*;
+pub use capital_evolution::*;
 pub use combat_resolution::*;
+pub use diplomacy::*;
 pub use economic_update::*;
 pub use fog_of_war::*;
+pub use logistics::*;
+pub use loyalty::*;
+pub use market::*;
 pub use movement::*;
+pub use naval::*;
+pub use occupancy::*;
+pub use population::*;
 pub use production::*;
+pub use promotions::*;
+pub use ranged_combat::*;
+pub use research::*;
+pub use settlement::*;
+pub use siege::*;
+pub use strategic_resources::*;
+pub use territory::*;
+pub use trade::*;
 pub use turn_management::*;
+pub use unit_orders::*;
@@ -1,10 +1,15 @@
 use crate::{
-    components::{military::FacingDirection, position::MovementOrder},
-    constants::{movement_validation, terrain_stats},
+    components::{military::FacingDirection, orders::UnitOrder, position::MovementOrder},
+    constants::{logistics, movement_validation},
     debug_utils::CoreDebugUtils,
-    MilitaryUnit, PlayerMovementOrder, Position, TerrainType, WorldMap,
+    pathfinding::Pathfinder,
+    resources::{DiplomaticState, TileOccupancy, TileOccupant},
+    systems::naval::TransportBerths,
+    CivId, Embarked, MilitaryUnit, MovementDomain, PlayerMovementOrder, Position, TerrainRegistry,
+    Transport, UnitClass, WorldMap,
 };
 use bevy::prelude::*;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 
 fn calculate_manhattan_distance_between_positions(from: Position, to: Position) -> u32 {
     let x_distance = (to.x - from.x).abs() as u32;
@@ -24,10 +29,19 @@ fn update_unit_facing_direction_from_movement(
     }
 }
 
+/// How a step takes a land unit on board a transport or back ashore
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Boarding {
+    Embark,
+    Disembark,
+}
+
 fn validate_movement_to_adjacent_tile(
     from: Position,
     to: Position,
+    domain: MovementDomain,
     world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
 ) -> Result<u32, &'static str> {
     let distance = calculate_manhattan_distance_between_positions(from, to);
     if distance != movement_validation::ADJACENT_TILE_DISTANCE {
@@ -35,22 +49,122 @@ fn validate_movement_to_adjacent_tile(
     }
 
     if let Some(tile) = world_map.get_tile(to) {
-        match tile.terrain {
-            TerrainType::Ocean => Err("Cannot move into ocean"),
-            _ => {
-                let movement_cost = tile.movement_cost as u32;
-                if movement_cost == 0 {
-                    Ok(movement_validation::DEFAULT_MOVEMENT_COST_WHEN_ZERO)
-                } else {
-                    Ok(movement_cost)
-                }
-            }
+        if !terrain_registry.can_enter(&tile.terrain, domain) {
+            return Err("Cannot move into impassable terrain");
+        }
+
+        let movement_cost = terrain_registry.movement_cost(&tile.terrain) as u32;
+        if movement_cost == 0 {
+            Ok(movement_validation::DEFAULT_MOVEMENT_COST_WHEN_ZERO)
+        } else {
+            Ok(movement_cost)
         }
     } else {
         Err("Target position is outside map boundaries")
     }
 }
 
+/// Validate a step by `unit`, letting a land unit board a friendly transport with a free
+/// berth and an embarked unit go ashore, either of which takes the rest of its movement
+fn validate_step(
+    unit: &MilitaryUnit,
+    embarked: bool,
+    from: Position,
+    to: Position,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    berths: &TransportBerths,
+) -> Result<(u32, Option<Boarding>), &'static str> {
+    let domain = unit.unit_class.domain();
+    if embarked {
+        validate_movement_to_adjacent_tile(from, to, domain, world_map, terrain_registry)
+            .map_err(|_| "Embarked units can only go ashore")?;
+        return Ok((unit.movement_remaining.max(1), Some(Boarding::Disembark)));
+    }
+
+    match validate_movement_to_adjacent_tile(from, to, domain, world_map, terrain_registry) {
+        Ok(movement_cost) => Ok((movement_cost, None)),
+        Err(_)
+            if domain == MovementDomain::Land
+                && calculate_manhattan_distance_between_positions(from, to)
+                    == movement_validation::ADJACENT_TILE_DISTANCE
+                && berths.has_free_berth(unit.owner, to) =>
+        {
+            Ok((unit.movement_remaining.max(1), Some(Boarding::Embark)))
+        }
+        Err(reason) => Err(reason),
+    }
+}
+
+/// Take a unit of `owner` on board or ashore at `to` as `boarding` requires, freeing the
+/// berth it held if it was embarked at `embarked_at`
+fn apply_boarding(
+    commands: &mut Commands,
+    entity: Entity,
+    owner: CivId,
+    embarked_at: Option<Position>,
+    to: Position,
+    boarding: Option<Boarding>,
+    berths: &mut TransportBerths,
+) {
+    if let Some(from) = embarked_at {
+        berths.leave(owner, from);
+    }
+    match boarding {
+        Some(Boarding::Embark) => {
+            berths.board(owner, to);
+            commands.entity(entity).insert(Embarked);
+        }
+        Some(Boarding::Disembark) => {
+            commands.entity(entity).remove::<Embarked>();
+        }
+        None => {}
+    }
+}
+
+/// Whether `unit` stepping onto `to` would stack more military units of its civilization
+/// there than the stacking limit allows. Units going aboard a transport do not count.
+fn exceeds_stacking_limit(
+    unit: &MilitaryUnit,
+    boarding: Option<Boarding>,
+    to: Position,
+    occupancy: &TileOccupancy,
+) -> bool {
+    unit.unit_class != UnitClass::Civilian
+        && boarding != Some(Boarding::Embark)
+        && !occupancy.has_room(unit.owner, to)
+}
+
+/// Record `entity`, which belongs to `unit`, as standing on `position` in the occupancy index
+fn record_unit_position(
+    occupancy: &mut TileOccupancy,
+    entity: Entity,
+    unit: &MilitaryUnit,
+    embarked: bool,
+    position: Position,
+) {
+    let occupant = TileOccupant {
+        entity,
+        owner: unit.owner,
+        military: unit.unit_class != UnitClass::Civilian && !embarked,
+    };
+    occupancy.place_unit(occupant, position);
+}
+
+/// The tiles next to units of civilizations `civ_id` is at war with, where its units stop
+pub fn hostile_zone_of_control(
+    occupancy: &TileOccupancy,
+    diplomatic_state: &DiplomaticState,
+    civ_id: CivId,
+) -> HashSet<Position> {
+    occupancy.zone_of_control(|other| diplomatic_state.is_at_war(civ_id, other))
+}
+
+/// System to move units one tile at a time on the player's orders
+///
+/// Steps onto a tile already holding as many of the civilization's military units as the
+/// stacking limit allows are refused, and a unit that steps next to a unit of a
+/// civilization it is at war with stops there for the turn.
 pub fn execute_movement_orders(
     mut commands: Commands,
     mut movement_query: Query<(
@@ -58,26 +172,97 @@ pub fn execute_movement_orders(
         &mut MilitaryUnit,
         &mut Position,
         &PlayerMovementOrder,
+        Option<&Transport>,
+        Has<Embarked>,
     )>,
+    other_units: Query<
+        (&MilitaryUnit, &Position, Option<&Transport>, Has<Embarked>),
+        Without<PlayerMovementOrder>,
+    >,
     world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut occupancy: ResMut<TileOccupancy>,
 ) {
-    for (entity, mut unit, mut position, movement_order) in movement_query.iter_mut() {
+    let mut zones_of_control: HashMap<CivId, HashSet<Position>> = HashMap::new();
+    let mut berths = TransportBerths::new(
+        movement_query
+            .iter()
+            .map(|(_, unit, position, _, transport, embarked)| {
+                (unit.owner, *position, transport, embarked)
+            })
+            .chain(
+                other_units
+                    .iter()
+                    .map(|(unit, position, transport, embarked)| {
+                        (unit.owner, *position, transport, embarked)
+                    }),
+            ),
+    );
+
+    for (entity, mut unit, mut position, movement_order, _, embarked) in movement_query.iter_mut() {
         let current_position = *position;
         let target_position = movement_order.target_position;
 
         commands.entity(entity).remove::<PlayerMovementOrder>();
 
-        match validate_movement_to_adjacent_tile(current_position, target_position, &world_map) {
-            Ok(movement_cost) => {
+        if !unit.is_reliable() {
+            CoreDebugUtils::log_unit_movement_failure(unit.id, "Unit refuses orders");
+            continue;
+        }
+
+        match validate_step(
+            &unit,
+            embarked,
+            current_position,
+            target_position,
+            &world_map,
+            &terrain_registry,
+            &berths,
+        ) {
+            Ok((_, boarding))
+                if exceeds_stacking_limit(&unit, boarding, target_position, &occupancy) =>
+            {
+                CoreDebugUtils::log_unit_movement_failure(
+                    unit.id,
+                    "Tile already holds as many units as it can stack",
+                );
+            }
+            Ok((movement_cost, boarding)) => {
                 if unit.movement_remaining >= movement_cost {
                     update_unit_facing_direction_from_movement(
                         &mut unit,
                         current_position,
                         target_position,
                     );
+                    apply_boarding(
+                        &mut commands,
+                        entity,
+                        unit.owner,
+                        embarked.then_some(current_position),
+                        target_position,
+                        boarding,
+                        &mut berths,
+                    );
 
                     *position = target_position;
                     unit.movement_remaining -= movement_cost;
+                    commands.entity(entity).remove::<UnitOrder>();
+                    unit.add_fatigue(movement_cost as f32 * logistics::FATIGUE_PER_MOVEMENT_POINT);
+                    record_unit_position(
+                        &mut occupancy,
+                        entity,
+                        &unit,
+                        boarding == Some(Boarding::Embark),
+                        target_position,
+                    );
+
+                    let zone_of_control = zones_of_control.entry(unit.owner).or_insert_with(|| {
+                        hostile_zone_of_control(&occupancy, &diplomatic_state, unit.owner)
+                    });
+                    if zone_of_control.contains(&target_position) {
+                        unit.movement_remaining = 0;
+                    }
 
                     CoreDebugUtils::log_unit_movement_success(
                         unit.id,
@@ -112,53 +297,458 @@ pub fn clear_completed_movement_orders(
     }
 }
 
-/// Execute AI movement orders (similar to player movement but uses MovementOrder instead of PlayerMovementOrder)
-pub fn execute_ai_movement_orders(
+/// Plan a goto order from `start` to `destination` along the cheapest path for a unit
+/// travelling in `domain` that steps around the `blocked` tiles short of the destination, or
+/// `None` if the destination cannot be reached
+pub fn plan_movement_order(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    start: Position,
+    destination: Position,
+    domain: MovementDomain,
+    blocked: &HashSet<Position>,
+) -> Option<MovementOrder> {
+    if start == destination {
+        return None;
+    }
+    let blocked: HashSet<Position> = blocked
+        .iter()
+        .copied()
+        .filter(|position| *position != destination)
+        .collect();
+    let path = Pathfinder::new().find_path_avoiding(
+        world_map,
+        terrain_registry,
+        start,
+        destination,
+        domain,
+        &blocked,
+    )?;
+    Some(MovementOrder::new(path[1..].to_vec(), destination))
+}
+
+/// Every tile a unit travelling in `domain` at `start` with `movement_points` left can reach
+/// this turn, mapped to the movement points it would have left on arrival
+///
+/// The `blocked` tiles cannot be entered, and movement ends on entering a tile in the
+/// `zone_of_control` of a hostile unit.
+pub fn reachable_tiles(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    start: Position,
+    domain: MovementDomain,
+    movement_points: u32,
+    blocked: &HashSet<Position>,
+    zone_of_control: &HashSet<Position>,
+) -> HashMap<Position, u32> {
+    let mut reachable = HashMap::from([(start, movement_points)]);
+    let mut frontier = BinaryHeap::from([(movement_points, start.x, start.y)]);
+
+    while let Some((remaining, x, y)) = frontier.pop() {
+        let current = Position::new(x, y);
+        if reachable[&current] > remaining {
+            continue;
+        }
+
+        for neighbor in world_map.neighbors(current) {
+            if blocked.contains(&neighbor) {
+                continue;
+            }
+            let Ok(movement_cost) = validate_movement_to_adjacent_tile(
+                current,
+                neighbor,
+                domain,
+                world_map,
+                terrain_registry,
+            ) else {
+                continue;
+            };
+            let Some(mut left) = remaining.checked_sub(movement_cost) else {
+                continue;
+            };
+            if zone_of_control.contains(&neighbor) {
+                left = 0;
+            }
+            if reachable.get(&neighbor).is_some_and(|best| *best >= left) {
+                continue;
+            }
+
+            reachable.insert(neighbor, left);
+            frontier.push((left, neighbor.x, neighbor.y));
+        }
+    }
+
+    reachable
+}
+
+/// The turn on which `unit` walking `path` from `start` reaches each of its steps, counting
+/// the current turn, with the movement points it has left, as turn 1
+///
+/// Mirrors `follow_movement_orders`: a step the unit cannot pay for waits until its movement
+/// points are refreshed at the start of the next turn, and entering a tile in the
+/// `zone_of_control` of a hostile unit ends the turn.
+pub fn path_arrival_turns(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    unit: &MilitaryUnit,
+    start: Position,
+    path: &[Position],
+    zone_of_control: &HashSet<Position>,
+) -> Vec<u32> {
+    let domain = unit.unit_class.domain();
+    let mut turn = 1;
+    let mut remaining = unit.movement_remaining;
+    let mut previous = start;
+
+    path.iter()
+        .map(|step| {
+            let movement_cost = validate_movement_to_adjacent_tile(
+                previous,
+                *step,
+                domain,
+                world_map,
+                terrain_registry,
+            )
+            .unwrap_or(movement_validation::DEFAULT_MOVEMENT_COST_WHEN_ZERO);
+            if remaining < movement_cost {
+                turn += 1;
+                remaining = unit.movement_range;
+            }
+            remaining = remaining.saturating_sub(movement_cost);
+            if zone_of_control.contains(step) {
+                remaining = 0;
+            }
+            previous = *step;
+            turn
+        })
+        .collect()
+}
+
+/// System to walk units along their `MovementOrder` paths, spending the movement points they
+/// have left each turn until they reach the destination
+///
+/// A step onto a tile held by a civilization the unit is at war with is left to
+/// `convert_hostile_moves_into_attacks`. Tiles held by other civilizations or already stacked
+/// to the limit are pathed around, and the order is dropped once no way around remains. A
+/// unit whose destination is the blocked tile waits next to it until it clears. A unit stops
+/// for the turn on stepping next to a hostile unit. Embarked units following an order go
+/// ashore with their first step.
+pub fn follow_movement_orders(
     mut commands: Commands,
-    mut movement_query: Query<(Entity, &mut MilitaryUnit, &mut Position, &MovementOrder)>,
+    mut movement_query: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &mut Position,
+        &mut MovementOrder,
+        Option<&Transport>,
+        Has<Embarked>,
+    )>,
+    stationary_units: Query<
+        (&MilitaryUnit, &Position, Option<&Transport>, Has<Embarked>),
+        Without<MovementOrder>,
+    >,
     world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut occupancy: ResMut<TileOccupancy>,
 ) {
-    for (entity, mut unit, mut position, movement_order) in movement_query.iter_mut() {
-        let current_position = *position;
+    let mut zones_of_control: HashMap<CivId, HashSet<Position>> = HashMap::new();
+    let mut berths = TransportBerths::new(
+        movement_query
+            .iter()
+            .map(|(_, unit, position, _, transport, embarked)| {
+                (unit.owner, *position, transport, embarked)
+            })
+            .chain(
+                stationary_units
+                    .iter()
+                    .map(|(unit, position, transport, embarked)| {
+                        (unit.owner, *position, transport, embarked)
+                    }),
+            ),
+    );
 
-        if let Some(next_position) = movement_order.next_position() {
+    for (entity, mut unit, mut position, mut movement_order, _, mut embarked) in
+        movement_query.iter_mut()
+    {
+        if !unit.is_reliable() {
+            CoreDebugUtils::log_unit_movement_failure(unit.id, "Unit refuses orders");
             commands.entity(entity).remove::<MovementOrder>();
+            continue;
+        }
 
-            match validate_movement_to_adjacent_tile(current_position, next_position, &world_map) {
-                Ok(movement_cost) => {
-                    if unit.movement_remaining >= movement_cost {
-                        update_unit_facing_direction_from_movement(
-                            &mut unit,
-                            current_position,
-                            next_position,
-                        );
+        while let Some(next_position) = movement_order.next_position() {
+            let foreign_owner = occupancy.foreign_owner_at(unit.owner, next_position);
+            if foreign_owner.is_some_and(|owner| diplomatic_state.is_at_war(unit.owner, owner)) {
+                break;
+            }
 
-                        *position = next_position;
-                        unit.movement_remaining -= movement_cost;
+            let step = validate_step(
+                &unit,
+                embarked,
+                *position,
+                next_position,
+                &world_map,
+                &terrain_registry,
+                &berths,
+            );
+            let stacked_full = step.as_ref().is_ok_and(|(_, boarding)| {
+                exceeds_stacking_limit(&unit, *boarding, next_position, &occupancy)
+            });
+            if foreign_owner.is_some() || stacked_full {
+                let destination = movement_order.destination;
+                if next_position == destination {
+                    break;
+                }
 
-                        CoreDebugUtils::log_unit_movement_success(
-                            unit.id,
-                            current_position.x,
-                            current_position.y,
-                            next_position.x,
-                            next_position.y,
-                            movement_cost,
-                            unit.movement_remaining,
-                        );
-                    } else {
-                        CoreDebugUtils::log_insufficient_movement_points(
+                let blocked = occupancy.blocked_tiles(unit.owner);
+                match plan_movement_order(
+                    &world_map,
+                    &terrain_registry,
+                    *position,
+                    destination,
+                    unit.unit_class.domain(),
+                    &blocked,
+                ) {
+                    Some(rerouted_order) => {
+                        *movement_order = rerouted_order;
+                        continue;
+                    }
+                    None => {
+                        CoreDebugUtils::log_unit_movement_failure(
                             unit.id,
-                            movement_cost,
-                            unit.movement_remaining,
+                            "No way around the units blocking the path",
                         );
+                        commands.entity(entity).remove::<MovementOrder>();
+                        break;
                     }
                 }
+            }
+
+            let (movement_cost, boarding) = match step {
+                Ok(step) => step,
                 Err(reason) => {
                     CoreDebugUtils::log_unit_movement_failure(unit.id, reason);
+                    commands.entity(entity).remove::<MovementOrder>();
+                    break;
                 }
+            };
+
+            // Out of movement points for this turn, carry on next turn
+            if unit.movement_remaining < movement_cost {
+                break;
             }
-        } else {
+
+            let current_position = *position;
+            update_unit_facing_direction_from_movement(&mut unit, current_position, next_position);
+            apply_boarding(
+                &mut commands,
+                entity,
+                unit.owner,
+                embarked.then_some(current_position),
+                next_position,
+                boarding,
+                &mut berths,
+            );
+            embarked = boarding == Some(Boarding::Embark);
+
+            *position = next_position;
+            unit.movement_remaining -= movement_cost;
+            unit.add_fatigue(movement_cost as f32 * logistics::FATIGUE_PER_MOVEMENT_POINT);
+            commands.entity(entity).remove::<UnitOrder>();
+            record_unit_position(&mut occupancy, entity, &unit, embarked, next_position);
+            movement_order.advance();
+
+            CoreDebugUtils::log_unit_movement_success(
+                unit.id,
+                current_position.x,
+                current_position.y,
+                next_position.x,
+                next_position.y,
+                movement_cost,
+                unit.movement_remaining,
+            );
+
+            let zone_of_control = zones_of_control.entry(unit.owner).or_insert_with(|| {
+                hostile_zone_of_control(&occupancy, &diplomatic_state, unit.owner)
+            });
+            if zone_of_control.contains(&next_position) {
+                unit.movement_remaining = 0;
+                break;
+            }
+        }
+
+        if movement_order.is_complete() {
             commands.entity(entity).remove::<MovementOrder>();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::occupancy::DEFAULT_MAX_UNITS_PER_TILE;
+    use crate::test_support::{terrain_registry, unit_registry, world_map};
+    use crate::{UnitRegistry, UnitType};
+    use bevy_ecs::system::RunSystemOnce;
+
+    fn tile(x: i32, y: i32) -> Position {
+        Position::new(x, y)
+    }
+
+    #[test]
+    fn test_reachable_tiles_spend_terrain_costs() {
+        let world_map = world_map(&["..^..", "....."]);
+        let reachable = reachable_tiles(
+            &world_map,
+            &terrain_registry(),
+            tile(0, 0),
+            MovementDomain::Land,
+            3,
+            &HashSet::new(),
+            &HashSet::new(),
+        );
+
+        let expected = HashMap::from([
+            (tile(0, 0), 3),
+            (tile(1, 0), 2),
+            (tile(0, 1), 2),
+            (tile(1, 1), 1),
+            (tile(2, 0), 0),
+            (tile(2, 1), 0),
+        ]);
+        assert_eq!(reachable, expected);
+    }
+
+    #[test]
+    fn test_reachable_tiles_respect_blocking_and_zone_of_control() {
+        let world_map = world_map(&["...", "..."]);
+        let reachable = reachable_tiles(
+            &world_map,
+            &terrain_registry(),
+            tile(0, 0),
+            MovementDomain::Land,
+            3,
+            &HashSet::from([tile(1, 0)]),
+            &HashSet::from([tile(0, 1)]),
+        );
+
+        assert_eq!(reachable, HashMap::from([(tile(0, 0), 3), (tile(0, 1), 0)]));
+    }
+
+    #[test]
+    fn test_reachable_tiles_keep_ships_at_sea() {
+        let world_map = world_map(&["~~."]);
+        let reachable = reachable_tiles(
+            &world_map,
+            &terrain_registry(),
+            tile(0, 0),
+            MovementDomain::Sea,
+            2,
+            &HashSet::new(),
+            &HashSet::new(),
+        );
+
+        assert_eq!(reachable, HashMap::from([(tile(0, 0), 2), (tile(1, 0), 1)]));
+    }
+
+    #[test]
+    fn test_path_arrival_turns_wait_for_fresh_movement_points() {
+        let world_map = world_map(&["....^"]);
+        let terrain_registry = terrain_registry();
+        let mut unit = MilitaryUnit::new(
+            0,
+            CivId(0),
+            UnitType::new("Infantry"),
+            tile(0, 0),
+            &UnitRegistry::default(),
+        );
+        unit.movement_range = 2;
+        unit.movement_remaining = 2;
+        let path = [tile(1, 0), tile(2, 0), tile(3, 0), tile(4, 0)];
+        let arrival_turns = |zone_of_control: &HashSet<Position>| {
+            path_arrival_turns(
+                &world_map,
+                &terrain_registry,
+                &unit,
+                tile(0, 0),
+                &path,
+                zone_of_control,
+            )
+        };
+
+        assert_eq!(arrival_turns(&HashSet::new()), vec![1, 1, 2, 3]);
+        assert_eq!(
+            arrival_turns(&HashSet::from([tile(1, 0)])),
+            vec![1, 2, 2, 3]
+        );
+    }
+
+    /// Walks a unit of civilization 0 from the west end of `rows` along `path` while a unit of
+    /// civilization 1, at peace with it, stands on `obstacle`, returning where the walker ends
+    /// up and whether it is still following its order
+    fn walk_past(rows: &[&str], path: &[Position], obstacle: Position) -> (Position, bool) {
+        let mut world = World::new();
+        world.insert_resource(world_map(rows));
+        world.insert_resource(terrain_registry());
+        world.insert_resource(DiplomaticState::default());
+
+        let unit_registry = unit_registry();
+        let unit = |id, owner, position| {
+            MilitaryUnit::new(
+                id,
+                owner,
+                UnitType::new("Infantry"),
+                position,
+                &unit_registry,
+            )
+        };
+        let mut walker = unit(0, CivId(0), tile(0, 0));
+        walker.movement_remaining = 10;
+        let destination = *path.last().unwrap();
+        let walker_entity = world
+            .spawn((
+                walker,
+                tile(0, 0),
+                MovementOrder::new(path.to_vec(), destination),
+            ))
+            .id();
+        let blocker_entity = world.spawn((unit(1, CivId(1), obstacle), obstacle)).id();
+
+        let occupant = |entity, owner| TileOccupant {
+            entity,
+            owner,
+            military: true,
+        };
+        world.insert_resource(TileOccupancy::new(
+            DEFAULT_MAX_UNITS_PER_TILE,
+            [
+                (occupant(walker_entity, CivId(0)), tile(0, 0)),
+                (occupant(blocker_entity, CivId(1)), obstacle),
+            ],
+            [],
+        ));
+
+        world.run_system_once(follow_movement_orders).unwrap();
+        let position = *world.get::<Position>(walker_entity).unwrap();
+        let following_order = world.get::<MovementOrder>(walker_entity).is_some();
+        (position, following_order)
+    }
+
+    #[test]
+    fn test_movement_orders_path_around_blocked_tiles() {
+        let path = [tile(1, 0), tile(2, 0)];
+
+        assert_eq!(
+            walk_past(&["...", "..."], &path, tile(1, 0)),
+            (tile(2, 0), false)
+        );
+    }
+
+    #[test]
+    fn test_movement_orders_wait_next_to_a_blocked_destination() {
+        let path = [tile(1, 0), tile(2, 0)];
+
+        assert_eq!(walk_past(&["..."], &path, tile(2, 0)), (tile(1, 0), true));
+    }
+}
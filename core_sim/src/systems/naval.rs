@@ -0,0 +1,287 @@
+use crate::{
+    components::rendering::SpriteEntityReference,
+    systems::{combat_resolution::despawn_unit, movement::reachable_tiles},
+    CivId, Embarked, MilitaryUnit, MovementDomain, Position, TerrainRegistry, Transport, UnitClass,
+    UnitRegistry, UnitType, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Event emitted when embarked units are left on the water without a transport
+#[derive(Message, Debug, Clone)]
+pub struct UnitLostAtSea {
+    pub civ_id: CivId,
+    pub unit_type: UnitType,
+    pub position: Position,
+}
+
+/// Free berths aboard each civilization's transports, by tile
+#[derive(Debug, Default)]
+pub(crate) struct TransportBerths(HashMap<(CivId, Position), u32>);
+
+impl TransportBerths {
+    /// Count the free berths on every tile from each unit's owner and position, the
+    /// transport it is, if any, and whether it is embarked
+    pub(crate) fn new<'a>(
+        units: impl IntoIterator<Item = (CivId, Position, Option<&'a Transport>, bool)>,
+    ) -> Self {
+        let mut capacity: HashMap<(CivId, Position), u32> = HashMap::new();
+        let mut cargo: HashMap<(CivId, Position), u32> = HashMap::new();
+        for (owner, position, transport, embarked) in units {
+            if let Some(transport) = transport {
+                *capacity.entry((owner, position)).or_default() += transport.capacity;
+            }
+            if embarked {
+                *cargo.entry((owner, position)).or_default() += 1;
+            }
+        }
+
+        Self(
+            capacity
+                .into_iter()
+                .map(|(tile, capacity)| {
+                    let aboard = cargo.get(&tile).copied().unwrap_or_default();
+                    (tile, capacity.saturating_sub(aboard))
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn has_free_berth(&self, civ_id: CivId, position: Position) -> bool {
+        self.0
+            .get(&(civ_id, position))
+            .is_some_and(|free| *free > 0)
+    }
+
+    pub(crate) fn board(&mut self, civ_id: CivId, position: Position) {
+        if let Some(free) = self.0.get_mut(&(civ_id, position)) {
+            *free = free.saturating_sub(1);
+        }
+    }
+
+    pub(crate) fn leave(&mut self, civ_id: CivId, position: Position) {
+        if let Some(free) = self.0.get_mut(&(civ_id, position)) {
+            *free += 1;
+        }
+    }
+}
+
+/// A navigable tile next to `position` where a ship built there can be launched, or `None`
+/// if the city at `position` is not on the coast
+pub fn naval_launch_site(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    position: Position,
+) -> Option<Position> {
+    world_map.neighbors(position).into_iter().find(|neighbor| {
+        world_map
+            .get_tile(*neighbor)
+            .is_some_and(|tile| terrain_registry.is_navigable(&tile.terrain))
+    })
+}
+
+/// Where a land unit at `land` and a ship at `sea` can meet: the shore tile the unit can reach
+/// most cheaply over land, paired with a navigable tile beside it the ship can sail to, or
+/// `None` if there is no such place
+///
+/// Neither search enters the `blocked` tiles. Ties go to the tile with the lowest `y` and
+/// then `x` so the choice does not depend on iteration order.
+pub fn find_crossing_point(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    land: Position,
+    sea: Position,
+    blocked: &HashSet<Position>,
+) -> Option<(Position, Position)> {
+    let reach = |start: Position, domain: MovementDomain| {
+        reachable_tiles(
+            world_map,
+            terrain_registry,
+            start,
+            domain,
+            u32::MAX,
+            blocked,
+            &HashSet::new(),
+        )
+    };
+    let waters = reach(sea, MovementDomain::Sea);
+
+    reach(land, MovementDomain::Land)
+        .into_iter()
+        .filter_map(|(shore, remaining)| {
+            let water = world_map
+                .neighbors(shore)
+                .into_iter()
+                .filter(|neighbor| waters.contains_key(neighbor))
+                .min_by_key(|neighbor| (u32::MAX - waters[neighbor], neighbor.y, neighbor.x))?;
+            Some((u32::MAX - remaining, shore, water))
+        })
+        .min_by_key(|(cost, shore, _)| (*cost, shore.y, shore.x))
+        .map(|(_, shore, water)| (shore, water))
+}
+
+/// System to keep the land units aboard each transport with it as it sails
+///
+/// Ships able to carry land units are fitted out as a `Transport` the first time they are
+/// seen. Whenever a transport has moved, as many embarked units of its civilization as it
+/// has room for follow it from the tile it left. Embarked units that end up on the water
+/// without a transport of their own civilization are lost at sea.
+pub fn carry_embarked_units(
+    mut commands: Commands,
+    unit_registry: Res<UnitRegistry>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    new_ships: Query<(Entity, &MilitaryUnit, &Position), (Without<Transport>, Without<Embarked>)>,
+    mut transports: Query<(&MilitaryUnit, &Position, &mut Transport), Without<Embarked>>,
+    mut cargo: Query<
+        (
+            Entity,
+            &MilitaryUnit,
+            &mut Position,
+            Option<&SpriteEntityReference>,
+        ),
+        With<Embarked>,
+    >,
+    mut lost_events: MessageWriter<UnitLostAtSea>,
+) {
+    for (entity, ship, position) in new_ships.iter() {
+        if ship.unit_class != UnitClass::Naval {
+            continue;
+        }
+        let capacity = unit_registry.transport_capacity(&ship.unit_type);
+        if capacity > 0 {
+            commands.entity(entity).insert(Transport {
+                capacity,
+                anchorage: *position,
+            });
+        }
+    }
+
+    for (ship, position, mut transport) in transports.iter_mut() {
+        if transport.anchorage == *position {
+            continue;
+        }
+
+        let departed_from = transport.anchorage;
+        for (_, _, mut cargo_position, _) in cargo
+            .iter_mut()
+            .filter(|(_, unit, cargo_position, _)| {
+                unit.owner == ship.owner && **cargo_position == departed_from
+            })
+            .take(transport.capacity as usize)
+        {
+            *cargo_position = *position;
+        }
+        transport.anchorage = *position;
+    }
+
+    let harbours: HashSet<(CivId, Position)> = transports
+        .iter()
+        .map(|(ship, position, _)| (ship.owner, *position))
+        .collect();
+    for (entity, unit, position, sprite_reference) in cargo.iter() {
+        if harbours.contains(&(unit.owner, *position)) {
+            continue;
+        }
+
+        let ashore = world_map
+            .get_tile(*position)
+            .is_some_and(|tile| terrain_registry.can_enter(&tile.terrain, MovementDomain::Land));
+        if ashore {
+            commands.entity(entity).remove::<Embarked>();
+            continue;
+        }
+
+        tracing::info!(
+            "Unit {} of civ {} was lost at sea at ({}, {})",
+            unit.id,
+            unit.owner.0,
+            position.x,
+            position.y
+        );
+        lost_events.write(UnitLostAtSea {
+            civ_id: unit.owner,
+            unit_type: unit.unit_type.clone(),
+            position: *position,
+        });
+        despawn_unit(&mut commands, entity, sprite_reference);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{terrain_registry, world_map};
+
+    #[test]
+    fn test_transport_berths_track_free_room() {
+        let harbour = Position::new(1, 0);
+        let transport = Transport {
+            capacity: 2,
+            anchorage: harbour,
+        };
+        let mut berths = TransportBerths::new([
+            (CivId(0), harbour, Some(&transport), false),
+            (CivId(0), harbour, None, true),
+            (CivId(1), Position::new(3, 0), None, true),
+        ]);
+
+        assert!(berths.has_free_berth(CivId(0), harbour));
+        assert!(!berths.has_free_berth(CivId(1), harbour));
+        assert!(!berths.has_free_berth(CivId(1), Position::new(3, 0)));
+
+        berths.board(CivId(0), harbour);
+        assert!(!berths.has_free_berth(CivId(0), harbour));
+        berths.board(CivId(0), harbour);
+        berths.leave(CivId(0), harbour);
+        assert!(berths.has_free_berth(CivId(0), harbour));
+    }
+
+    #[test]
+    fn test_overloaded_transport_has_no_free_berth() {
+        let harbour = Position::new(0, 0);
+        let transport = Transport {
+            capacity: 1,
+            anchorage: harbour,
+        };
+        let berths = TransportBerths::new([
+            (CivId(0), harbour, Some(&transport), false),
+            (CivId(0), harbour, None, true),
+            (CivId(0), harbour, None, true),
+        ]);
+
+        assert!(!berths.has_free_berth(CivId(0), harbour));
+    }
+
+    #[test]
+    fn test_find_crossing_point_meets_at_nearest_shore() {
+        let world_map = world_map(&["..~~.."]);
+        let terrain_registry = terrain_registry();
+        let crossing = |land, sea| {
+            find_crossing_point(&world_map, &terrain_registry, land, sea, &HashSet::new())
+        };
+
+        assert_eq!(
+            crossing(Position::new(0, 0), Position::new(3, 0)),
+            Some((Position::new(1, 0), Position::new(2, 0)))
+        );
+        assert_eq!(
+            crossing(Position::new(5, 0), Position::new(2, 0)),
+            Some((Position::new(4, 0), Position::new(3, 0)))
+        );
+    }
+
+    #[test]
+    fn test_find_crossing_point_needs_connected_water() {
+        let world_map = world_map(&["..~.~"]);
+        let crossing = find_crossing_point(
+            &world_map,
+            &terrain_registry(),
+            Position::new(0, 0),
+            Position::new(4, 0),
+            &HashSet::new(),
+        );
+
+        assert_eq!(crossing, None);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{
+    components::military::UnitClass,
+    resources::{GameConfig, TileOccupancy, TileOccupant},
+    tile::tile_components::{TileContents, WorldTile},
+    Capital, City, Embarked, MilitaryUnit, Position,
+};
+use bevy_ecs::prelude::*;
+
+/// System to rebuild the `TileOccupancy` index from the `Position` of every unit and city
+pub fn update_tile_occupancy(
+    mut occupancy: ResMut<TileOccupancy>,
+    game_config: Res<GameConfig>,
+    units: Query<(Entity, &MilitaryUnit, &Position, Has<Embarked>)>,
+    cities: Query<(Entity, &City, &Position)>,
+) {
+    occupancy.set_if_neq(TileOccupancy::new(
+        game_config.max_units_per_tile,
+        units.iter().map(|(entity, unit, position, embarked)| {
+            let occupant = TileOccupant {
+                entity,
+                owner: unit.owner,
+                military: unit.unit_class != UnitClass::Civilian && !embarked,
+            };
+            (occupant, *position)
+        }),
+        cities
+            .iter()
+            .map(|(entity, city, position)| (entity, city.owner, *position)),
+    ));
+}
+
+/// System to mirror the `TileOccupancy` index into the `TileContents` of each map tile
+pub fn sync_tile_contents(
+    occupancy: Res<TileOccupancy>,
+    capitals: Query<(), With<Capital>>,
+    mut tiles: Query<(&WorldTile, &mut TileContents)>,
+) {
+    if !occupancy.is_changed() {
+        return;
+    }
+
+    for (tile, mut contents) in tiles.iter_mut() {
+        let position = tile.grid_pos;
+        contents.units = occupancy
+            .units_at(position)
+            .iter()
+            .map(|occupant| occupant.entity)
+            .collect();
+        contents.cities = occupancy
+            .city_at(position)
+            .map(|(entity, _)| entity)
+            .into_iter()
+            .collect();
+        contents.capitals = contents
+            .cities
+            .iter()
+            .copied()
+            .filter(|entity| capitals.contains(*entity))
+            .collect();
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    components::turn_phases::TurnPhase, constants::population, BorderPressure, BuildingRegistry,
+    City, CityGrowth, CivId, Position, TerrainRegistry, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashSet;
+
+/// Event emitted when a city gains or loses a citizen
+#[derive(Message, Debug, Clone)]
+pub struct PopulationChanged {
+    pub city_entity: Entity,
+    pub civ_id: CivId,
+    pub city_name: String,
+    pub previous_population: u32,
+    pub new_population: u32,
+}
+
+/// System to have every city work its tiles, store its food surplus and grow or starve,
+/// during the turn transition
+///
+/// Each citizen works the best free tile the city's civilization owns within the city's
+/// borders; the city center is always worked. Production is refreshed from the worked
+/// tiles so it is up to date before production queues advance.
+pub fn grow_populations(
+    turn_phase: Res<TurnPhase>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    building_registry: Res<BuildingRegistry>,
+    mut cities: Query<(
+        Entity,
+        &mut City,
+        &Position,
+        &BorderPressure,
+        &mut CityGrowth,
+    )>,
+    mut population_events: MessageWriter<PopulationChanged>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let mut claimed_tiles: HashSet<Position> =
+        cities.iter().map(|(_, _, pos, _, _)| *pos).collect();
+
+    for (city_entity, mut city, city_position, pressure, mut growth) in cities.iter_mut() {
+        growth.worked_tiles = choose_worked_tiles(
+            &city,
+            *city_position,
+            pressure.radius,
+            &world_map,
+            &terrain_registry,
+            &claimed_tiles,
+        );
+        claimed_tiles.extend(growth.worked_tiles.iter().copied());
+
+        let worked_yields: Vec<(f32, f32, f32)> = std::iter::once(*city_position)
+            .chain(growth.worked_tiles.iter().copied())
+            .filter_map(|position| world_map.get_tile(position))
+            .map(|tile| terrain_registry.yields(&tile.terrain))
+            .collect();
+        let tile_food: f32 = worked_yields.iter().map(|(food, _, _)| food).sum();
+        let tile_production: f32 = worked_yields
+            .iter()
+            .map(|(_, production, _)| production)
+            .sum();
+
+        city.tile_production = tile_production;
+        city.update_stats(&building_registry);
+
+        growth.food_surplus =
+            tile_food + city.building_food(&building_registry) - city.food_consumption();
+        growth.food_stored += growth.food_surplus;
+
+        let previous_population = city.population;
+        let growth_threshold = city.growth_threshold(&building_registry);
+        if growth.food_stored >= growth_threshold {
+            growth.food_stored -= growth_threshold;
+            city.grow_population(population::POPULATION_PER_CITIZEN);
+        } else if growth.food_stored < 0.0 {
+            growth.food_stored = 0.0;
+            city.shrink_population(population::POPULATION_PER_CITIZEN);
+        }
+
+        if city.population != previous_population {
+            tracing::debug!(
+                "{} population changed from {} to {}",
+                city.name,
+                previous_population,
+                city.population
+            );
+            population_events.write(PopulationChanged {
+                city_entity,
+                civ_id: city.owner,
+                city_name: city.name.clone(),
+                previous_population,
+                new_population: city.population,
+            });
+        }
+    }
+}
+
+/// The best tiles for `city`'s citizens, by food plus production, that no other city works
+fn choose_worked_tiles(
+    city: &City,
+    city_position: Position,
+    radius: i32,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    claimed_tiles: &HashSet<Position>,
+) -> Vec<Position> {
+    let mut candidates: Vec<(Position, f32)> = (-radius..=radius)
+        .flat_map(|dx| {
+            let reach = radius - dx.abs();
+            (-reach..=reach)
+                .map(move |dy| Position::new(city_position.x + dx, city_position.y + dy))
+        })
+        .filter(|position| !claimed_tiles.contains(position))
+        .filter_map(|position| {
+            let tile = world_map.get_tile(position)?;
+            if tile.owner != Some(city.owner) {
+                return None;
+            }
+            let (food, production, _) = terrain_registry.yields(&tile.terrain);
+            Some((position, food + production))
+        })
+        .collect();
+
+    candidates.sort_by(|(pos_a, score_a), (pos_b, score_b)| {
+        score_b
+            .total_cmp(score_a)
+            .then(pos_a.x.cmp(&pos_b.x))
+            .then(pos_a.y.cmp(&pos_b.y))
+    });
+
+    candidates
+        .into_iter()
+        .take(city.citizens() as usize)
+        .map(|(position, _)| position)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{building_registry, terrain_registry, world_map};
+    use crate::{Building, BuildingType};
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+
+    /// Run `grow_populations` once for `city` alone on a strip of two plains tiles it owns,
+    /// so its center and at most one other tile are worked
+    fn grow(city: City, food_stored: f32) -> (City, CityGrowth, usize) {
+        let mut world = World::new();
+        let mut world_map = world_map(&[".."]);
+        for x in 0..2 {
+            world_map.get_tile_mut(Position::new(x, 0)).unwrap().owner = Some(city.owner);
+        }
+        world.insert_resource(world_map);
+        world.insert_resource(terrain_registry());
+        world.insert_resource(building_registry());
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.init_resource::<Messages<PopulationChanged>>();
+
+        let city_entity = world
+            .spawn((
+                city,
+                Position::new(0, 0),
+                BorderPressure::default(),
+                CityGrowth {
+                    food_stored,
+                    ..Default::default()
+                },
+            ))
+            .id();
+
+        world.run_system_once(grow_populations).unwrap();
+
+        let changes = world.resource::<Messages<PopulationChanged>>().len();
+        let mut entity = world.entity_mut(city_entity);
+        let growth = entity.take::<CityGrowth>().unwrap();
+        (entity.take::<City>().unwrap(), growth, changes)
+    }
+
+    fn city(population: u32, buildings: &[&str]) -> City {
+        let mut city = City::new("Test".to_string(), CivId(0));
+        city.population = population;
+        city.buildings = buildings
+            .iter()
+            .map(|&name| Building {
+                building_type: BuildingType::new(name),
+                level: 1,
+            })
+            .collect();
+        city
+    }
+
+    /// Food from the city center and the one other tile
+    fn worked_food() -> f32 {
+        let (food, _, _) = terrain_registry().yields(&crate::TerrainType::Plains);
+        2.0 * food
+    }
+
+    #[test]
+    fn test_city_grows_once_enough_food_is_stored() {
+        let city = city(1000, &[]);
+        let threshold = city.growth_threshold(&building_registry());
+        let surplus = worked_food() - city.food_consumption();
+        assert!(surplus > 0.0);
+
+        let (city, growth, changes) = grow(city, threshold - surplus + 1.0);
+
+        assert_eq!(city.population, 2000);
+        assert_eq!(growth.food_surplus, surplus);
+        assert_eq!(growth.food_stored, 1.0);
+        assert_eq!(changes, 1);
+    }
+
+    #[test]
+    fn test_city_keeps_storing_food_below_the_threshold() {
+        let city = city(1000, &[]);
+        let surplus = worked_food() - city.food_consumption();
+
+        let (city, growth, changes) = grow(city, 0.0);
+
+        assert_eq!(city.population, 1000);
+        assert_eq!(growth.food_stored, surplus);
+        assert_eq!(changes, 0);
+    }
+
+    #[test]
+    fn test_city_starves_when_its_stores_run_out() {
+        let city = city(3000, &[]);
+        assert!(worked_food() < city.food_consumption());
+
+        let (city, growth, changes) = grow(city, 0.0);
+
+        assert_eq!(city.population, 2000);
+        assert_eq!(growth.food_stored, 0.0);
+        assert_eq!(changes, 1);
+    }
+
+    #[test]
+    fn test_starvation_never_empties_a_city() {
+        let (city, _, changes) = grow(city(1000, &[]), -100.0);
+
+        assert_eq!(city.population, population::MIN_CITY_POPULATION);
+        assert_eq!(changes, 0);
+    }
+
+    #[test]
+    fn test_granary_lowers_the_food_needed_to_grow() {
+        let building_registry = building_registry();
+        let without = city(1000, &[]);
+        let with_granary = city(1000, &["Granary"]);
+        let base_threshold = without.growth_threshold(&building_registry);
+        let granary_threshold = with_granary.growth_threshold(&building_registry);
+        let reduction = building_registry
+            .definition(&BuildingType::new("Granary"))
+            .growth_threshold_reduction;
+        assert!(reduction > 0.0);
+        assert_eq!(granary_threshold, base_threshold * (1.0 - reduction));
+
+        // Enough to grow with the granary's discount, but short of the full threshold
+        let stored_after_harvest = (granary_threshold + base_threshold) / 2.0;
+        let surplus = worked_food() + with_granary.building_food(&building_registry)
+            - with_granary.food_consumption();
+
+        let (city, growth, _) = grow(with_granary, stored_after_harvest - surplus);
+
+        assert_eq!(city.population, 2000);
+        assert!((growth.food_stored - (stored_after_harvest - granary_threshold)).abs() < 1e-4);
+    }
+}
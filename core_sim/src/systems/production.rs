@@ -1,28 +1,188 @@
 use crate::components::production::{PlayerActionsComplete, ProductionItem, ProductionQueue};
 use crate::resources::CurrentTurn;
+use crate::systems::naval::naval_launch_site;
+use crate::tech_tree::TechTree;
 use crate::{
-    Capital, City, CivId, Civilization, MilitaryUnit, PlayerControlled, Position, WorldMap,
+    BuildingRegistry, Capital, City, CivId, Civilization, GameResource, MilitaryUnit,
+    MovementDomain, MovementOrder, PlayerControlled, Position, TerrainRegistry, UnitOrder,
+    UnitRegistry, WorldMap,
 };
 use bevy::prelude::*;
+use std::fmt;
+
+/// Reasons a city cannot take on a production item
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProductionError {
+    /// Ships need water next to the city to be launched onto
+    NotCoastal,
+    /// No technology unlocking the item is known yet; any one of these would do
+    Locked(Vec<String>),
+    /// Technologies the item's definition requires that are not known yet
+    MissingTechnologies(Vec<String>),
+    /// Strategic resources the civilization has no access to
+    MissingResources(Vec<GameResource>),
+    /// The city already has the building at its maximum level
+    AtMaximumLevel,
+    /// The civilization cannot pay the item's gold cost
+    InsufficientGold { cost: f32, available: f32 },
+}
+
+impl fmt::Display for ProductionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProductionError::NotCoastal => write!(f, "the city is not on the coast"),
+            ProductionError::Locked(technologies) => {
+                write!(f, "requires {}", technologies.join(" or "))
+            }
+            ProductionError::MissingTechnologies(technologies) => {
+                write!(f, "requires {}", technologies.join(", "))
+            }
+            ProductionError::MissingResources(resources) => {
+                let names: Vec<&str> = resources.iter().map(GameResource::name).collect();
+                write!(f, "requires access to {}", names.join(", "))
+            }
+            ProductionError::AtMaximumLevel => write!(f, "already at its maximum level"),
+            ProductionError::InsufficientGold { cost, available } => {
+                write!(f, "costs {} gold, {:.0} available", cost, available)
+            }
+        }
+    }
+}
+
+/// Check that `civilization` may produce `item` in `city`, which stands at `city_position`.
+/// The item must be unlocked, its required technologies known and its required resources
+/// in hand; ships also need a coastal city and buildings room for another level.
+pub fn validate_production(
+    item: &ProductionItem,
+    civilization: &Civilization,
+    city: &City,
+    city_position: Position,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    tech_tree: &TechTree,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+) -> Result<(), ProductionError> {
+    if !tech_tree.can_produce(&civilization.technologies, item) {
+        return Err(ProductionError::Locked(
+            tech_tree
+                .unlocking_technologies(item.name())
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        ));
+    }
+
+    let (required_technologies, required_resources) = match item {
+        ProductionItem::Unit(unit_type) => {
+            let definition = unit_registry.definition(unit_type);
+            if definition.class().domain() == MovementDomain::Sea
+                && naval_launch_site(world_map, terrain_registry, city_position).is_none()
+            {
+                return Err(ProductionError::NotCoastal);
+            }
+            (
+                &definition.required_technologies,
+                &definition.required_resources,
+            )
+        }
+        ProductionItem::Building(building_type) => {
+            if !city.can_build(building_type, building_registry) {
+                return Err(ProductionError::AtMaximumLevel);
+            }
+            let definition = building_registry.definition(building_type);
+            (
+                &definition.required_technologies,
+                &definition.required_resources,
+            )
+        }
+    };
+
+    let missing_technologies: Vec<String> = required_technologies
+        .iter()
+        .filter(|technology| !civilization.technologies.knows(technology))
+        .cloned()
+        .collect();
+    if !missing_technologies.is_empty() {
+        return Err(ProductionError::MissingTechnologies(missing_technologies));
+    }
+
+    let missing_resources: Vec<GameResource> = required_resources
+        .iter()
+        .copied()
+        .filter(|resource| !civilization.economy.has_resource(*resource))
+        .collect();
+    if !missing_resources.is_empty() {
+        return Err(ProductionError::MissingResources(missing_resources));
+    }
+
+    Ok(())
+}
+
+/// Validate `item` with `validate_production`, then pay its gold cost up front and add it to
+/// `production_queue`
+pub fn queue_production(
+    item: ProductionItem,
+    civilization: &mut Civilization,
+    city: &City,
+    city_position: Position,
+    production_queue: &mut ProductionQueue,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    tech_tree: &TechTree,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+) -> Result<(), ProductionError> {
+    validate_production(
+        &item,
+        civilization,
+        city,
+        city_position,
+        world_map,
+        terrain_registry,
+        tech_tree,
+        unit_registry,
+        building_registry,
+    )?;
+
+    let cost = item.gold_cost(unit_registry, building_registry);
+    if civilization.economy.gold < cost {
+        return Err(ProductionError::InsufficientGold {
+            cost,
+            available: civilization.economy.gold,
+        });
+    }
+
+    civilization.economy.gold -= cost;
+    production_queue.add_to_queue(item);
+    Ok(())
+}
 
 /// System to process production queues each turn
 pub fn process_production_queues(
-    mut query: Query<(&mut ProductionQueue, &mut City, &Capital, &Position)>,
+    mut query: Query<(&mut ProductionQueue, &mut City, &Position)>,
     mut commands: Commands,
     mut unit_id_counter: Local<u32>,
     world_map: Res<WorldMap>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
     current_turn: Res<CurrentTurn>,
 ) {
-    for (mut production_queue, mut city, capital, position) in query.iter_mut() {
-        if let Some(completed_item) = production_queue.add_production(city.production) {
+    for (mut production_queue, mut city, position) in query.iter_mut() {
+        if let Some(completed_item) =
+            production_queue.add_production(city.production, &unit_registry, &building_registry)
+        {
+            let owner = city.owner;
             spawn_completed_production(
                 &mut commands,
                 &completed_item,
-                &capital.owner,
+                &owner,
                 position,
                 &mut unit_id_counter,
                 &mut city,
                 &world_map,
+                &unit_registry,
+                &building_registry,
                 current_turn.0,
             );
         }
@@ -38,23 +198,32 @@ fn spawn_completed_production(
     unit_id_counter: &mut u32,
     city: &mut City,
     _world_map: &WorldMap,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
     _current_turn: u32,
 ) {
     match item {
         ProductionItem::Unit(unit_type) => {
             // Spawn new military unit at capital position
-            let unit = MilitaryUnit::new(*unit_id_counter, *owner, *unit_type, *position);
+            let unit = MilitaryUnit::new(
+                *unit_id_counter,
+                *owner,
+                unit_type.clone(),
+                *position,
+                unit_registry,
+            );
             *unit_id_counter += 1;
 
             // Add PlayerControlled component for player civilizations (CivId(0))
-            let mut entity_commands = commands.spawn((unit, *position));
+            let mut entity_commands = commands.spawn((unit, *position, *owner));
+            entity_commands.insert(crate::ProvidesVision::unit_vision());
             if owner.0 == 0 {
                 entity_commands.insert(PlayerControlled);
             }
         }
         ProductionItem::Building(building_type) => {
             // Add building to city
-            city.add_building(building_type.clone());
+            city.add_building(building_type.clone(), building_registry);
         }
     }
 }
@@ -62,7 +231,7 @@ fn spawn_completed_production(
 /// System to check if all player actions are complete
 pub fn check_player_actions_complete(
     player_civs: Query<&Civilization, With<PlayerControlled>>,
-    player_units: Query<&MilitaryUnit>,
+    player_units: Query<&MilitaryUnit, (Without<UnitOrder>, Without<MovementOrder>)>,
     player_capitals: Query<&ProductionQueue, (With<Capital>, With<PlayerControlled>)>,
     mut player_actions: ResMut<PlayerActionsComplete>,
 ) {
@@ -84,7 +253,8 @@ pub fn check_player_actions_complete(
         return;
     };
 
-    // Check if all player units have moved or have no movement left
+    // Check if all player units have moved, have no movement left or are following a
+    // standing or goto order
     let mut all_units_moved = true;
     for unit in player_units.iter() {
         if unit.owner == player_civ_id && unit.can_move() {
@@ -124,7 +294,7 @@ pub fn handle_player_production_orders(
     for _order in production_orders.read() {
         // Mark that player has made production decisions this turn
         // Note: The actual order processing (gold deduction and queue addition)
-        // is now handled directly in the UI for immediate feedback
+        // is now handled directly in the UI for immediate feedback, through `queue_production`
         player_actions.production_decisions_made_this_turn = true;
     }
 }
@@ -163,3 +333,130 @@ pub fn reset_unit_movement(
     // Reset player actions tracking
     player_actions.reset();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        building_registry, tech_tree, terrain_registry, unit_registry, world_map,
+    };
+    use crate::{CivPersonality, Economy, Military, Technologies, UnitType};
+
+    fn civilization(technologies: &[&str], resources: &[GameResource]) -> Civilization {
+        let known: Vec<String> = technologies.iter().map(|tech| tech.to_string()).collect();
+        Civilization {
+            id: CivId(0),
+            name: "Civ 0".to_string(),
+            color: [0.0; 3],
+            capital: None,
+            personality: CivPersonality::default(),
+            technologies: Technologies::with_known(&known),
+            economy: Economy {
+                resources: resources.iter().map(|resource| (*resource, 1)).collect(),
+                ..Economy::default()
+            },
+            military: Military::default(),
+            music_theme: String::new(),
+            sound_theme: String::new(),
+        }
+    }
+
+    /// `validate_production` for a city at the west end of `rows`
+    fn validate(
+        item: &ProductionItem,
+        civilization: &Civilization,
+        rows: &[&str],
+    ) -> Result<(), ProductionError> {
+        let city = City::new("Test".to_string(), CivId(0));
+        validate_production(
+            item,
+            civilization,
+            &city,
+            Position::new(0, 0),
+            &world_map(rows),
+            &terrain_registry(),
+            &tech_tree(),
+            &unit_registry(),
+            &building_registry(),
+        )
+    }
+
+    fn unit(name: &str) -> ProductionItem {
+        ProductionItem::Unit(UnitType::new(name))
+    }
+
+    #[test]
+    fn test_units_are_locked_until_their_technology_is_known() {
+        let horsemen = civilization(&[], &[GameResource::Horses]);
+        let result = validate(&unit("Cavalry"), &horsemen, &["..."]);
+        assert_eq!(
+            result,
+            Err(ProductionError::Locked(vec!["Horse Riding".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_units_need_their_strategic_resources() {
+        let item = unit("Cavalry");
+        let without_horses = civilization(&["Horse Riding"], &[GameResource::Iron]);
+        let with_horses = civilization(&["Horse Riding"], &[GameResource::Horses]);
+
+        let missing = vec![GameResource::Horses];
+        assert_eq!(
+            validate(&item, &without_horses, &["..."]),
+            Err(ProductionError::MissingResources(missing))
+        );
+        assert_eq!(validate(&item, &with_horses, &["..."]), Ok(()));
+    }
+
+    #[test]
+    fn test_ships_need_a_coastal_city() {
+        let item = unit("Galley");
+        let sailors = civilization(&["Sailing"], &[]);
+
+        let inland = validate(&item, &sailors, &["..."]);
+        assert_eq!(inland, Err(ProductionError::NotCoastal));
+        assert_eq!(validate(&item, &sailors, &[".~~"]), Ok(()));
+    }
+
+    #[test]
+    fn test_queue_production_pays_up_front() {
+        let world_map = world_map(&["..."]);
+        let (terrain_registry, tech_tree) = (terrain_registry(), tech_tree());
+        let (unit_registry, building_registry) = (unit_registry(), building_registry());
+        let city = City::new("Test".to_string(), CivId(0));
+        let mut production_queue = ProductionQueue::new(CivId(0));
+        let mut civilization = civilization(&["Bronze Working"], &[]);
+        let infantry = UnitType::new("Infantry");
+        let cost = unit_registry.definition(&infantry).gold_cost;
+
+        let mut queue = |civilization: &mut Civilization| {
+            queue_production(
+                unit("Infantry"),
+                civilization,
+                &city,
+                Position::new(0, 0),
+                &mut production_queue,
+                &world_map,
+                &terrain_registry,
+                &tech_tree,
+                &unit_registry,
+                &building_registry,
+            )
+        };
+
+        civilization.economy.gold = cost - 1.0;
+        assert_eq!(
+            queue(&mut civilization),
+            Err(ProductionError::InsufficientGold {
+                cost,
+                available: cost - 1.0
+            })
+        );
+
+        civilization.economy.gold = cost + 1.0;
+        assert_eq!(queue(&mut civilization), Ok(()));
+        assert_eq!(civilization.economy.gold, 1.0);
+        assert_eq!(production_queue.queue.len(), 1);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    components::turn_phases::TurnPhase,
+    unit_registry::{PromotionDefinition, PromotionEffect},
+    CivId, CivPersonality, Civilization, MilitaryUnit, PlayerControlled, UnitRegistry, UnitType,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// Event emitted when a unit is given a promotion
+#[derive(Message, Debug, Clone)]
+pub struct UnitPromoted {
+    pub civ_id: CivId,
+    pub unit_type: UnitType,
+    pub promotion: String,
+}
+
+/// Give `unit` the promotion named `promotion` if it has one to spend and may take it
+pub fn promote_unit(
+    unit: &mut MilitaryUnit,
+    unit_registry: &UnitRegistry,
+    promotion: &str,
+) -> Result<(), &'static str> {
+    if unit.pending_promotions() == 0 {
+        return Err("Unit has not earned a promotion");
+    }
+    let Some(effect) = unit_registry
+        .available_promotions(unit)
+        .find(|definition| definition.name == promotion)
+        .map(|definition| definition.effect.clone())
+    else {
+        return Err("Promotion is not available to this unit");
+    };
+
+    if let PromotionEffect::ExtraMovement(points) = effect {
+        unit.movement_range += points;
+        unit.movement_remaining += points;
+    }
+    unit.promotions.push(promotion.to_string());
+    Ok(())
+}
+
+/// System to spend the promotions AI units have earned, picking whatever suits their
+/// civilization's personality
+pub fn promote_ai_units(
+    turn_phase: Res<TurnPhase>,
+    unit_registry: Res<UnitRegistry>,
+    civilizations: Query<&Civilization, Without<PlayerControlled>>,
+    mut units: Query<&mut MilitaryUnit>,
+    mut promotion_events: MessageWriter<UnitPromoted>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let personalities: HashMap<CivId, &CivPersonality> = civilizations
+        .iter()
+        .map(|civ| (civ.id, &civ.personality))
+        .collect();
+
+    for mut unit in units.iter_mut() {
+        let Some(personality) = personalities.get(&unit.owner) else {
+            continue;
+        };
+
+        while unit.pending_promotions() > 0 {
+            let Some(promotion) = unit_registry
+                .available_promotions(&unit)
+                .enumerate()
+                .max_by(|(index_a, a), (index_b, b)| {
+                    promotion_preference(a, personality)
+                        .total_cmp(&promotion_preference(b, personality))
+                        .then(index_b.cmp(index_a))
+                })
+                .map(|(_, promotion)| promotion.name.clone())
+            else {
+                break;
+            };
+
+            if promote_unit(&mut unit, &unit_registry, &promotion).is_err() {
+                break;
+            }
+            tracing::info!(
+                "Unit {} of civ {} was promoted with {}",
+                unit.id,
+                unit.owner.0,
+                promotion
+            );
+            promotion_events.write(UnitPromoted {
+                civ_id: unit.owner,
+                unit_type: unit.unit_type.clone(),
+                promotion,
+            });
+        }
+    }
+}
+
+/// System to let units with healing promotions recover health during the turn transition
+pub fn heal_promoted_units(
+    turn_phase: Res<TurnPhase>,
+    unit_registry: Res<UnitRegistry>,
+    mut units: Query<&mut MilitaryUnit>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for mut unit in units.iter_mut() {
+        let healing_rate = unit_registry.healing_rate(&unit);
+        if healing_rate > 0.0 {
+            unit.health = (unit.health + unit.max_health * healing_rate).min(unit.max_health);
+        }
+    }
+}
+
+/// How much a civilization with `personality` wants `promotion`
+fn promotion_preference(promotion: &PromotionDefinition, personality: &CivPersonality) -> f32 {
+    match promotion.effect {
+        PromotionEffect::ClassBonus { .. } => personality.militarism,
+        PromotionEffect::TerrainDefense { .. } => personality.isolationism,
+        PromotionEffect::ExtraMovement(_) => personality.exploration_drive,
+        PromotionEffect::Healing(_) => 1.0 - personality.risk_tolerance,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::veterancy;
+    use crate::Position;
+
+    fn unit_registry() -> UnitRegistry {
+        UnitRegistry {
+            promotions: vec![
+                PromotionDefinition {
+                    name: "Mobility".to_string(),
+                    description: String::new(),
+                    effect: PromotionEffect::ExtraMovement(1),
+                    unit_classes: Vec::new(),
+                },
+                PromotionDefinition {
+                    name: "Navigation".to_string(),
+                    description: String::new(),
+                    effect: PromotionEffect::Healing(0.1),
+                    unit_classes: vec!["Naval".to_string()],
+                },
+                PromotionDefinition {
+                    name: "Drill".to_string(),
+                    description: String::new(),
+                    effect: PromotionEffect::Healing(0.1),
+                    unit_classes: Vec::new(),
+                },
+            ],
+            ..UnitRegistry::default()
+        }
+    }
+
+    fn unit_with_experience(unit_registry: &UnitRegistry, experience: f32) -> MilitaryUnit {
+        let mut unit = MilitaryUnit::new(
+            0,
+            CivId(0),
+            UnitType::new("Infantry"),
+            Position::new(0, 0),
+            unit_registry,
+        );
+        unit.experience = experience;
+        unit
+    }
+
+    #[test]
+    fn test_recruits_cannot_be_promoted() {
+        let unit_registry = unit_registry();
+        let mut unit = unit_with_experience(&unit_registry, 0.0);
+
+        assert!(promote_unit(&mut unit, &unit_registry, "Mobility").is_err());
+        assert!(unit.promotions.is_empty());
+    }
+
+    #[test]
+    fn test_promotion_applies_extra_movement_once_per_level() {
+        let unit_registry = unit_registry();
+        let mut unit = unit_with_experience(&unit_registry, veterancy::REGULAR_EXPERIENCE);
+        let movement_range = unit.movement_range;
+
+        assert!(promote_unit(&mut unit, &unit_registry, "Mobility").is_ok());
+        assert_eq!(unit.movement_range, movement_range + 1);
+        assert_eq!(unit.movement_remaining, movement_range + 1);
+        assert_eq!(unit.promotions, vec!["Mobility".to_string()]);
+
+        assert!(promote_unit(&mut unit, &unit_registry, "Drill").is_err());
+    }
+
+    #[test]
+    fn test_promotion_must_be_available_to_the_unit() {
+        let unit_registry = unit_registry();
+        let mut unit = unit_with_experience(&unit_registry, veterancy::ELITE_EXPERIENCE);
+        promote_unit(&mut unit, &unit_registry, "Mobility").unwrap();
+
+        assert!(promote_unit(&mut unit, &unit_registry, "Mobility").is_err());
+        assert!(promote_unit(&mut unit, &unit_registry, "Navigation").is_err());
+        assert!(promote_unit(&mut unit, &unit_registry, "Unknown").is_err());
+        assert!(promote_unit(&mut unit, &unit_registry, "Drill").is_ok());
+    }
+}
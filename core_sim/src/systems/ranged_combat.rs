@@ -0,0 +1,352 @@
+use crate::{
+    combat::CombatSystem,
+    components::{orders::UnitOrder, rendering::SpriteEntityReference},
+    constants::combat::MINIMUM_CITY_DEFENSE,
+    resources::{DiplomaticState, GameRng},
+    systems::combat_resolution::{
+        apply_combat_outcome, break_defender_orders, collect_combatants, defender_bonus,
+        promotion_bonus,
+    },
+    City, CivId, Embarked, MilitaryUnit, Position, TerrainRegistry, UnitRegistry, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashSet;
+
+/// Event for a unit striking a tile within its range without moving
+#[derive(Message, Debug, Clone)]
+pub struct RangedAttackRequested {
+    pub attacker: Entity,
+    pub target_position: Position,
+}
+
+/// What a ranged attack hit
+#[derive(Debug, Clone)]
+pub enum RangedAttackTarget {
+    Units {
+        /// Share of their health the defenders lost
+        casualty_rate: f32,
+        defenders_destroyed: usize,
+    },
+    City {
+        city_name: String,
+        defense_lost: f32,
+    },
+}
+
+/// Event emitted once a ranged attack or bombardment has been made
+#[derive(Message, Debug, Clone)]
+pub struct RangedAttackResolved {
+    pub attacker_civ: CivId,
+    pub defender_civ: CivId,
+    pub position: Position,
+    pub target: RangedAttackTarget,
+}
+
+/// Tiles strictly between `from` and `to` on the line joining them
+fn tiles_between(from: Position, to: Position) -> Vec<Position> {
+    let dx = (to.x - from.x).abs();
+    let dy = -(to.y - from.y).abs();
+    let step_x = (to.x - from.x).signum();
+    let step_y = (to.y - from.y).signum();
+    let mut error = dx + dy;
+    let (mut x, mut y) = (from.x, from.y);
+    let mut tiles = Vec::new();
+
+    while (x, y) != (to.x, to.y) {
+        let doubled_error = 2 * error;
+        if doubled_error >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled_error <= dx {
+            error += dx;
+            y += step_y;
+        }
+        if (x, y) != (to.x, to.y) {
+            tiles.push(Position::new(x, y));
+        }
+    }
+    tiles
+}
+
+/// Whether no tile between `from` and `to` holds terrain that blocks line of sight
+pub fn has_line_of_sight(
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    from: Position,
+    to: Position,
+) -> bool {
+    tiles_between(from, to).into_iter().all(|position| {
+        !world_map
+            .get_tile(position)
+            .is_some_and(|tile| terrain_registry.blocks_line_of_sight(&tile.terrain))
+    })
+}
+
+/// Check that `attacker` standing on `attacker_position` can make a ranged attack on
+/// `target_position` this turn
+pub fn validate_ranged_attack(
+    attacker: &MilitaryUnit,
+    attacker_position: Position,
+    target_position: Position,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+) -> Result<(), &'static str> {
+    if !attacker.has_ranged_attack() {
+        return Err("Unit cannot attack at range");
+    }
+    if !attacker.can_move() {
+        return Err("Unit has no movement left to attack");
+    }
+    if !CombatSystem::can_attack(attacker, attacker_position, target_position) {
+        return Err("Target is out of range");
+    }
+    if !has_line_of_sight(
+        world_map,
+        terrain_registry,
+        attacker_position,
+        target_position,
+    ) {
+        return Err("No line of sight to the target");
+    }
+    Ok(())
+}
+
+/// System that makes every requested ranged attack
+///
+/// Units able to bombard cities (see `UnitRegistry::bombardment_multiplier`) knock defense
+/// out of a hostile city on the target tile, which counts against the city at the next
+/// siege and is rebuilt when the city's stats are recalculated. Otherwise the hostile units
+/// on the tile take damage without striking back, and the attacker stays where it is.
+pub fn resolve_ranged_attacks(
+    mut commands: Commands,
+    mut attack_requests: MessageReader<RangedAttackRequested>,
+    mut units: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &mut Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    mut cities: Query<(&mut City, &Position), Without<MilitaryUnit>>,
+    embarked_units: Query<(), With<Embarked>>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    unit_registry: Res<UnitRegistry>,
+    unit_orders: Query<&UnitOrder>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut rng: ResMut<GameRng>,
+    mut attack_results: MessageWriter<RangedAttackResolved>,
+) {
+    // Despawns are deferred, so remember this frame's casualties to keep them out of later attacks
+    let mut destroyed_units = HashSet::new();
+
+    for request in attack_requests.read() {
+        if destroyed_units.contains(&request.attacker) {
+            continue;
+        }
+        let Ok((_, attacker, attacker_position, _)) = units.get(request.attacker) else {
+            continue;
+        };
+
+        if let Err(reason) = validate_ranged_attack(
+            attacker,
+            *attacker_position,
+            request.target_position,
+            &world_map,
+            &terrain_registry,
+        ) {
+            tracing::debug!(
+                "Unit {} cannot attack ({}, {}): {}",
+                attacker.id,
+                request.target_position.x,
+                request.target_position.y,
+                reason
+            );
+            continue;
+        }
+
+        if embarked_units.contains(request.attacker) {
+            tracing::debug!("Unit {} cannot attack while embarked", attacker.id);
+            continue;
+        }
+
+        if !attacker.is_reliable() {
+            tracing::info!(
+                "Unit {} of civ {} refuses to attack",
+                attacker.id,
+                attacker.owner.0
+            );
+            continue;
+        }
+
+        let attacker_civ = attacker.owner;
+        let bombardment_multiplier = unit_registry.bombardment_multiplier(&attacker.unit_type);
+        let hostile_city = cities.iter_mut().find(|(city, position)| {
+            **position == request.target_position
+                && city.owner != attacker_civ
+                && diplomatic_state.is_at_war(attacker_civ, city.owner)
+        });
+
+        let (defender_civ, target) = match (hostile_city, bombardment_multiplier) {
+            (Some((mut city, _)), Some(multiplier)) => {
+                let damage = CombatSystem::resolve_bombardment(attacker, multiplier, &mut rng.0);
+                let previous_defense = city.defense;
+                city.defense = (city.defense - damage).max(MINIMUM_CITY_DEFENSE);
+
+                (
+                    city.owner,
+                    RangedAttackTarget::City {
+                        city_name: city.name.clone(),
+                        defense_lost: previous_defense - city.defense,
+                    },
+                )
+            }
+            _ => {
+                let defender_entities: Vec<Entity> = units
+                    .iter()
+                    .filter(|(entity, unit, position, _)| {
+                        **position == request.target_position
+                            && unit.owner != attacker_civ
+                            && diplomatic_state.is_at_war(attacker_civ, unit.owner)
+                            && !destroyed_units.contains(entity)
+                    })
+                    .map(|(entity, ..)| entity)
+                    .collect();
+
+                let Some(defender_civ) = defender_entities
+                    .first()
+                    .and_then(|entity| units.get(*entity).ok())
+                    .map(|(_, defender, _, _)| defender.owner)
+                else {
+                    tracing::debug!(
+                        "Unit {} has nothing to attack at ({}, {})",
+                        attacker.id,
+                        request.target_position.x,
+                        request.target_position.y
+                    );
+                    continue;
+                };
+                let defender_entities: Vec<Entity> = defender_entities
+                    .into_iter()
+                    .filter(|entity| {
+                        units
+                            .get(*entity)
+                            .is_ok_and(|(_, unit, _, _)| unit.owner == defender_civ)
+                    })
+                    .collect();
+
+                let attacker_entities = vec![request.attacker];
+                let mut attacking_units = collect_combatants(&units, &attacker_entities);
+                let mut defending_units = collect_combatants(&units, &defender_entities);
+
+                let attack_bonus =
+                    promotion_bonus(&unit_registry, &attacking_units, &defending_units, None);
+                let defense_bonus = defender_bonus(
+                    &world_map,
+                    &terrain_registry,
+                    &unit_registry,
+                    &unit_orders,
+                    request.target_position,
+                    &defender_entities,
+                    &defending_units,
+                    &attacking_units,
+                );
+
+                let Some(ranged_unit) = attacking_units.first_mut() else {
+                    continue;
+                };
+                let result = CombatSystem::resolve_ranged_attack(
+                    ranged_unit,
+                    &mut defending_units,
+                    attack_bonus,
+                    defense_bonus,
+                    &mut rng.0,
+                );
+
+                apply_combat_outcome(
+                    &mut commands,
+                    &mut units,
+                    &attacker_entities,
+                    attacking_units,
+                    &mut destroyed_units,
+                );
+                let defenders_destroyed = apply_combat_outcome(
+                    &mut commands,
+                    &mut units,
+                    &defender_entities,
+                    defending_units,
+                    &mut destroyed_units,
+                );
+                break_defender_orders(
+                    &mut commands,
+                    &unit_orders,
+                    &defender_entities,
+                    &destroyed_units,
+                );
+
+                (
+                    defender_civ,
+                    RangedAttackTarget::Units {
+                        casualty_rate: result.casualty_rate,
+                        defenders_destroyed,
+                    },
+                )
+            }
+        };
+
+        commands.entity(request.attacker).remove::<UnitOrder>();
+        if let Ok((_, mut attacker, _, _)) = units.get_mut(request.attacker) {
+            attacker.movement_remaining = 0;
+        }
+
+        tracing::info!(
+            "Ranged attack on ({}, {}): civ {} struck civ {} - {:?}",
+            request.target_position.x,
+            request.target_position.y,
+            attacker_civ.0,
+            defender_civ.0,
+            target
+        );
+
+        attack_results.write(RangedAttackResolved {
+            attacker_civ,
+            defender_civ,
+            position: request.target_position,
+            target,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{terrain_registry, world_map};
+
+    #[test]
+    fn test_tiles_between_follow_the_line() {
+        let origin = Position::new(0, 0);
+
+        assert_eq!(
+            tiles_between(origin, Position::new(3, 0)),
+            vec![Position::new(1, 0), Position::new(2, 0)]
+        );
+        assert_eq!(
+            tiles_between(Position::new(2, 2), origin),
+            vec![Position::new(1, 1)]
+        );
+        assert!(tiles_between(origin, Position::new(0, 1)).is_empty());
+        assert!(tiles_between(origin, origin).is_empty());
+    }
+
+    #[test]
+    fn test_line_of_sight_blocked_only_between_the_ends() {
+        let terrain_registry = terrain_registry();
+        let from = Position::new(0, 0);
+        let to = Position::new(2, 0);
+
+        let sight_across = |rows| has_line_of_sight(&world_map(rows), &terrain_registry, from, to);
+
+        assert!(!sight_across(&[".F."]));
+        assert!(sight_across(&["F^F"]));
+    }
+}
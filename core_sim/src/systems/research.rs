@@ -0,0 +1,170 @@
+use crate::{
+    components::turn_phases::TurnPhase, constants::research, tech_tree::TechTree, BuildingRegistry,
+    City, CivId, Civilization,
+};
+use bevy_ecs::prelude::*;
+
+/// Event emitted when a civilization finishes researching a technology
+#[derive(Message, Debug, Clone)]
+pub struct TechnologyResearched {
+    pub civ_id: CivId,
+    pub technology: String,
+}
+
+/// System to accumulate research points and complete technologies once per turn,
+/// during the turn transition
+///
+/// Points are only banked while a civilization is researching something.
+pub fn advance_research(
+    turn_phase: Res<TurnPhase>,
+    tech_tree: Res<TechTree>,
+    building_registry: Res<BuildingRegistry>,
+    mut civilizations: Query<&mut Civilization>,
+    cities: Query<&City>,
+    mut researched_events: MessageWriter<TechnologyResearched>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for mut civilization in civilizations.iter_mut() {
+        let civ_id = civilization.id;
+        let research_per_turn = research::BASE_RESEARCH_PER_TURN
+            + cities
+                .iter()
+                .filter(|city| city.owner == civ_id)
+                .map(|city| city_research(city, &building_registry))
+                .sum::<f32>();
+
+        let technologies = &mut civilization.technologies;
+        let Some(current_research) = technologies.current_research.clone() else {
+            continue;
+        };
+
+        if !tech_tree.can_research(technologies, &current_research) {
+            tracing::debug!(
+                "Civ {} can no longer research {}, clearing it",
+                civ_id.0,
+                current_research
+            );
+            technologies.current_research = None;
+            continue;
+        }
+
+        technologies.research_points += research_per_turn;
+        let Some(cost) = tech_tree.research_cost(&current_research) else {
+            continue;
+        };
+        if technologies.research_points < cost {
+            continue;
+        }
+
+        technologies.research_points -= cost;
+        technologies.learn(&current_research);
+        technologies.current_research = None;
+
+        tracing::info!("Civ {} discovered {}", civ_id.0, current_research);
+        researched_events.write(TechnologyResearched {
+            civ_id,
+            technology: current_research,
+        });
+    }
+}
+
+fn city_research(city: &City, building_registry: &BuildingRegistry) -> f32 {
+    let building_research: f32 = city
+        .buildings
+        .iter()
+        .map(|building| {
+            building_registry
+                .definition(&building.building_type)
+                .research_yield_at(building.level)
+        })
+        .sum();
+
+    city.population as f32 / 1000.0 * research::RESEARCH_PER_THOUSAND_POPULATION + building_research
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{building_registry, tech_tree};
+    use crate::{CivPersonality, Economy, Military, Technologies};
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+
+    /// Runs `turns` turns of research for a civilization without cities, returning its
+    /// technologies and the number of technologies it discovered
+    fn research(technologies: Technologies, turns: u32) -> (Technologies, usize) {
+        let mut world = World::new();
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.insert_resource(tech_tree());
+        world.insert_resource(building_registry());
+        world.init_resource::<Messages<TechnologyResearched>>();
+        let civ_entity = world
+            .spawn(Civilization {
+                id: CivId(0),
+                name: "Civ 0".to_string(),
+                color: [0.0; 3],
+                capital: None,
+                personality: CivPersonality::default(),
+                technologies,
+                economy: Economy::default(),
+                military: Military::default(),
+                music_theme: String::new(),
+                sound_theme: String::new(),
+            })
+            .id();
+
+        for _ in 0..turns {
+            world.run_system_once(advance_research).unwrap();
+        }
+        let civilization = world.get::<Civilization>(civ_entity).unwrap();
+        let discoveries = world.resource::<Messages<TechnologyResearched>>().len();
+        (civilization.technologies.clone(), discoveries)
+    }
+
+    fn researching(name: &str, research_points: f32) -> Technologies {
+        Technologies {
+            current_research: Some(name.to_string()),
+            research_points,
+            ..Technologies::default()
+        }
+    }
+
+    #[test]
+    fn test_idle_civilizations_bank_no_research() {
+        let (technologies, discoveries) = research(Technologies::default(), 20);
+
+        assert_eq!(technologies.research_points, 0.0);
+        assert_eq!(discoveries, 0);
+    }
+
+    #[test]
+    fn test_research_accumulates_until_the_technology_is_discovered() {
+        let (technologies, _) = research(researching("Agriculture", 0.0), 1);
+        assert_eq!(
+            technologies.research_points,
+            research::BASE_RESEARCH_PER_TURN
+        );
+        assert!(!technologies.knows("Agriculture"));
+
+        let (technologies, discoveries) = research(researching("Agriculture", 19.0), 1);
+        assert!(technologies.knows("Agriculture"));
+        assert_eq!(technologies.current_research, None);
+        assert_eq!(
+            technologies.research_points,
+            19.0 + research::BASE_RESEARCH_PER_TURN - 20.0
+        );
+        assert_eq!(discoveries, 1);
+    }
+
+    #[test]
+    fn test_unavailable_research_is_dropped_without_banking() {
+        let (technologies, discoveries) = research(researching("Animal Husbandry", 0.0), 1);
+
+        assert_eq!(technologies.current_research, None);
+        assert_eq!(technologies.research_points, 0.0);
+        assert_eq!(discoveries, 0);
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    components::{position::MovementOrder, rendering::SpriteEntityReference},
+    constants::settlement,
+    systems::combat_resolution::despawn_unit,
+    BuildingRegistry, City, CivId, Civilization, MilitaryUnit, PlayerControlled, Position,
+    ProductionQueue, ProvidesVision, SettleOrder, UnitRegistry, WorldMap,
+};
+use bevy_ecs::prelude::*;
+
+/// Event emitted when a settler founds a new city
+#[derive(Message, Debug, Clone)]
+pub struct CityFounded {
+    pub city_entity: Entity,
+    pub civ_id: CivId,
+    pub city_name: String,
+    pub position: Position,
+}
+
+/// Whether `unit` can found cities, i.e. its type has the `FoundCity` ability
+pub fn is_settler(unit: &MilitaryUnit, unit_registry: &UnitRegistry) -> bool {
+    unit_registry
+        .definition(&unit.unit_type)
+        .has_ability(settlement::FOUND_CITY_ABILITY)
+}
+
+/// Check whether `civ_id` may found a city at `position`
+pub fn validate_city_site(
+    world_map: &WorldMap,
+    civ_id: CivId,
+    position: Position,
+    city_positions: &[Position],
+) -> Result<(), &'static str> {
+    let Some(tile) = world_map.get_tile(position) else {
+        return Err("City site is outside map boundaries");
+    };
+
+    if !tile.terrain.is_buildable() {
+        return Err("Cities cannot be founded on this terrain");
+    }
+
+    if tile.owner.is_some_and(|owner| owner != civ_id) {
+        return Err("City site is inside another civilization's territory");
+    }
+
+    if city_positions
+        .iter()
+        .any(|city| city.manhattan_distance_to(&position) < settlement::MIN_CITY_DISTANCE)
+    {
+        return Err("City site is too close to an existing city");
+    }
+
+    Ok(())
+}
+
+/// The valid city site closest to `target`, searching up to `SITE_SEARCH_RADIUS` tiles away
+pub fn find_city_site_near(
+    world_map: &WorldMap,
+    civ_id: CivId,
+    target: Position,
+    city_positions: &[Position],
+) -> Option<Position> {
+    (0..=settlement::SITE_SEARCH_RADIUS).find_map(|distance| {
+        (-distance..=distance)
+            .flat_map(|dx| {
+                let dy = distance - dx.abs();
+                [
+                    Position::new(target.x + dx, target.y + dy),
+                    Position::new(target.x + dx, target.y - dy),
+                ]
+            })
+            .find(|&position| {
+                validate_city_site(world_map, civ_id, position, city_positions).is_ok()
+            })
+    })
+}
+
+/// Spawn a new city for `civ_id` at `position` and claim the tiles around it
+pub fn found_city(
+    commands: &mut Commands,
+    world_map: &mut WorldMap,
+    building_registry: &BuildingRegistry,
+    civ_id: CivId,
+    city_name: String,
+    position: Position,
+    is_player: bool,
+) -> Entity {
+    let mut city = City {
+        population: settlement::FOUNDED_CITY_POPULATION,
+        buildings: Vec::new(),
+        ..City::new(city_name.clone(), civ_id)
+    };
+    city.update_stats(building_registry);
+
+    let mut city_commands = commands.spawn((city, position, civ_id));
+    city_commands.insert(ProductionQueue::new(civ_id));
+    city_commands.insert(ProvidesVision::city_vision());
+
+    if is_player {
+        city_commands.insert(PlayerControlled);
+    }
+
+    let city_entity = city_commands.id();
+    claim_city_territory(world_map, civ_id, position, &city_name);
+
+    tracing::info!(
+        "Civ {} founded {} at ({}, {})",
+        civ_id.0,
+        city_name,
+        position.x,
+        position.y
+    );
+
+    city_entity
+}
+
+fn claim_city_territory(
+    world_map: &mut WorldMap,
+    civ_id: CivId,
+    center: Position,
+    city_name: &str,
+) {
+    let radius = settlement::CITY_CLAIM_RADIUS;
+    for dx in -radius..=radius {
+        for dy in -radius..=radius {
+            let position = Position::new(center.x + dx, center.y + dy);
+            if position.manhattan_distance_to(&center) > radius {
+                continue;
+            }
+
+            if let Some(tile) = world_map.get_tile_mut(position) {
+                if tile.owner.is_none() {
+                    tile.owner = Some(civ_id);
+                }
+            }
+        }
+    }
+
+    if let Some(tile) = world_map.get_tile_mut(center) {
+        tile.owner = Some(civ_id);
+        tile.city = Some(city_name.to_string());
+    }
+}
+
+fn new_city_name(civilization_name: &str, city_number: usize) -> String {
+    format!("{} City {}", civilization_name, city_number)
+}
+
+/// System to found a city once a settler reaches its `SettleOrder` target. Settlers travel on
+/// the `MovementOrder` planned when the settle order is given; orders whose site has become
+/// invalid, or whose settler was stopped short of it, are dropped.
+pub fn advance_settlers(
+    mut commands: Commands,
+    mut world_map: ResMut<WorldMap>,
+    building_registry: Res<BuildingRegistry>,
+    settlers: Query<(
+        Entity,
+        &MilitaryUnit,
+        &Position,
+        &SettleOrder,
+        Option<&SpriteEntityReference>,
+        Has<MovementOrder>,
+    )>,
+    cities: Query<(&City, &Position)>,
+    civilizations: Query<(&Civilization, Has<PlayerControlled>)>,
+    mut founded_events: MessageWriter<CityFounded>,
+) {
+    let mut city_sites: Vec<(CivId, Position)> = cities
+        .iter()
+        .map(|(city, position)| (city.owner, *position))
+        .collect();
+
+    for (entity, unit, position, settle_order, sprite_reference, has_movement_order) in
+        settlers.iter()
+    {
+        if *position == settle_order.target {
+            let city_positions: Vec<Position> = city_sites.iter().map(|(_, site)| *site).collect();
+            if let Err(reason) =
+                validate_city_site(&world_map, unit.owner, *position, &city_positions)
+            {
+                tracing::debug!(
+                    "Settler {} cannot found a city at ({}, {}): {}",
+                    unit.id,
+                    position.x,
+                    position.y,
+                    reason
+                );
+                commands.entity(entity).remove::<SettleOrder>();
+                continue;
+            }
+
+            let Some((civilization, is_player)) =
+                civilizations.iter().find(|(civ, _)| civ.id == unit.owner)
+            else {
+                continue;
+            };
+
+            let city_count = city_sites
+                .iter()
+                .filter(|(owner, _)| *owner == unit.owner)
+                .count();
+            let city_name = new_city_name(&civilization.name, city_count + 1);
+            let city_entity = found_city(
+                &mut commands,
+                &mut world_map,
+                &building_registry,
+                unit.owner,
+                city_name.clone(),
+                *position,
+                is_player,
+            );
+
+            despawn_unit(&mut commands, entity, sprite_reference);
+            city_sites.push((unit.owner, *position));
+
+            founded_events.write(CityFounded {
+                city_entity,
+                civ_id: unit.owner,
+                city_name,
+                position: *position,
+            });
+            continue;
+        }
+
+        if !has_movement_order {
+            tracing::debug!(
+                "Settler {} was stopped short of ({}, {}), dropping its settle order",
+                unit.id,
+                settle_order.target.x,
+                settle_order.target.y
+            );
+            commands.entity(entity).remove::<SettleOrder>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::world_map;
+
+    #[test]
+    fn test_validate_city_site() {
+        let mut world_map = world_map(&["......", "......", "....~~"]);
+        world_map.get_tile_mut(Position::new(5, 0)).unwrap().owner = Some(CivId(1));
+        world_map.get_tile_mut(Position::new(4, 0)).unwrap().owner = Some(CivId(0));
+        let cities = [Position::new(0, 0)];
+        let is_valid =
+            |x, y| validate_city_site(&world_map, CivId(0), Position::new(x, y), &cities).is_ok();
+
+        assert!(is_valid(4, 0), "own territory far enough from other cities");
+        assert!(is_valid(3, 1));
+        assert!(!is_valid(2, 1), "too close to an existing city");
+        assert!(!is_valid(5, 0), "foreign territory");
+        assert!(!is_valid(5, 2), "ocean");
+        assert!(!is_valid(6, 0), "outside the map");
+    }
+
+    #[test]
+    fn test_find_city_site_near_skips_invalid_tiles() {
+        let world_map = world_map(&["~~~~~", "~~~.~", "~~~~~"]);
+
+        assert_eq!(
+            find_city_site_near(&world_map, CivId(0), Position::new(1, 1), &[]),
+            Some(Position::new(3, 1))
+        );
+        let nearby_city = [Position::new(2, 0)];
+        assert_eq!(
+            find_city_site_near(&world_map, CivId(0), Position::new(1, 1), &nearby_city),
+            None
+        );
+    }
+}
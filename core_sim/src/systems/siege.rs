@@ -0,0 +1,472 @@
+use crate::{
+    combat::{CombatSystem, SiegeResult},
+    components::{rendering::SpriteEntityReference, turn_phases::TurnPhase},
+    constants::combat::{MELEE_RANGE, MINIMUM_UNIT_HEALTH_THRESHOLD},
+    resources::{DiplomaticState, GameRng},
+    systems::combat_resolution::despawn_unit,
+    BuildingRegistry, Capital, City, CivId, Civilization, MilitaryUnit, PlayerControlled, Position,
+    ProductionQueue, Siege, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// Event emitted when a city changes hands through a siege
+#[derive(Message, Debug, Clone)]
+pub struct CityCaptured {
+    pub city_entity: Entity,
+    pub city_name: String,
+    pub position: Position,
+    pub previous_owner: CivId,
+    pub new_owner: CivId,
+    pub surrendered: bool,
+}
+
+/// System that advances every siege once per turn, during the turn transition
+///
+/// A captured city's `Capital` and `ProductionQueue` pass to the attacker along with it,
+/// leaving a civilization that loses its capital without one.
+pub fn resolve_sieges(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    mut cities: Query<(
+        Entity,
+        &mut City,
+        &Position,
+        Option<&mut Siege>,
+        Option<&mut Capital>,
+        Option<&mut ProductionQueue>,
+    )>,
+    mut units: Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    mut civilizations: Query<(&mut Civilization, Has<PlayerControlled>)>,
+    mut world_map: ResMut<WorldMap>,
+    diplomatic_state: Res<DiplomaticState>,
+    building_registry: Res<BuildingRegistry>,
+    mut rng: ResMut<GameRng>,
+    mut captured_events: MessageWriter<CityCaptured>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let player_civ_ids: Vec<CivId> = civilizations
+        .iter()
+        .filter(|(_, is_player)| *is_player)
+        .map(|(civ, _)| civ.id)
+        .collect();
+
+    for (city_entity, mut city, city_position, siege, capital, production_queue) in
+        cities.iter_mut()
+    {
+        let Some(besieger) = find_besieger(&units, &diplomatic_state, city.owner, *city_position)
+        else {
+            if siege.is_some() {
+                tracing::info!("Siege of {} has been lifted", city.name);
+                commands.entity(city_entity).remove::<Siege>();
+            }
+            continue;
+        };
+
+        let turns_besieged = match siege {
+            Some(mut siege) if siege.besieger == besieger => {
+                siege.turns_besieged += 1;
+                siege.turns_besieged
+            }
+            Some(mut siege) => {
+                siege.besieger = besieger;
+                siege.turns_besieged = 1;
+                1
+            }
+            None => {
+                tracing::info!("Civ {} has laid siege to {}", besieger.0, city.name);
+                commands.entity(city_entity).insert(Siege {
+                    besieger,
+                    turns_besieged: 1,
+                });
+                1
+            }
+        };
+
+        let besieging_entities = units_near(&units, besieger, *city_position, MELEE_RANGE);
+        let garrison_entities = units_near(&units, city.owner, *city_position, 0);
+
+        let besieging_units: Vec<MilitaryUnit> = besieging_entities
+            .iter()
+            .filter_map(|entity| units.get(*entity).ok().map(|(_, unit, _, _)| unit.clone()))
+            .collect();
+        let garrison_defense: f32 = garrison_entities
+            .iter()
+            .filter_map(|entity| units.get(*entity).ok())
+            .map(|(_, unit, _, _)| unit.effective_defense())
+            .sum();
+
+        let result = CombatSystem::resolve_siege(
+            &besieging_units,
+            city.defense + garrison_defense,
+            city.fortification_level(&building_registry),
+            turns_besieged,
+            &mut rng.0,
+        );
+
+        let surrendered = matches!(result, SiegeResult::Surrendered { .. });
+
+        match result {
+            SiegeResult::Captured {
+                attacker_casualties,
+                ..
+            } => {
+                apply_siege_casualties(
+                    &mut commands,
+                    &mut units,
+                    &besieging_entities,
+                    attacker_casualties,
+                );
+                for entity in &garrison_entities {
+                    if let Ok((_, _, _, sprite_reference)) = units.get(*entity) {
+                        despawn_unit(&mut commands, *entity, sprite_reference);
+                    }
+                }
+            }
+            SiegeResult::Surrendered { .. } => {
+                for entity in &garrison_entities {
+                    if let Ok((_, _, _, sprite_reference)) = units.get(*entity) {
+                        despawn_unit(&mut commands, *entity, sprite_reference);
+                    }
+                }
+            }
+            SiegeResult::Ongoing {
+                attacker_casualties,
+                defender_casualties,
+            } => {
+                apply_siege_casualties(
+                    &mut commands,
+                    &mut units,
+                    &besieging_entities,
+                    attacker_casualties,
+                );
+                apply_siege_casualties(
+                    &mut commands,
+                    &mut units,
+                    &garrison_entities,
+                    defender_casualties,
+                );
+                tracing::info!(
+                    "{} holds out against civ {} (turn {} of siege)",
+                    city.name,
+                    besieger.0,
+                    turns_besieged
+                );
+                continue;
+            }
+        }
+
+        let previous_owner = city.owner;
+
+        tracing::info!(
+            "{} has fallen to civ {} after {} turns of siege",
+            city.name,
+            besieger.0,
+            turns_besieged
+        );
+
+        city.owner = besieger;
+
+        if let Some(mut capital) = capital {
+            capital.owner = besieger;
+            for (mut civilization, _) in civilizations.iter_mut() {
+                if civilization.id == previous_owner && civilization.capital == Some(*city_position)
+                {
+                    civilization.capital = None;
+                }
+            }
+        }
+
+        if let Some(mut production_queue) = production_queue {
+            production_queue.owner = besieger;
+        }
+
+        if let Some(tile) = world_map.get_tile_mut(*city_position) {
+            tile.owner = Some(besieger);
+        }
+
+        let mut city_commands = commands.entity(city_entity);
+        city_commands.insert(besieger).remove::<Siege>();
+        if player_civ_ids.contains(&besieger) {
+            city_commands.insert(PlayerControlled);
+        } else {
+            city_commands.remove::<PlayerControlled>();
+        }
+
+        captured_events.write(CityCaptured {
+            city_entity,
+            city_name: city.name.clone(),
+            position: *city_position,
+            previous_owner,
+            new_owner: besieger,
+            surrendered,
+        });
+    }
+}
+
+/// The civ at war with the city's owner that has the most units adjacent to (or inside) it
+fn find_besieger(
+    units: &Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    diplomatic_state: &DiplomaticState,
+    city_owner: CivId,
+    city_position: Position,
+) -> Option<CivId> {
+    let mut unit_counts: HashMap<CivId, usize> = HashMap::new();
+    for (_, unit, position, _) in units.iter() {
+        if unit.owner != city_owner
+            && diplomatic_state.is_at_war(unit.owner, city_owner)
+            && position.manhattan_distance_to(&city_position) as u32 <= MELEE_RANGE
+        {
+            *unit_counts.entry(unit.owner).or_default() += 1;
+        }
+    }
+
+    unit_counts
+        .into_iter()
+        .max_by_key(|(civ_id, count)| (*count, std::cmp::Reverse(civ_id.0)))
+        .map(|(civ_id, _)| civ_id)
+}
+
+fn units_near(
+    units: &Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    owner: CivId,
+    center: Position,
+    max_distance: u32,
+) -> Vec<Entity> {
+    units
+        .iter()
+        .filter(|(_, unit, position, _)| {
+            unit.owner == owner && position.manhattan_distance_to(&center) as u32 <= max_distance
+        })
+        .map(|(entity, ..)| entity)
+        .collect()
+}
+
+/// Remove `casualty_rate` of each unit's maximum health, despawning units that drop too low
+fn apply_siege_casualties(
+    commands: &mut Commands,
+    units: &mut Query<(
+        Entity,
+        &mut MilitaryUnit,
+        &Position,
+        Option<&SpriteEntityReference>,
+    )>,
+    entities: &[Entity],
+    casualty_rate: f32,
+) {
+    for entity in entities {
+        let Ok((_, mut unit, _, sprite_reference)) = units.get_mut(*entity) else {
+            continue;
+        };
+
+        unit.health = (unit.health - unit.max_health * casualty_rate).max(0.0);
+        if unit.health <= MINIMUM_UNIT_HEALTH_THRESHOLD {
+            tracing::info!("Unit {} of civ {} died in a siege", unit.id, unit.owner.0);
+            despawn_unit(commands, *entity, sprite_reference);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{building_registry, world_map};
+    use crate::{
+        CapitalAge, CivPersonality, Economy, Military, ProductionItem, Technologies, Treaty,
+        UnitRegistry, UnitType,
+    };
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+    use rand::SeedableRng;
+
+    const CITY_POSITION: Position = Position { x: 1, y: 0 };
+
+    fn unit(owner: CivId, strength: f32) -> MilitaryUnit {
+        let mut unit = MilitaryUnit::new(
+            0,
+            owner,
+            UnitType::new("Infantry"),
+            Position::new(0, 0),
+            &UnitRegistry::default(),
+        );
+        unit.attack = strength;
+        unit.defense = strength;
+        unit
+    }
+
+    fn civilization(id: CivId, capital: Option<Position>) -> Civilization {
+        Civilization {
+            id,
+            name: format!("Civ {}", id.0),
+            color: [0.0; 3],
+            capital,
+            personality: CivPersonality::default(),
+            technologies: Technologies::default(),
+            economy: Economy::default(),
+            military: Military::default(),
+            music_theme: String::new(),
+            sound_theme: String::new(),
+        }
+    }
+
+    /// A world where civilization 0 holds its capital on `CITY_POSITION`, at war with
+    /// civilizations 1 and 2 and at peace with 3
+    fn besieged_world() -> World {
+        let mut diplomatic_state = DiplomaticState::default();
+        let civ_ids = [CivId(0), CivId(1), CivId(2), CivId(3)];
+        diplomatic_state.seed_relations(&civ_ids);
+        for enemy in [CivId(1), CivId(2)] {
+            diplomatic_state
+                .get_relation_mut(CivId(0), enemy)
+                .unwrap()
+                .treaties
+                .push(Treaty::War { started_turn: 0 });
+        }
+
+        let mut world = World::new();
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.insert_resource(world_map(&["..."]));
+        world.insert_resource(diplomatic_state);
+        world.insert_resource(building_registry());
+        world.insert_resource(GameRng(rand_pcg::Pcg64::seed_from_u64(0)));
+        world.init_resource::<Messages<CityCaptured>>();
+        world.spawn(civilization(CivId(0), Some(CITY_POSITION)));
+        world.spawn((civilization(CivId(1), None), PlayerControlled));
+        world
+    }
+
+    fn spawn_city(world: &mut World) -> Entity {
+        let mut production_queue = ProductionQueue::new(CivId(0));
+        production_queue.add_to_queue(ProductionItem::Unit(UnitType::new("Archer")));
+        world
+            .spawn((
+                City::new("Capital".to_string(), CivId(0)),
+                CITY_POSITION,
+                CivId(0),
+                Capital {
+                    owner: CivId(0),
+                    age: CapitalAge::Neolithic,
+                    sprite_index: CapitalAge::Neolithic.sprite_index(),
+                    established_turn: 0,
+                },
+                production_queue,
+            ))
+            .id()
+    }
+
+    fn besieger(world: &mut World) -> Option<CivId> {
+        world
+            .run_system_once(
+                |units: Query<(
+                    Entity,
+                    &mut MilitaryUnit,
+                    &Position,
+                    Option<&SpriteEntityReference>,
+                )>,
+                 diplomatic_state: Res<DiplomaticState>| {
+                    find_besieger(&units, &diplomatic_state, CivId(0), CITY_POSITION)
+                },
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_the_enemy_with_the_most_adjacent_units_besieges() {
+        let mut world = besieged_world();
+        assert_eq!(besieger(&mut world), None);
+
+        world.spawn((unit(CivId(1), 1.0), Position::new(0, 0)));
+        world.spawn((unit(CivId(1), 1.0), Position::new(2, 0)));
+        for _ in 0..3 {
+            world.spawn((unit(CivId(2), 1.0), Position::new(1, 2)));
+            world.spawn((unit(CivId(3), 1.0), Position::new(1, 1)));
+        }
+        assert_eq!(besieger(&mut world), Some(CivId(1)));
+
+        world.spawn((unit(CivId(2), 1.0), Position::new(1, 1)));
+        world.spawn((unit(CivId(2), 1.0), CITY_POSITION));
+        world.spawn((unit(CivId(2), 1.0), Position::new(0, 0)));
+        assert_eq!(besieger(&mut world), Some(CivId(2)));
+    }
+
+    #[test]
+    fn test_sieges_start_and_lift_with_the_besiegers() {
+        let mut world = besieged_world();
+        let city = spawn_city(&mut world);
+        let besieging_unit = world.spawn((unit(CivId(1), 0.0), Position::new(0, 0))).id();
+
+        world.run_system_once(resolve_sieges).unwrap();
+        let siege = world.get::<Siege>(city).unwrap();
+        assert_eq!((siege.besieger, siege.turns_besieged), (CivId(1), 1));
+
+        world.run_system_once(resolve_sieges).unwrap();
+        assert_eq!(world.get::<Siege>(city).unwrap().turns_besieged, 2);
+
+        world.despawn(besieging_unit);
+        world.run_system_once(resolve_sieges).unwrap();
+        assert!(world.get::<Siege>(city).is_none());
+        assert_eq!(world.get::<City>(city).unwrap().owner, CivId(0));
+    }
+
+    #[test]
+    fn test_captured_capitals_pass_to_the_attacker() {
+        let mut world = besieged_world();
+        let city = spawn_city(&mut world);
+        world.spawn((unit(CivId(1), 1000.0), Position::new(0, 0)));
+
+        for _ in 0..10 {
+            world.run_system_once(resolve_sieges).unwrap();
+            if world.get::<City>(city).unwrap().owner == CivId(1) {
+                break;
+            }
+        }
+
+        assert_eq!(world.get::<City>(city).unwrap().owner, CivId(1));
+        assert_eq!(world.get::<CivId>(city), Some(&CivId(1)));
+        assert_eq!(world.get::<Capital>(city).unwrap().owner, CivId(1));
+        assert!(world.get::<PlayerControlled>(city).is_some());
+        assert!(world.get::<Siege>(city).is_none());
+
+        let production_queue = world.get::<ProductionQueue>(city).unwrap();
+        assert_eq!(production_queue.owner, CivId(1));
+        assert_eq!(production_queue.queue.len(), 1);
+
+        let tile = world
+            .resource::<WorldMap>()
+            .get_tile(CITY_POSITION)
+            .unwrap();
+        assert_eq!(tile.owner, Some(CivId(1)));
+
+        let mut civilizations = world.query::<&Civilization>();
+        let loser = civilizations
+            .iter(&world)
+            .find(|civ| civ.id == CivId(0))
+            .unwrap();
+        assert_eq!(loser.capital, None);
+
+        let captures = world.resource::<Messages<CityCaptured>>();
+        let capture = captures.iter_current_update_messages().last().unwrap();
+        assert_eq!(
+            (capture.previous_owner, capture.new_owner),
+            (CivId(0), CivId(1))
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{resources::CivTerritories, Civilization, GameResource, WorldMap};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// System to grant every civilization access to the resources on the tiles it owns
+///
+/// Runs whenever territory is rebuilt, so resources are gained and lost along with the
+/// tiles that hold them.
+pub fn update_resource_access(
+    world_map: Res<WorldMap>,
+    civ_territories: Res<CivTerritories>,
+    mut civilizations: Query<&mut Civilization>,
+) {
+    if !civ_territories.is_changed() {
+        return;
+    }
+
+    for mut civilization in civilizations.iter_mut() {
+        let mut resources: HashMap<GameResource, u32> = HashMap::new();
+        for position in civ_territories.tiles_of(civilization.id) {
+            if let Some(resource) = world_map.get_tile(*position).and_then(|tile| tile.resource) {
+                *resources.entry(resource).or_default() += 1;
+            }
+        }
+
+        for resource in GameResource::ALL {
+            let had_access = civilization.economy.has_resource(resource);
+            let has_access = resources.contains_key(&resource);
+            if had_access != has_access {
+                tracing::info!(
+                    "{} {} access to {}",
+                    civilization.name,
+                    if has_access { "gained" } else { "lost" },
+                    resource.name()
+                );
+            }
+        }
+
+        civilization.economy.resources = resources;
+    }
+}
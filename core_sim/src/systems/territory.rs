@@ -0,0 +1,211 @@
+use crate::{
+    components::turn_phases::TurnPhase, constants::territory, resources::CivTerritories,
+    BorderPressure, City, CivId, Position, Territory, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// System to grow city borders with culture and hand contested tiles to the strongest
+/// claimant during the turn transition
+///
+/// Every city claims the tiles within its border radius, more strongly the closer they are.
+/// A tile changes hands only when a rival's claim beats the current owner's, so borders
+/// push outward without flipping back and forth between neighbours.
+pub fn update_territory(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    mut world_map: ResMut<WorldMap>,
+    mut civ_territories: ResMut<CivTerritories>,
+    mut cities: Query<(&City, &Position, &mut BorderPressure)>,
+    mut territories: Query<(Entity, &mut Territory, &Position)>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let mut claims: HashMap<Position, HashMap<CivId, f32>> = HashMap::new();
+    let mut city_centers: HashMap<Position, CivId> = HashMap::new();
+
+    for (city, city_position, mut pressure) in cities.iter_mut() {
+        let culture = city.culture_per_turn();
+        grow_border(city, &mut pressure, culture);
+
+        for position in tiles_within(*city_position, pressure.radius) {
+            if world_map.get_tile(position).is_none() {
+                continue;
+            }
+            let distance = city_position.manhattan_distance_to(&position);
+            let strength = culture * (pressure.radius - distance + 1) as f32;
+            let civ_claim = claims
+                .entry(position)
+                .or_default()
+                .entry(city.owner)
+                .or_default();
+            *civ_claim = civ_claim.max(strength);
+        }
+        city_centers.insert(*city_position, city.owner);
+    }
+
+    let mut existing: HashMap<Position, Entity> = HashMap::new();
+    let mut held_strength: HashMap<Position, f32> = HashMap::new();
+    for (entity, territory, position) in territories.iter() {
+        existing.insert(*position, entity);
+        held_strength.insert(*position, territory.control_strength);
+    }
+
+    let mut control: HashMap<Position, (CivId, f32)> = HashMap::new();
+    for (position, civ_claims) in &claims {
+        // Equal claims go to the lower civ id so the outcome does not depend on hash order
+        let Some((&challenger, &challenger_strength)) = civ_claims
+            .iter()
+            .max_by(|(civ_a, a), (civ_b, b)| a.total_cmp(b).then(civ_b.0.cmp(&civ_a.0)))
+        else {
+            continue;
+        };
+
+        let incumbent = world_map.get_tile(*position).and_then(|tile| tile.owner);
+        let winner = match incumbent {
+            Some(owner) if owner != challenger => {
+                let incumbent_strength = civ_claims
+                    .get(&owner)
+                    .copied()
+                    .or_else(|| held_strength.get(position).copied())
+                    .unwrap_or_default();
+                if challenger_strength > incumbent_strength {
+                    (challenger, challenger_strength)
+                } else {
+                    (owner, incumbent_strength)
+                }
+            }
+            _ => (challenger, challenger_strength),
+        };
+
+        control.insert(*position, winner);
+    }
+
+    for (position, owner) in &city_centers {
+        let strength = claims
+            .get(position)
+            .and_then(|civ_claims| civ_claims.get(owner))
+            .copied()
+            .unwrap_or_default();
+        control.insert(*position, (*owner, strength));
+    }
+
+    for (position, (owner, strength)) in control {
+        let Some(tile) = world_map.get_tile_mut(position) else {
+            continue;
+        };
+        if tile.owner != Some(owner) {
+            tracing::debug!(
+                "Tile ({}, {}) passed from {:?} to civ {}",
+                position.x,
+                position.y,
+                tile.owner,
+                owner.0
+            );
+            tile.owner = Some(owner);
+        }
+
+        let terrain_type = tile.terrain.clone();
+        match existing.remove(&position) {
+            Some(entity) => {
+                if let Ok((_, mut territory, _)) = territories.get_mut(entity) {
+                    territory.owner = owner;
+                    territory.control_strength = strength;
+                    territory.terrain_type = terrain_type;
+                }
+            }
+            None => {
+                commands.spawn((
+                    Territory {
+                        owner,
+                        control_strength: strength,
+                        terrain_type,
+                    },
+                    position,
+                ));
+            }
+        }
+    }
+
+    // Territory nobody claims this turn is kept by whoever holds the tile, if anyone
+    for (position, entity) in existing {
+        match world_map.get_tile(position).and_then(|tile| tile.owner) {
+            Some(owner) => {
+                if let Ok((_, mut territory, _)) = territories.get_mut(entity) {
+                    territory.owner = owner;
+                }
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    *civ_territories = CivTerritories::from_world_map(&world_map);
+}
+
+fn grow_border(city: &City, pressure: &mut BorderPressure, culture: f32) {
+    pressure.accumulated += culture;
+
+    while pressure.radius < territory::MAX_BORDER_RADIUS
+        && pressure.accumulated >= pressure.growth_cost()
+    {
+        pressure.accumulated -= pressure.growth_cost();
+        pressure.radius += 1;
+        tracing::debug!(
+            "Borders of {} grew to radius {}",
+            city.name,
+            pressure.radius
+        );
+    }
+}
+
+fn tiles_within(center: Position, radius: i32) -> impl Iterator<Item = Position> {
+    (-radius..=radius).flat_map(move |dx| {
+        let reach = radius - dx.abs();
+        (-reach..=reach).map(move |dy| Position::new(center.x + dx, center.y + dy))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::world_map;
+    use bevy_ecs::system::RunSystemOnce;
+
+    /// Run `update_territory` once with a city of civ 1 at the west end of a strip of plains
+    /// and an identical city of civ 0 at the east end, whose borders both reach the middle
+    fn contest_middle_tile(middle_owner: Option<CivId>) -> Option<CivId> {
+        let mut world = World::new();
+        let mut world_map = world_map(&["....."]);
+        let middle = Position::new(2, 0);
+        world_map.get_tile_mut(middle).unwrap().owner = middle_owner;
+        world.insert_resource(world_map);
+        world.insert_resource(TurnPhase::TurnTransition);
+        world.insert_resource(CivTerritories::default());
+
+        for (owner, x) in [(CivId(1), 0), (CivId(0), 4)] {
+            world.spawn((
+                City::new(format!("City {}", owner.0), owner),
+                Position::new(x, 0),
+                BorderPressure {
+                    accumulated: 0.0,
+                    radius: 2,
+                },
+            ));
+        }
+
+        world.run_system_once(update_territory).unwrap();
+        world.resource::<WorldMap>().get_tile(middle).unwrap().owner
+    }
+
+    #[test]
+    fn test_equal_claims_go_to_lower_civ_id() {
+        assert_eq!(contest_middle_tile(None), Some(CivId(0)));
+    }
+
+    #[test]
+    fn test_equal_claim_does_not_take_tile_from_owner() {
+        assert_eq!(contest_middle_tile(Some(CivId(1))), Some(CivId(1)));
+    }
+}
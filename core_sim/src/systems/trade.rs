@@ -0,0 +1,454 @@
+use crate::{
+    components::turn_phases::TurnPhase,
+    constants::{diplomacy, trade},
+    economy::EconomicSystem,
+    pathfinding::Pathfinder,
+    resources::{
+        DiplomaticProposal, DiplomaticState, GlobalEconomy, GlobalTradeRoute, Negotiation,
+    },
+    City, CivId, Civilization, GameResource, MilitaryUnit, MovementDomain, Position,
+    TerrainRegistry, TradeRoute, WorldMap,
+};
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// Event emitted when hostile units cut a trade route and carry off its goods
+#[derive(Message, Debug, Clone)]
+pub struct TradeRoutePillaged {
+    pub from_civ: CivId,
+    pub to_civ: CivId,
+    pub pillager: CivId,
+    pub loot: f32,
+}
+
+/// Whether `civ_a` and `civ_b` have a trade agreement and are not at war
+pub fn can_trade(diplomatic_state: &DiplomaticState, civ_a: CivId, civ_b: CivId) -> bool {
+    civ_a != civ_b
+        && !diplomatic_state.is_at_war(civ_a, civ_b)
+        && diplomatic_state
+            .get_relation(civ_a, civ_b)
+            .is_some_and(|relation| relation.trade_agreement)
+}
+
+/// Open trade pact talks between `initiator` and `target` unless they are at war, already
+/// trade or are already negotiating. Returns whether talks were opened.
+pub fn propose_trade_pact(
+    diplomatic_state: &mut DiplomaticState,
+    initiator: CivId,
+    target: CivId,
+) -> bool {
+    let already_negotiating = diplomatic_state
+        .ongoing_negotiations
+        .iter()
+        .any(|negotiation| {
+            matches!(negotiation.proposal, DiplomaticProposal::TradePact)
+                && DiplomaticState::relation_key(negotiation.initiator, negotiation.target)
+                    == DiplomaticState::relation_key(initiator, target)
+        });
+    if initiator == target
+        || already_negotiating
+        || can_trade(diplomatic_state, initiator, target)
+        || diplomatic_state.is_at_war(initiator, target)
+    {
+        return false;
+    }
+
+    tracing::debug!(
+        "Civ {} proposed a trade pact to civ {}",
+        initiator.0,
+        target.0
+    );
+    diplomatic_state.ongoing_negotiations.push(Negotiation {
+        initiator,
+        target,
+        proposal: DiplomaticProposal::TradePact,
+        turns_remaining: diplomacy::TRADE_PACT_NEGOTIATION_TURNS,
+    });
+    true
+}
+
+/// Open a trade route from the closest unconnected pair of cities of `exporter` and
+/// `partner`, carrying `requested_resource` if the exporter has access to it and its
+/// most valuable resource otherwise
+pub fn establish_trade_route(
+    global_economy: &mut GlobalEconomy,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    cities: &[(CivId, Position)],
+    exporter: &Civilization,
+    partner: CivId,
+    requested_resource: GameResource,
+) -> Result<(), &'static str> {
+    let open_routes = global_economy
+        .trade_routes
+        .iter()
+        .filter(|route| route.from_civ == exporter.id)
+        .count();
+    if open_routes >= trade::MAX_TRADE_ROUTES_PER_CIV {
+        return Err("Civilization already runs as many trade routes as it can");
+    }
+
+    let resource = if exporter.economy.has_resource(requested_resource) {
+        requested_resource
+    } else {
+        GameResource::ALL
+            .into_iter()
+            .filter(|resource| exporter.economy.has_resource(*resource))
+            .max_by(|a, b| {
+                global_economy
+                    .resource_price(*a)
+                    .total_cmp(&global_economy.resource_price(*b))
+            })
+            .ok_or("Civilization has no resources to trade")?
+    };
+
+    let mut city_pairs: Vec<(Position, Position)> = cities
+        .iter()
+        .filter(|(owner, _)| *owner == exporter.id)
+        .flat_map(|(_, from_city)| {
+            cities
+                .iter()
+                .filter(|(owner, _)| *owner == partner)
+                .map(move |(_, to_city)| (*from_city, *to_city))
+        })
+        .filter(|(from_city, to_city)| {
+            !global_economy
+                .trade_routes
+                .iter()
+                .any(|route| route.connects(*from_city, *to_city))
+        })
+        .collect();
+    city_pairs.sort_by_key(|(from_city, to_city)| {
+        (
+            from_city.manhattan_distance_to(to_city),
+            from_city.x,
+            from_city.y,
+            to_city.x,
+            to_city.y,
+        )
+    });
+
+    let (from_city, to_city, path) = city_pairs
+        .into_iter()
+        .find_map(|(from_city, to_city)| {
+            let path = Pathfinder::new().find_path(
+                world_map,
+                terrain_registry,
+                from_city,
+                to_city,
+                MovementDomain::Land,
+                f32::MAX,
+            )?;
+            (path.len() <= trade::MAX_TRADE_ROUTE_LENGTH).then_some((from_city, to_city, path))
+        })
+        .ok_or("No pair of cities is within reach of a trade route")?;
+
+    let volume =
+        EconomicSystem::trade_route_volume(path.len(), global_economy.resource_price(resource));
+
+    tracing::info!(
+        "Civ {} opened a trade route carrying {} to civ {} worth {:.1} gold per turn",
+        exporter.id.0,
+        resource.name(),
+        partner.0,
+        volume
+    );
+
+    global_economy.trade_routes.push(GlobalTradeRoute {
+        from_civ: exporter.id,
+        to_civ: partner,
+        resource,
+        volume,
+        security_level: 1.0,
+        from_city,
+        to_city,
+        path,
+    });
+
+    Ok(())
+}
+
+/// System to secure, pillage or close trade routes and pay out their gold during the turn
+/// transition
+///
+/// Route value follows the market price of the goods carried. Routes close when their
+/// partners lose the trade agreement or go to war, or when either city changes hands.
+/// Units of civilizations at war with either partner that stand on the route wear down
+/// its security; a route with no security left is pillaged.
+pub fn update_trade_routes(
+    turn_phase: Res<TurnPhase>,
+    mut global_economy: ResMut<GlobalEconomy>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut civilizations: Query<&mut Civilization>,
+    cities: Query<(&City, &Position)>,
+    units: Query<(&MilitaryUnit, &Position)>,
+    mut pillage_events: MessageWriter<TradeRoutePillaged>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    let city_owners: HashMap<Position, CivId> = cities
+        .iter()
+        .map(|(city, position)| (*position, city.owner))
+        .collect();
+
+    let mut units_at: HashMap<Position, Vec<CivId>> = HashMap::new();
+    for (unit, position) in units.iter() {
+        units_at.entry(*position).or_default().push(unit.owner);
+    }
+
+    let resource_prices = global_economy.resource_prices.clone();
+    let trade_multiplier = global_economy.trade_multiplier();
+
+    let mut loot_by_civ: HashMap<CivId, f32> = HashMap::new();
+    global_economy.trade_routes.retain_mut(|route| {
+        if !can_trade(&diplomatic_state, route.from_civ, route.to_civ)
+            || city_owners.get(&route.from_city) != Some(&route.from_civ)
+            || city_owners.get(&route.to_city) != Some(&route.to_civ)
+        {
+            tracing::info!(
+                "Trade route between civ {} and civ {} closed",
+                route.from_civ.0,
+                route.to_civ.0
+            );
+            return false;
+        }
+
+        route.volume = EconomicSystem::trade_route_volume(
+            route.path.len(),
+            resource_prices
+                .get(&route.resource)
+                .copied()
+                .unwrap_or_default(),
+        );
+
+        let hostile_units: Vec<CivId> = route
+            .path
+            .iter()
+            .filter_map(|position| units_at.get(position))
+            .flatten()
+            .copied()
+            .filter(|owner| {
+                !route.involves(*owner)
+                    && (diplomatic_state.is_at_war(*owner, route.from_civ)
+                        || diplomatic_state.is_at_war(*owner, route.to_civ))
+            })
+            .collect();
+
+        if hostile_units.is_empty() {
+            route.security_level =
+                (route.security_level + trade::SECURITY_RECOVERY_PER_TURN).min(1.0);
+            return true;
+        }
+
+        route.security_level -= trade::SECURITY_LOSS_PER_HOSTILE_UNIT * hostile_units.len() as f32;
+        if route.security_level > 0.0 {
+            return true;
+        }
+
+        // The raider with the most units on the route carries off the goods
+        let mut raiders: HashMap<CivId, usize> = HashMap::new();
+        for owner in &hostile_units {
+            *raiders.entry(*owner).or_default() += 1;
+        }
+        let Some(pillager) = raiders
+            .into_iter()
+            .max_by(|(civ_a, count_a), (civ_b, count_b)| {
+                count_a.cmp(count_b).then(civ_b.0.cmp(&civ_a.0))
+            })
+            .map(|(civ_id, _)| civ_id)
+        else {
+            return true;
+        };
+
+        let loot = route.volume * trade::PILLAGE_LOOT_TURNS;
+        *loot_by_civ.entry(pillager).or_default() += loot;
+        tracing::info!(
+            "Civ {} pillaged the trade route between civ {} and civ {} for {:.1} gold",
+            pillager.0,
+            route.from_civ.0,
+            route.to_civ.0,
+            loot
+        );
+        pillage_events.write(TradeRoutePillaged {
+            from_civ: route.from_civ,
+            to_civ: route.to_civ,
+            pillager,
+            loot,
+        });
+        false
+    });
+
+    for mut civilization in civilizations.iter_mut() {
+        let civ_id = civilization.id;
+        civilization.economy.gold += loot_by_civ.get(&civ_id).copied().unwrap_or_default();
+        civilization.economy.trade_routes = global_economy
+            .trade_routes
+            .iter()
+            .filter(|route| route.involves(civ_id))
+            .map(|route| {
+                let (from, to) = if route.from_civ == civ_id {
+                    (route.from_city, route.to_city)
+                } else {
+                    (route.to_city, route.from_city)
+                };
+                TradeRoute {
+                    from,
+                    to,
+                    value: route.value() * trade_multiplier,
+                    security: route.security_level,
+                }
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::diplomacy::Treaty;
+    use crate::test_support::{terrain_registry, world_map};
+    use crate::{CivPersonality, Economy, Military, Technologies};
+
+    fn civilization(id: CivId, resources: &[GameResource]) -> Civilization {
+        Civilization {
+            id,
+            name: format!("Civ {}", id.0),
+            color: [0.0; 3],
+            capital: None,
+            personality: CivPersonality::default(),
+            technologies: Technologies::default(),
+            economy: Economy {
+                resources: resources.iter().map(|resource| (*resource, 1)).collect(),
+                ..Economy::default()
+            },
+            military: Military::default(),
+            music_theme: String::new(),
+            sound_theme: String::new(),
+        }
+    }
+
+    fn diplomatic_state() -> DiplomaticState {
+        let mut diplomatic_state = DiplomaticState::default();
+        diplomatic_state.seed_relations(&[CivId(0), CivId(1)]);
+        diplomatic_state
+    }
+
+    fn declare_war(diplomatic_state: &mut DiplomaticState) {
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap()
+            .treaties
+            .push(Treaty::War { started_turn: 1 });
+    }
+
+    #[test]
+    fn test_can_trade_needs_agreement_and_peace() {
+        let mut diplomatic_state = diplomatic_state();
+        assert!(!can_trade(&diplomatic_state, CivId(0), CivId(1)));
+
+        diplomatic_state
+            .get_relation_mut(CivId(0), CivId(1))
+            .unwrap()
+            .trade_agreement = true;
+        assert!(can_trade(&diplomatic_state, CivId(1), CivId(0)));
+        assert!(!can_trade(&diplomatic_state, CivId(0), CivId(0)));
+
+        declare_war(&mut diplomatic_state);
+        assert!(!can_trade(&diplomatic_state, CivId(0), CivId(1)));
+    }
+
+    #[test]
+    fn test_propose_trade_pact_opens_talks_once() {
+        let mut diplomatic_state = diplomatic_state();
+        let (civ_a, civ_b) = (CivId(0), CivId(1));
+
+        assert!(propose_trade_pact(&mut diplomatic_state, civ_a, civ_b));
+        assert!(!propose_trade_pact(&mut diplomatic_state, civ_b, civ_a));
+        assert!(!propose_trade_pact(&mut diplomatic_state, civ_a, civ_a));
+        assert_eq!(diplomatic_state.ongoing_negotiations.len(), 1);
+    }
+
+    #[test]
+    fn test_propose_trade_pact_refused_at_war() {
+        let mut diplomatic_state = diplomatic_state();
+        declare_war(&mut diplomatic_state);
+
+        let proposed = propose_trade_pact(&mut diplomatic_state, CivId(0), CivId(1));
+        assert!(!proposed);
+        assert!(diplomatic_state.ongoing_negotiations.is_empty());
+    }
+
+    #[test]
+    fn test_establish_trade_route_links_closest_cities_first() {
+        let world_map = world_map(&[".........."]);
+        let terrain_registry = terrain_registry();
+        let mut global_economy = GlobalEconomy::default();
+        let cities = [
+            (CivId(0), Position::new(0, 0)),
+            (CivId(1), Position::new(9, 0)),
+            (CivId(1), Position::new(5, 0)),
+        ];
+        let exporter = civilization(CivId(0), &[GameResource::Iron]);
+        let mut establish = |resource| {
+            establish_trade_route(
+                &mut global_economy,
+                &world_map,
+                &terrain_registry,
+                &cities,
+                &exporter,
+                CivId(1),
+                resource,
+            )
+        };
+
+        assert!(establish(GameResource::Iron).is_ok());
+        assert!(establish(GameResource::Gold).is_ok());
+        // Every pair of cities is already linked
+        assert!(establish(GameResource::Iron).is_err());
+
+        let routes = &global_economy.trade_routes;
+        assert!(routes[0].connects(Position::new(0, 0), Position::new(5, 0)));
+        assert!(routes[1].connects(Position::new(0, 0), Position::new(9, 0)));
+        assert!(routes
+            .iter()
+            .all(|route| route.resource == GameResource::Iron));
+    }
+
+    #[test]
+    fn test_establish_trade_route_needs_goods_and_a_path() {
+        let world_map = world_map(&["..~.."]);
+        let terrain_registry = terrain_registry();
+        let mut global_economy = GlobalEconomy::default();
+        let cities = [
+            (CivId(0), Position::new(0, 0)),
+            (CivId(1), Position::new(4, 0)),
+        ];
+
+        let no_goods = civilization(CivId(0), &[]);
+        let result = establish_trade_route(
+            &mut global_economy,
+            &world_map,
+            &terrain_registry,
+            &cities,
+            &no_goods,
+            CivId(1),
+            GameResource::Iron,
+        );
+        assert!(result.is_err());
+
+        let overseas = civilization(CivId(0), &[GameResource::Iron]);
+        let result = establish_trade_route(
+            &mut global_economy,
+            &world_map,
+            &terrain_registry,
+            &cities,
+            &overseas,
+            CivId(1),
+            GameResource::Iron,
+        );
+        assert!(result.is_err());
+        assert!(global_economy.trade_routes.is_empty());
+    }
+}
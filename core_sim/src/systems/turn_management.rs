@@ -1,6 +1,7 @@
 use crate::{
     components::{
         city::City,
+        military::MovementDomain,
         position::MovementOrder,
         production::{ProductionItem, ProductionQueue},
         turn_phases::{
@@ -10,9 +11,9 @@ use crate::{
         Capital, Civilization, MilitaryUnit, PlayerActionsComplete, PlayerControlled,
     },
     constants::civilization_management::{PLAYER_CIVILIZATION_ID, STARTING_UNIT_ID_COUNTER},
-    pathfinding::Pathfinder,
     resources::{CurrentTurn, GameConfig},
-    CivId, Position, WorldMap,
+    systems::naval::naval_launch_site,
+    BuildingRegistry, CivId, Position, TerrainRegistry, UnitRegistry, WorldMap,
 };
 use bevy_ecs::prelude::*;
 
@@ -144,20 +145,12 @@ pub fn handle_ai_turn_processing(
     mut ai_turn_events: MessageReader<ProcessAITurn>,
     mut ai_complete_events: MessageWriter<AITurnComplete>,
     civilizations: Query<&Civilization>,
-    mut commands: Commands,
-    mut units_query: Query<(Entity, &mut MilitaryUnit, &mut Position)>,
-    world_map: Res<WorldMap>,
+    units_query: Query<(&MilitaryUnit, Has<MovementOrder>)>,
 ) {
     for ai_event in ai_turn_events.read() {
         tracing::info!("Processing AI turn for civilization {:?}", ai_event.civ_id);
 
-        process_ai_civilization_turn(
-            ai_event.civ_id,
-            &civilizations,
-            &mut commands,
-            &mut units_query,
-            &world_map,
-        );
+        process_ai_civilization_turn(ai_event.civ_id, &civilizations, &units_query);
 
         ai_complete_events.write(AITurnComplete {
             civ_id: ai_event.civ_id,
@@ -189,117 +182,49 @@ pub fn handle_ai_turn_completion(
     }
 }
 
+/// AI units move along the goto orders their civilization's actions gave them, see
+/// `follow_movement_orders`
 fn process_ai_civilization_turn(
     civ_id: CivId,
     civilizations: &Query<&Civilization>,
-    commands: &mut Commands,
-    units_query: &mut Query<(Entity, &mut MilitaryUnit, &mut Position)>,
-    world_map: &WorldMap,
+    units_query: &Query<(&MilitaryUnit, Has<MovementOrder>)>,
 ) {
     if let Some(civ) = civilizations.iter().find(|civ| civ.id == civ_id) {
         tracing::info!("AI {} ({}) is taking their turn", civ.name, civ_id.0);
 
         let mut unit_count = 0;
-        let mut moved_units = 0;
+        let mut moving_units = 0;
 
-        for (entity, mut unit, mut position) in units_query.iter_mut() {
+        for (unit, has_movement_order) in units_query.iter() {
             if unit.owner == civ_id {
                 unit_count += 1;
-                tracing::debug!("Found AI unit {} for civilization {}", unit.id, civ_id.0);
-
-                if unit.can_move() {
-                    move_ai_unit_simple(entity, &mut unit, &mut position, commands, world_map);
-                    moved_units += 1;
+                if has_movement_order {
+                    moving_units += 1;
                 }
             }
         }
 
         tracing::info!(
-            "AI {} completed their turn (processed {} units, {} could move)",
+            "AI {} completed their turn ({} units, {} under way)",
             civ.name,
             unit_count,
-            moved_units
+            moving_units
         );
     }
 }
 
-/// Simple AI unit movement - moves to the first valid adjacent tile
-fn move_ai_unit_simple(
-    entity: Entity,
-    unit: &mut MilitaryUnit,
-    position: &mut Position,
-    commands: &mut Commands,
-    world_map: &WorldMap,
-) {
-    let current_pos = *position;
-    let adjacent_positions = current_pos.adjacent_positions();
-
-    tracing::debug!(
-        "AI unit {} at ({}, {}) checking {} adjacent positions",
-        unit.id,
-        current_pos.x,
-        current_pos.y,
-        adjacent_positions.len()
-    );
-
-    // Try to move to the first valid adjacent position
-    for (i, target_pos) in adjacent_positions.iter().enumerate() {
-        tracing::debug!(
-            "AI unit {} checking position {} ({}, {})",
-            unit.id,
-            i,
-            target_pos.x,
-            target_pos.y
-        );
-
-        if is_valid_move_target(current_pos, *target_pos, world_map) {
-            commands
-                .entity(entity)
-                .insert(MovementOrder::new(vec![*target_pos], *target_pos));
-
-            tracing::info!(
-                "AI unit {} planned movement from ({}, {}) to ({}, {})",
-                unit.id,
-                current_pos.x,
-                current_pos.y,
-                target_pos.x,
-                target_pos.y
-            );
-
-            return;
-        } else {
-            tracing::debug!(
-                "AI unit {} invalid move to ({}, {})",
-                unit.id,
-                target_pos.x,
-                target_pos.y
-            );
-        }
-    }
-
-    tracing::debug!("AI unit {} found no valid moves", unit.id);
-}
-
-fn is_valid_move_target(from: Position, to: Position, world_map: &WorldMap) -> bool {
-    if let Some(tile) = world_map.get_tile(to) {
-        match tile.terrain {
-            crate::TerrainType::Ocean => false,
-            _ => from.manhattan_distance_to(&to) == 1,
-        }
-    } else {
-        false
-    }
-}
-
 pub fn handle_turn_transition_complete(
     mut turn_phase: ResMut<TurnPhase>,
     mut current_turn: ResMut<CurrentTurn>,
     mut player_actions: ResMut<PlayerActionsComplete>,
     mut units: Query<&mut MilitaryUnit>,
-    mut production_query: Query<(Entity, &mut ProductionQueue, &mut City, &Capital, &Position)>,
+    mut production_query: Query<(Entity, &mut ProductionQueue, &mut City, &Position)>,
     mut commands: Commands,
     mut unit_id_counter: Local<u32>,
     world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
     mut production_events: MessageWriter<ProductionUpdated>,
     mut start_player_events: MessageWriter<StartPlayerTurn>,
     mut turn_order: ResMut<TurnOrder>,
@@ -318,6 +243,9 @@ pub fn handle_turn_transition_complete(
         &mut commands,
         &mut unit_id_counter,
         &world_map,
+        &terrain_registry,
+        &unit_registry,
+        &building_registry,
         next_turn_number,
         &mut production_events,
         &player_civs,
@@ -364,27 +292,36 @@ fn reset_player_action_tracking(player_actions: &mut ResMut<PlayerActionsComplet
 }
 
 fn process_all_city_production_for_turn(
-    production_query: &mut Query<(Entity, &mut ProductionQueue, &mut City, &Capital, &Position)>,
+    production_query: &mut Query<(Entity, &mut ProductionQueue, &mut City, &Position)>,
     commands: &mut Commands,
     unit_id_counter: &mut Local<u32>,
     world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
     turn_number: u32,
     production_events: &mut MessageWriter<ProductionUpdated>,
     player_civs: &Query<&Civilization, With<PlayerControlled>>,
 ) {
-    for (entity, mut production_queue, mut city, capital, position) in production_query.iter_mut() {
+    for (entity, mut production_queue, mut city, position) in production_query.iter_mut() {
         let had_production_before =
             production_queue.current_production.is_some() || !production_queue.queue.is_empty();
 
-        if let Some(completed_item) = production_queue.add_production(city.production) {
+        if let Some(completed_item) =
+            production_queue.add_production(city.production, unit_registry, building_registry)
+        {
+            let owner = city.owner;
             spawn_completed_production_item(
                 commands,
                 &completed_item,
-                &capital.owner,
+                &owner,
                 position,
                 unit_id_counter,
                 &mut city,
                 world_map,
+                terrain_registry,
+                unit_registry,
+                building_registry,
                 turn_number,
                 player_civs,
             );
@@ -408,7 +345,10 @@ fn spawn_completed_production_item(
     position: &Position,
     unit_id_counter: &mut Local<u32>,
     city: &mut City,
-    _world_map: &WorldMap,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
     _current_turn: u32,
     player_civs: &Query<&Civilization, With<PlayerControlled>>,
 ) {
@@ -420,11 +360,14 @@ fn spawn_completed_production_item(
                 owner,
                 position,
                 unit_id_counter,
+                world_map,
+                terrain_registry,
+                unit_registry,
                 player_civs,
             );
         }
         ProductionItem::Building(building_type) => {
-            add_building_to_city(city, building_type);
+            add_building_to_city(city, building_type, building_registry);
         }
     }
 }
@@ -435,20 +378,58 @@ fn spawn_military_unit_at_position(
     owner: &crate::components::CivId,
     position: &Position,
     unit_id_counter: &mut Local<u32>,
+    world_map: &WorldMap,
+    terrain_registry: &TerrainRegistry,
+    unit_registry: &UnitRegistry,
     player_civs: &Query<&Civilization, With<PlayerControlled>>,
 ) {
-    let unit = crate::MilitaryUnit::new(**unit_id_counter, *owner, *unit_type, *position);
+    // Ships are launched onto the water next to the city that built them
+    let position = match unit_registry.definition(unit_type).class().domain() {
+        MovementDomain::Land => *position,
+        MovementDomain::Sea => match naval_launch_site(world_map, terrain_registry, *position) {
+            Some(launch_site) => launch_site,
+            None => {
+                tracing::warn!(
+                    "Civ {} cannot launch a {} from the inland city at ({}, {})",
+                    owner.0,
+                    unit_type,
+                    position.x,
+                    position.y
+                );
+                return;
+            }
+        },
+    };
+
+    let unit = crate::MilitaryUnit::new(
+        **unit_id_counter,
+        *owner,
+        unit_type.clone(),
+        position,
+        unit_registry,
+    );
     **unit_id_counter += 1;
 
-    let mut entity_commands = commands.spawn((unit, *position));
+    let mut entity_commands = commands.spawn((unit, position, *owner));
+    entity_commands.insert(crate::ProvidesVision::unit_vision());
 
     if is_player_controlled_civilization(*owner, player_civs) {
         entity_commands.insert(crate::PlayerControlled);
     }
 }
 
-fn add_building_to_city(city: &mut City, building_type: &crate::components::city::BuildingType) {
-    city.add_building(building_type.clone());
+fn add_building_to_city(
+    city: &mut City,
+    building_type: &crate::components::city::BuildingType,
+    building_registry: &BuildingRegistry,
+) {
+    if !city.add_building(building_type.clone(), building_registry) {
+        tracing::debug!(
+            "{} already has the highest level {}",
+            city.name,
+            building_type
+        );
+    }
 }
 
 pub fn auto_advance_turn_system(
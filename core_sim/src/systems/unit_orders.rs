@@ -0,0 +1,219 @@
+use crate::{
+    components::{
+        fog_of_war::ProvidesVision, military::UnitClass, orders::UnitOrder, turn_phases::TurnPhase,
+    },
+    constants::unit_orders,
+    resources::DiplomaticState,
+    CivId, MilitaryUnit, Position, UnitType,
+};
+use bevy_ecs::prelude::*;
+
+/// Event emitted when an enemy unit comes into view of a unit on sentry duty
+#[derive(Message, Debug, Clone)]
+pub struct SentryAlerted {
+    pub civ_id: CivId,
+    pub unit_type: UnitType,
+    pub position: Position,
+    pub enemy: CivId,
+}
+
+/// Check whether `unit` may be given `order`
+pub fn validate_unit_order(unit: &MilitaryUnit, order: &UnitOrder) -> Result<(), &'static str> {
+    if !unit.is_reliable() {
+        return Err("Unit refuses orders");
+    }
+    if matches!(order, UnitOrder::Fortify { .. }) && unit.unit_class == UnitClass::Civilian {
+        return Err("Civilian units cannot fortify");
+    }
+    Ok(())
+}
+
+/// System to carry standing orders over into the next turn during the turn transition
+///
+/// Fortified units dig in one level deeper and units that skipped a turn are ready again.
+pub fn advance_unit_orders(
+    turn_phase: Res<TurnPhase>,
+    mut commands: Commands,
+    mut orders: Query<(Entity, &mut UnitOrder)>,
+) {
+    if !matches!(*turn_phase, TurnPhase::TurnTransition) {
+        return;
+    }
+
+    for (entity, mut order) in orders.iter_mut() {
+        match *order {
+            UnitOrder::Fortify { level } if level < unit_orders::MAX_FORTIFICATION_LEVEL => {
+                *order = UnitOrder::Fortify { level: level + 1 };
+            }
+            UnitOrder::Skip => {
+                commands.entity(entity).remove::<UnitOrder>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// System to wake units on sentry duty once a unit of a civilization they are at war with
+/// comes within their vision range
+pub fn wake_sentries(
+    mut commands: Commands,
+    sentries: Query<(
+        Entity,
+        &MilitaryUnit,
+        &Position,
+        Option<&ProvidesVision>,
+        &UnitOrder,
+    )>,
+    units: Query<(&MilitaryUnit, &Position)>,
+    diplomatic_state: Res<DiplomaticState>,
+    mut sentry_events: MessageWriter<SentryAlerted>,
+) {
+    for (entity, sentry, position, vision, order) in sentries.iter() {
+        if *order != UnitOrder::Sentry {
+            continue;
+        }
+
+        let vision_range =
+            vision.map_or(ProvidesVision::unit_vision().range, |vision| vision.range);
+        let Some(enemy) = units
+            .iter()
+            .find(|(other, other_position)| {
+                diplomatic_state.is_at_war(sentry.owner, other.owner)
+                    && position.manhattan_distance_to(other_position) <= vision_range
+            })
+            .map(|(other, _)| other.owner)
+        else {
+            continue;
+        };
+
+        tracing::info!(
+            "Unit {} of civ {} spotted units of civ {} at ({}, {})",
+            sentry.id,
+            sentry.owner.0,
+            enemy.0,
+            position.x,
+            position.y
+        );
+        sentry_events.write(SentryAlerted {
+            civ_id: sentry.owner,
+            unit_type: sentry.unit_type.clone(),
+            position: *position,
+            enemy,
+        });
+        commands.entity(entity).remove::<UnitOrder>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Treaty, UnitRegistry};
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+
+    fn unit(owner: CivId, position: Position) -> MilitaryUnit {
+        MilitaryUnit::new(
+            0,
+            owner,
+            UnitType::new("Infantry"),
+            position,
+            &UnitRegistry::default(),
+        )
+    }
+
+    fn advance(turn_phase: TurnPhase, order: UnitOrder) -> Option<UnitOrder> {
+        let mut world = World::new();
+        world.insert_resource(turn_phase);
+        let entity = world.spawn(order).id();
+
+        world.run_system_once(advance_unit_orders).unwrap();
+        world.get::<UnitOrder>(entity).copied()
+    }
+
+    #[test]
+    fn test_fortified_units_dig_in_up_to_the_maximum() {
+        let max_level = unit_orders::MAX_FORTIFICATION_LEVEL;
+        let deeper = advance(TurnPhase::TurnTransition, UnitOrder::Fortify { level: 0 });
+        let at_max = advance(
+            TurnPhase::TurnTransition,
+            UnitOrder::Fortify { level: max_level },
+        );
+
+        assert_eq!(deeper, Some(UnitOrder::Fortify { level: 1 }));
+        assert_eq!(at_max, Some(UnitOrder::Fortify { level: max_level }));
+    }
+
+    #[test]
+    fn test_skip_lasts_one_turn_and_other_orders_stay() {
+        assert_eq!(advance(TurnPhase::TurnTransition, UnitOrder::Skip), None);
+        assert_eq!(
+            advance(TurnPhase::TurnTransition, UnitOrder::Sleep),
+            Some(UnitOrder::Sleep)
+        );
+        assert_eq!(
+            advance(TurnPhase::TurnTransition, UnitOrder::Sentry),
+            Some(UnitOrder::Sentry)
+        );
+    }
+
+    #[test]
+    fn test_orders_only_advance_during_the_turn_transition() {
+        let skip = advance(TurnPhase::default(), UnitOrder::Skip);
+        let fortify = advance(TurnPhase::default(), UnitOrder::Fortify { level: 0 });
+
+        assert_eq!(skip, Some(UnitOrder::Skip));
+        assert_eq!(fortify, Some(UnitOrder::Fortify { level: 0 }));
+    }
+
+    /// Run `wake_sentries` once for a civ 0 sentry at the origin and a civ 1 unit at
+    /// `enemy_position`, and return the sentry's order and how many alerts were raised
+    fn watch(at_war: bool, enemy_position: Position) -> (Option<UnitOrder>, usize) {
+        let mut world = World::new();
+        let mut diplomatic_state = DiplomaticState::default();
+        diplomatic_state.seed_relations(&[CivId(0), CivId(1)]);
+        if at_war {
+            diplomatic_state
+                .get_relation_mut(CivId(0), CivId(1))
+                .unwrap()
+                .treaties
+                .push(Treaty::War { started_turn: 0 });
+        }
+        world.insert_resource(diplomatic_state);
+        world.init_resource::<Messages<SentryAlerted>>();
+
+        let origin = Position::new(0, 0);
+        let sentry = world
+            .spawn((unit(CivId(0), origin), origin, UnitOrder::Sentry))
+            .id();
+        world.spawn((unit(CivId(1), enemy_position), enemy_position));
+
+        world.run_system_once(wake_sentries).unwrap();
+        let alerts = world.resource::<Messages<SentryAlerted>>().len();
+        (world.get::<UnitOrder>(sentry).copied(), alerts)
+    }
+
+    #[test]
+    fn test_sentries_wake_when_an_enemy_comes_into_view() {
+        let in_view = Position::new(ProvidesVision::unit_vision().range, 0);
+
+        assert_eq!(watch(true, in_view), (None, 1));
+    }
+
+    #[test]
+    fn test_sentries_ignore_distant_enemies_and_civilizations_at_peace() {
+        let in_view = Position::new(ProvidesVision::unit_vision().range, 0);
+        let out_of_view = Position::new(ProvidesVision::unit_vision().range + 1, 0);
+
+        assert_eq!(watch(false, in_view), (Some(UnitOrder::Sentry), 0));
+        assert_eq!(watch(true, out_of_view), (Some(UnitOrder::Sentry), 0));
+    }
+
+    #[test]
+    fn test_civilians_cannot_fortify() {
+        let mut settler = unit(CivId(0), Position::new(0, 0));
+        settler.unit_class = UnitClass::Civilian;
+
+        assert!(validate_unit_order(&settler, &UnitOrder::Fortify { level: 0 }).is_err());
+        assert!(validate_unit_order(&settler, &UnitOrder::Sleep).is_ok());
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{ProductionItem, Technologies};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single entry of `technologies.ron`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TechnologyDefinition {
+    pub name: String,
+    pub cost: f32,
+    pub prerequisites: Vec<String>,
+    pub unlocks: Vec<String>,
+    pub description: String,
+    pub era: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EraDefinition {
+    pub name: String,
+    pub description: String,
+    pub tech_cost_modifier: f32,
+    pub duration_turns: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TechTreeLayoutEntry {
+    pub tech: String,
+    pub position: (i32, i32),
+}
+
+/// The full technology tree, loaded once from `technologies.ron`
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TechTree {
+    pub technologies: Vec<TechnologyDefinition>,
+    pub eras: Vec<EraDefinition>,
+    #[serde(default)]
+    pub tech_tree_layout: Vec<TechTreeLayoutEntry>,
+}
+
+impl TechTree {
+    pub fn load_from_ron(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let tech_tree: TechTree = ron::from_str(&content)?;
+        Ok(tech_tree)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TechnologyDefinition> {
+        self.technologies.iter().find(|tech| tech.name == name)
+    }
+
+    /// Research points needed for a technology, scaled by its era
+    pub fn research_cost(&self, name: &str) -> Option<f32> {
+        let tech = self.get(name)?;
+        let era_modifier = self
+            .eras
+            .iter()
+            .find(|era| era.name == tech.era)
+            .map(|era| era.tech_cost_modifier)
+            .unwrap_or(1.0);
+        Some(tech.cost * era_modifier)
+    }
+
+    /// A technology can be researched once it is unknown and all its prerequisites are known
+    pub fn can_research(&self, technologies: &Technologies, name: &str) -> bool {
+        self.get(name).is_some_and(|tech| {
+            !technologies.knows(name)
+                && tech
+                    .prerequisites
+                    .iter()
+                    .all(|prerequisite| technologies.knows(prerequisite))
+        })
+    }
+
+    /// Technologies that can be researched right now, in tree order
+    pub fn available_technologies(
+        &self,
+        technologies: &Technologies,
+    ) -> Vec<&TechnologyDefinition> {
+        self.technologies
+            .iter()
+            .filter(|tech| self.can_research(technologies, &tech.name))
+            .collect()
+    }
+
+    /// Switch research to `name` if it is available. Accumulated points carry over.
+    pub fn start_research(&self, technologies: &mut Technologies, name: &str) -> bool {
+        if !self.can_research(technologies, name) {
+            return false;
+        }
+        technologies.current_research = Some(name.to_string());
+        true
+    }
+
+    /// Technologies whose `unlocks` list names `item`
+    pub fn unlocking_technologies(&self, item: &str) -> Vec<&str> {
+        self.technologies
+            .iter()
+            .filter(|tech| tech.unlocks.iter().any(|unlock| unlock == item))
+            .map(|tech| tech.name.as_str())
+            .collect()
+    }
+
+    /// Items no technology unlocks are always available; otherwise any one unlocking tech suffices
+    pub fn is_unlocked(&self, technologies: &Technologies, item: &str) -> bool {
+        let unlocking_technologies = self.unlocking_technologies(item);
+        unlocking_technologies.is_empty()
+            || unlocking_technologies
+                .iter()
+                .any(|tech| technologies.knows(tech))
+    }
+
+    pub fn can_produce(&self, technologies: &Technologies, item: &ProductionItem) -> bool {
+        self.is_unlocked(technologies, item.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn technology(
+        name: &str,
+        cost: f32,
+        prerequisites: &[&str],
+        era: &str,
+    ) -> TechnologyDefinition {
+        TechnologyDefinition {
+            name: name.to_string(),
+            cost,
+            prerequisites: prerequisites.iter().map(|tech| tech.to_string()).collect(),
+            unlocks: Vec::new(),
+            description: String::new(),
+            era: era.to_string(),
+        }
+    }
+
+    fn tech_tree() -> TechTree {
+        TechTree {
+            technologies: vec![
+                technology("Agriculture", 20.0, &[], "Ancient"),
+                technology("Animal Husbandry", 25.0, &["Agriculture"], "Ancient"),
+                technology("Iron Working", 50.0, &["Agriculture"], "Classical"),
+            ],
+            eras: vec![EraDefinition {
+                name: "Classical".to_string(),
+                description: String::new(),
+                tech_cost_modifier: 1.5,
+                duration_turns: 100,
+            }],
+            tech_tree_layout: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_can_research_needs_prerequisites_and_an_unknown_technology() {
+        let tech_tree = tech_tree();
+        let mut technologies = Technologies::default();
+
+        assert!(tech_tree.can_research(&technologies, "Agriculture"));
+        assert!(!tech_tree.can_research(&technologies, "Animal Husbandry"));
+        assert!(!tech_tree.can_research(&technologies, "Writing"));
+
+        technologies.learn("Agriculture");
+
+        assert!(!tech_tree.can_research(&technologies, "Agriculture"));
+        assert!(tech_tree.can_research(&technologies, "Animal Husbandry"));
+    }
+
+    #[test]
+    fn test_start_research_only_switches_to_available_technologies() {
+        let tech_tree = tech_tree();
+        let mut technologies = Technologies {
+            research_points: 12.0,
+            ..Technologies::default()
+        };
+
+        assert!(!tech_tree.start_research(&mut technologies, "Animal Husbandry"));
+        assert_eq!(technologies.current_research, None);
+
+        assert!(tech_tree.start_research(&mut technologies, "Agriculture"));
+        assert_eq!(
+            technologies.current_research.as_deref(),
+            Some("Agriculture")
+        );
+        assert_eq!(technologies.research_points, 12.0);
+    }
+
+    #[test]
+    fn test_research_cost_is_scaled_by_era() {
+        let tech_tree = tech_tree();
+
+        assert_eq!(tech_tree.research_cost("Agriculture"), Some(20.0));
+        assert_eq!(tech_tree.research_cost("Iron Working"), Some(75.0));
+        assert_eq!(tech_tree.research_cost("Writing"), None);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::constants::terrain;
+use crate::{MovementDomain, TerrainType};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single entry of `terrain.ron`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerrainDefinition {
+    pub name: String,
+    pub movement_cost: f32,
+    pub defense_bonus: f32,
+    pub food_yield: f32,
+    pub production_yield: f32,
+    pub gold_yield: f32,
+    pub color: (f32, f32, f32),
+    pub description: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Land units can never enter impassable terrain
+    #[serde(default = "default_passable")]
+    pub passable: bool,
+    /// Naval units can only enter navigable terrain
+    #[serde(default)]
+    pub navigable: bool,
+    /// Ranged attacks cannot be made across this terrain
+    #[serde(default)]
+    pub blocks_line_of_sight: bool,
+}
+
+fn default_passable() -> bool {
+    true
+}
+
+impl TerrainDefinition {
+    /// Tile yields as (food, production, gold)
+    pub fn yields(&self) -> (f32, f32, f32) {
+        (self.food_yield, self.production_yield, self.gold_yield)
+    }
+
+    pub fn allows_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|name| name == feature)
+    }
+}
+
+/// Stats for terrain types the registry does not know about
+static FALLBACK_TERRAIN: TerrainDefinition = TerrainDefinition {
+    name: String::new(),
+    movement_cost: terrain::FALLBACK_MOVEMENT_COST,
+    defense_bonus: terrain::FALLBACK_DEFENSE_BONUS,
+    food_yield: terrain::FALLBACK_FOOD_YIELD,
+    production_yield: terrain::FALLBACK_PRODUCTION_YIELD,
+    gold_yield: terrain::FALLBACK_GOLD_YIELD,
+    color: terrain::FALLBACK_COLOR,
+    description: String::new(),
+    features: Vec::new(),
+    passable: true,
+    navigable: false,
+    blocks_line_of_sight: false,
+};
+
+/// Movement, combat and yield rules for every terrain type, loaded once from `terrain.ron`
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TerrainRegistry {
+    pub terrain_types: Vec<TerrainDefinition>,
+}
+
+impl TerrainRegistry {
+    pub fn load_from_ron(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let registry: TerrainRegistry = ron::from_str(&content)?;
+        Ok(registry)
+    }
+
+    pub fn get(&self, terrain_type: &TerrainType) -> Option<&TerrainDefinition> {
+        self.terrain_types
+            .iter()
+            .find(|definition| definition.name == terrain_type.name())
+    }
+
+    /// Definition for `terrain_type`, or generic fallback stats if it is not registered
+    pub fn definition(&self, terrain_type: &TerrainType) -> &TerrainDefinition {
+        self.get(terrain_type).unwrap_or_else(|| {
+            tracing::warn!(
+                "Terrain type '{}' is not in the terrain registry, using fallback stats",
+                terrain_type.name()
+            );
+            &FALLBACK_TERRAIN
+        })
+    }
+
+    pub fn movement_cost(&self, terrain_type: &TerrainType) -> f32 {
+        self.definition(terrain_type).movement_cost
+    }
+
+    pub fn defense_bonus(&self, terrain_type: &TerrainType) -> f32 {
+        self.definition(terrain_type).defense_bonus
+    }
+
+    /// Tile yields as (food, production, gold)
+    pub fn yields(&self, terrain_type: &TerrainType) -> (f32, f32, f32) {
+        self.definition(terrain_type).yields()
+    }
+
+    pub fn is_passable(&self, terrain_type: &TerrainType) -> bool {
+        self.definition(terrain_type).passable
+    }
+
+    pub fn is_navigable(&self, terrain_type: &TerrainType) -> bool {
+        self.definition(terrain_type).navigable
+    }
+
+    pub fn blocks_line_of_sight(&self, terrain_type: &TerrainType) -> bool {
+        self.definition(terrain_type).blocks_line_of_sight
+    }
+
+    /// Whether units travelling in `domain` can enter `terrain_type`
+    pub fn can_enter(&self, terrain_type: &TerrainType, domain: MovementDomain) -> bool {
+        match domain {
+            MovementDomain::Land => self.is_passable(terrain_type),
+            MovementDomain::Sea => self.is_navigable(terrain_type),
+        }
+    }
+}
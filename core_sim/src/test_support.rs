@@ -0,0 +1,66 @@
+//! Fixtures shared by the unit tests
+
+use crate::{
+    BuildingRegistry, Position, TechTree, TerrainRegistry, TerrainType, UnitRegistry, WorldMap,
+};
+
+/// The terrain rules the game ships with
+pub(crate) fn terrain_registry() -> TerrainRegistry {
+    TerrainRegistry::load_from_ron(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../dominion_earth/assets/data/terrain.ron"
+    ))
+    .expect("terrain.ron should load")
+}
+
+/// The buildings the game ships with
+pub(crate) fn building_registry() -> BuildingRegistry {
+    BuildingRegistry::load_from_ron(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../dominion_earth/assets/data/buildings.ron"
+    ))
+    .expect("buildings.ron should load")
+}
+
+/// The units the game ships with
+pub(crate) fn unit_registry() -> UnitRegistry {
+    UnitRegistry::load_from_ron(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../dominion_earth/assets/data/units.ron"
+    ))
+    .expect("units.ron should load")
+}
+
+/// The technology tree the game ships with
+pub(crate) fn tech_tree() -> TechTree {
+    TechTree::load_from_ron(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../dominion_earth/assets/data/technologies.ron"
+    ))
+    .expect("technologies.ron should load")
+}
+
+/// A map drawn one row of tiles per string, starting from `y = 0`
+///
+/// `.` is plains, `^` hills, `M` mountains, `F` forest and `~` ocean.
+pub(crate) fn world_map(rows: &[&str]) -> WorldMap {
+    let mut world_map = WorldMap::new(rows[0].len() as u32, rows.len() as u32);
+    for (y, row) in rows.iter().enumerate() {
+        for (x, symbol) in row.chars().enumerate() {
+            let terrain = match symbol {
+                '.' => TerrainType::Plains,
+                '^' => TerrainType::Hills,
+                'M' => TerrainType::Mountains,
+                'F' => TerrainType::Forest,
+                '~' => TerrainType::Ocean,
+                _ => panic!("unknown map symbol '{symbol}'"),
+            };
+            let position = Position::new(x as i32, y as i32);
+            world_map
+                .get_tile_mut(position)
+                .expect("row lengths should match")
+                .terrain = terrain;
+        }
+    }
+    world_map
+}
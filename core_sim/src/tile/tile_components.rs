@@ -115,6 +115,7 @@ pub fn setup_world_tiles(
     tilemap_id: TilemapId,
     tile_assets: &impl TileAssetProvider,
     world_map: &mut WorldMap,
+    terrain_registry: &crate::TerrainRegistry,
 ) -> TileStorage {
     let map_size = TilemapSize {
         x: world_map.width,
@@ -144,6 +145,7 @@ pub fn setup_world_tiles(
         &mut terrain_types,
         &map_size,
         world_map,
+        terrain_registry,
     );
     update_shallow_coast_tiles_pass(
         commands,
@@ -151,6 +153,7 @@ pub fn setup_world_tiles(
         &mut terrain_types,
         &map_size,
         world_map,
+        terrain_registry,
     );
 
     tile_storage
@@ -220,6 +220,7 @@ pub fn update_coast_tiles_pass(
     terrain_types: &mut Vec<Vec<TerrainType>>,
     map_dimensions: &TilemapSize,
     world_map: &mut crate::resources::WorldMap,
+    terrain_registry: &crate::TerrainRegistry,
 ) {
     // Collect all coast conversions first to avoid borrowing conflicts
     let mut coast_conversions = Vec::new();
@@ -262,6 +263,7 @@ pub fn update_coast_tiles_pass(
             &ocean_neighbors,
             terrain_types,
             world_map,
+            terrain_registry,
         );
     }
 }
@@ -276,6 +278,7 @@ pub fn update_shallow_coast_tiles_pass(
     terrain_types: &mut Vec<Vec<TerrainType>>,
     map_dimensions: &TilemapSize,
     world_map: &mut crate::resources::WorldMap,
+    terrain_registry: &crate::TerrainRegistry,
 ) {
     // Collect all shallow coast conversions first to avoid borrowing conflicts
     let mut shallow_coast_conversions = Vec::new();
@@ -309,6 +312,7 @@ pub fn update_shallow_coast_tiles_pass(
             y_coord,
             terrain_types,
             world_map,
+            terrain_registry,
         );
     }
 }
@@ -320,6 +324,7 @@ fn convert_ocean_to_shallow_coast_tile(
     y_coord: u32,
     terrain_grid: &mut Vec<Vec<TerrainType>>,
     world_map: &mut crate::resources::WorldMap,
+    terrain_registry: &crate::TerrainRegistry,
 ) {
     // Debug log the conversion using core debug utilities
     CoreDebugUtils::log_shallow_coast_conversion(x_coord, y_coord);
@@ -341,7 +346,7 @@ fn convert_ocean_to_shallow_coast_tile(
     // Keep the world map resource synchronized for UI display
     let world_position = Position::new(x_coord as i32, y_coord as i32);
     if let Some(map_tile) = world_map.get_tile_mut(world_position) {
-        map_tile.terrain = TerrainType::ShallowCoast;
+        map_tile.set_terrain(TerrainType::ShallowCoast, terrain_registry);
     }
 }
 
@@ -671,6 +676,7 @@ fn convert_land_to_coast_tile(
     ocean_neighbors: &OceanNeighbors,
     terrain_grid: &mut Vec<Vec<TerrainType>>,
     world_map: &mut crate::resources::WorldMap,
+    terrain_registry: &crate::TerrainRegistry,
 ) {
     // Debug log the conversion using core debug utilities
     let ocean_direction_names = ocean_neighbors.get_ocean_direction_names();
@@ -705,6 +711,6 @@ fn convert_land_to_coast_tile(
     // Keep the world map resource synchronized for UI display
     let world_position = Position::new(x_coord as i32, y_coord as i32);
     if let Some(map_tile) = world_map.get_tile_mut(world_position) {
-        map_tile.terrain = TerrainType::Coast;
+        map_tile.set_terrain(TerrainType::Coast, terrain_registry);
     }
 }
@@ -0,0 +1,293 @@
+use crate::constants::{combat, naval, units};
+use crate::{Economy, GameResource, MilitaryUnit, Technologies, TerrainType, UnitClass, UnitType};
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A single entry of `units.ron`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitDefinition {
+    pub name: String,
+    pub attack: f32,
+    pub defense: f32,
+    pub health: f32,
+    pub movement_range: u32,
+    pub range: u32,
+    pub gold_cost: f32,
+    pub production_cost: f32,
+    #[serde(default)]
+    pub required_technologies: Vec<String>,
+    /// Strategic resources the civilization must own a source of
+    #[serde(default)]
+    pub required_resources: Vec<GameResource>,
+    pub unit_class: String,
+    #[serde(default)]
+    pub special_abilities: Vec<String>,
+}
+
+impl UnitDefinition {
+    pub fn class(&self) -> UnitClass {
+        UnitClass::from_name(&self.unit_class).unwrap_or_default()
+    }
+
+    /// Gold paid each turn to keep the unit in the field
+    pub fn maintenance_cost(&self) -> f32 {
+        self.gold_cost * units::UNIT_MAINTENANCE_RATE
+    }
+
+    pub fn has_ability(&self, ability: &str) -> bool {
+        self.special_abilities.iter().any(|name| name == ability)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassCombatModifier {
+    pub vs_class: String,
+    pub modifier: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitClassDefinition {
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    pub combat_modifiers: Vec<ClassCombatModifier>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecialAbilityDefinition {
+    pub name: String,
+    pub description: String,
+    pub effect_type: String,
+    #[serde(default)]
+    pub value: Option<f32>,
+    #[serde(default)]
+    pub target_class: Option<String>,
+    #[serde(default)]
+    pub terrain: Option<String>,
+}
+
+/// What a promotion does for the unit that earns it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PromotionEffect {
+    /// Extra defense, as a fraction, while standing on `terrain`
+    TerrainDefense { terrain: String, bonus: f32 },
+    /// Extra movement points every turn
+    ExtraMovement(u32),
+    /// Share of maximum health restored every turn
+    Healing(f32),
+    /// Extra strength, as a fraction, when fighting units of `class`
+    ClassBonus { class: String, bonus: f32 },
+}
+
+/// A single entry of the promotion catalog in `units.ron`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromotionDefinition {
+    pub name: String,
+    pub description: String,
+    pub effect: PromotionEffect,
+    /// Unit classes that may take the promotion, every class when empty
+    #[serde(default)]
+    pub unit_classes: Vec<String>,
+}
+
+impl PromotionDefinition {
+    pub fn allows_class(&self, unit_class: UnitClass) -> bool {
+        self.unit_classes.is_empty()
+            || self
+                .unit_classes
+                .iter()
+                .any(|name| name == unit_class.name())
+    }
+}
+
+/// Stats for unit types the registry does not know about
+static FALLBACK_UNIT: UnitDefinition = UnitDefinition {
+    name: String::new(),
+    attack: units::FALLBACK_ATTACK,
+    defense: units::FALLBACK_DEFENSE,
+    health: units::FALLBACK_HEALTH,
+    movement_range: units::FALLBACK_MOVEMENT_RANGE,
+    range: units::FALLBACK_RANGE,
+    gold_cost: units::FALLBACK_GOLD_COST,
+    production_cost: units::FALLBACK_PRODUCTION_COST,
+    required_technologies: Vec::new(),
+    required_resources: Vec::new(),
+    unit_class: String::new(),
+    special_abilities: Vec::new(),
+};
+
+/// Every buildable unit, loaded once from `units.ron`
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnitRegistry {
+    pub unit_types: Vec<UnitDefinition>,
+    #[serde(default)]
+    pub unit_classes: Vec<UnitClassDefinition>,
+    #[serde(default)]
+    pub special_abilities: Vec<SpecialAbilityDefinition>,
+    #[serde(default)]
+    pub promotions: Vec<PromotionDefinition>,
+}
+
+impl UnitRegistry {
+    pub fn load_from_ron(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let registry: UnitRegistry = ron::from_str(&content)?;
+        Ok(registry)
+    }
+
+    pub fn get(&self, unit_type: &UnitType) -> Option<&UnitDefinition> {
+        self.unit_types
+            .iter()
+            .find(|definition| definition.name == unit_type.name())
+    }
+
+    /// Definition for `unit_type`, or generic fallback stats if it is not registered
+    pub fn definition(&self, unit_type: &UnitType) -> &UnitDefinition {
+        self.get(unit_type).unwrap_or_else(|| {
+            tracing::warn!(
+                "Unit type '{}' is not in the unit registry, using fallback stats",
+                unit_type.name()
+            );
+            &FALLBACK_UNIT
+        })
+    }
+
+    pub fn unit_type_names(&self) -> impl Iterator<Item = &str> {
+        self.unit_types
+            .iter()
+            .map(|definition| definition.name.as_str())
+    }
+
+    /// Whether a civilization knows every technology the unit requires
+    pub fn has_required_technologies(
+        &self,
+        technologies: &Technologies,
+        unit_type: &UnitType,
+    ) -> bool {
+        self.get(unit_type).is_none_or(|definition| {
+            definition
+                .required_technologies
+                .iter()
+                .all(|tech| technologies.knows(tech))
+        })
+    }
+
+    /// Whether a civilization owns a source of every resource the unit requires
+    pub fn has_required_resources(&self, economy: &Economy, unit_type: &UnitType) -> bool {
+        self.get(unit_type)
+            .is_none_or(|definition| economy.has_resources(&definition.required_resources))
+    }
+
+    pub fn special_ability(&self, name: &str) -> Option<&SpecialAbilityDefinition> {
+        self.special_abilities
+            .iter()
+            .find(|ability| ability.name == name)
+    }
+
+    /// Land units a unit of `unit_type` can carry, 0 for units that are not transports
+    pub fn transport_capacity(&self, unit_type: &UnitType) -> u32 {
+        if !self
+            .get(unit_type)
+            .is_some_and(|definition| definition.has_ability(naval::TRANSPORT_ABILITY))
+        {
+            return 0;
+        }
+        self.special_ability(naval::TRANSPORT_ABILITY)
+            .and_then(|ability| ability.value)
+            .map(|capacity| capacity as u32)
+            .unwrap_or(naval::DEFAULT_TRANSPORT_CAPACITY)
+    }
+
+    /// Damage multiplier a unit of `unit_type` deals to city defenses, or `None` if it cannot
+    /// bombard cities
+    pub fn bombardment_multiplier(&self, unit_type: &UnitType) -> Option<f32> {
+        if !self
+            .get(unit_type)
+            .is_some_and(|definition| definition.has_ability(combat::BOMBARDMENT_ABILITY))
+        {
+            return None;
+        }
+        Some(
+            self.special_ability(combat::BOMBARDMENT_ABILITY)
+                .and_then(|ability| ability.value)
+                .unwrap_or(combat::DEFAULT_BOMBARDMENT_MULTIPLIER),
+        )
+    }
+
+    /// Damage multiplier one unit class deals to another, 1.0 when no modifier is defined
+    pub fn class_modifier(&self, attacker_class: UnitClass, defender_class: UnitClass) -> f32 {
+        self.unit_classes
+            .iter()
+            .find(|class| class.name == attacker_class.name())
+            .and_then(|class| {
+                class
+                    .combat_modifiers
+                    .iter()
+                    .find(|modifier| modifier.vs_class == defender_class.name())
+            })
+            .map(|modifier| modifier.modifier)
+            .unwrap_or(1.0)
+    }
+
+    pub fn promotion(&self, name: &str) -> Option<&PromotionDefinition> {
+        self.promotions
+            .iter()
+            .find(|promotion| promotion.name == name)
+    }
+
+    /// Promotions `unit` may still choose, in catalog order
+    pub fn available_promotions<'a>(
+        &'a self,
+        unit: &'a MilitaryUnit,
+    ) -> impl Iterator<Item = &'a PromotionDefinition> {
+        self.promotions.iter().filter(|promotion| {
+            promotion.allows_class(unit.unit_class) && !unit.has_promotion(&promotion.name)
+        })
+    }
+
+    /// Effects of every promotion `unit` has chosen
+    pub fn promotion_effects<'a>(
+        &'a self,
+        unit: &'a MilitaryUnit,
+    ) -> impl Iterator<Item = &'a PromotionEffect> {
+        unit.promotions
+            .iter()
+            .filter_map(|name| self.promotion(name))
+            .map(|promotion| &promotion.effect)
+    }
+
+    /// Extra defense `unit`'s promotions give it on `terrain`
+    pub fn terrain_defense_bonus(&self, unit: &MilitaryUnit, terrain: &TerrainType) -> f32 {
+        self.promotion_effects(unit)
+            .map(|effect| match effect {
+                PromotionEffect::TerrainDefense {
+                    terrain: bonus_terrain,
+                    bonus,
+                } if bonus_terrain == terrain.name() => *bonus,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Extra strength `unit`'s promotions give it against units of `opponent_class`
+    pub fn class_bonus(&self, unit: &MilitaryUnit, opponent_class: UnitClass) -> f32 {
+        self.promotion_effects(unit)
+            .map(|effect| match effect {
+                PromotionEffect::ClassBonus { class, bonus } if class == opponent_class.name() => {
+                    *bonus
+                }
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// Share of its maximum health `unit`'s promotions restore every turn
+    pub fn healing_rate(&self, unit: &MilitaryUnit) -> f32 {
+        self.promotion_effects(unit)
+            .map(|effect| match effect {
+                PromotionEffect::Healing(rate) => *rate,
+                _ => 0.0,
+            })
+            .sum()
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    constants::{map_generation, terrain_stats},
-    resources::MapTile, resources::Resource as GameResource, Position, TerrainType, WorldMap,
+    constants::map_generation, resources::MapTile, resources::Resource as GameResource, Position,
+    TerrainRegistry, TerrainType, WorldMap,
 };
 use rand::Rng;
 
@@ -8,14 +8,21 @@ use rand::Rng;
 /// 1. Start with whole map as ocean
 /// 2. Add plain land masses only (no hills/other terrain yet)
 /// 3. Process land tiles to convert to appropriate coast tiles based on ocean neighbors
-pub fn generate_island_map(width: u32, height: u32, rng: &mut impl Rng) -> WorldMap {
+///
+/// Tile movement costs and defense bonuses come from `terrain_registry`.
+pub fn generate_island_map(
+    width: u32,
+    height: u32,
+    terrain_registry: &TerrainRegistry,
+    rng: &mut impl Rng,
+) -> WorldMap {
     let mut map = WorldMap::new(width, height);
 
     // STEP 1: Start with whole map as ocean tiles
-    initialize_ocean_map(&mut map);
+    initialize_ocean_map(&mut map, terrain_registry);
 
     // STEP 2: Create plain land masses only (no hills/other terrain)
-    generate_plain_landmasses(&mut map, width, height, rng);
+    generate_plain_landmasses(&mut map, width, height, terrain_registry, rng);
 
     // NOTE: Coast tile conversion will happen later in tile_passes.rs
     // The coast conversion logic is handled in the three-pass system:
@@ -30,51 +37,56 @@ pub fn generate_island_map(width: u32, height: u32, rng: &mut impl Rng) -> World
 }
 
 /// STEP 1: Initialize entire map with ocean tiles
-fn initialize_ocean_map(map: &mut WorldMap) {
+fn initialize_ocean_map(map: &mut WorldMap, terrain_registry: &TerrainRegistry) {
     for x in 0..map.width {
         for y in 0..map.height {
             let pos = Position::new(x as i32, y as i32);
             if let Some(tile) = map.get_tile_mut(pos) {
-                *tile = MapTile {
-                    terrain: TerrainType::Ocean,
-                    owner: None,
-                    city: None,
-                    resource: None,
-                    movement_cost: terrain_stats::OCEAN_MOVEMENT_COST,
-                    defense_bonus: terrain_stats::OCEAN_DEFENSE_BONUS,
-                };
+                *tile = MapTile::new(TerrainType::Ocean, terrain_registry);
             }
         }
     }
 }
 
 /// STEP 2: Generate plain land masses only (no hills or other terrain types)
-fn generate_plain_landmasses(map: &mut WorldMap, width: u32, height: u32, rng: &mut impl Rng) {
+fn generate_plain_landmasses(
+    map: &mut WorldMap,
+    width: u32,
+    height: u32,
+    terrain_registry: &TerrainRegistry,
+    rng: &mut impl Rng,
+) {
     // Generate major islands with smaller satellite islands
     let num_major_islands = rng.gen_range(map_generation::MAJOR_ISLANDS_MIN..=map_generation::MAJOR_ISLANDS_MAX);
 
     for _ in 0..num_major_islands {
-        generate_plain_island_cluster(map, width, height, rng);
+        generate_plain_island_cluster(map, width, height, terrain_registry, rng);
     }
 
     // Add scattered small islands
     let num_small_islands = rng.gen_range(map_generation::SMALL_ISLANDS_MIN..map_generation::SMALL_ISLANDS_MAX);
     for _ in 0..num_small_islands {
-        generate_small_plain_island(map, width, height, rng);
+        generate_small_plain_island(map, width, height, terrain_registry, rng);
     }
 
     // Smooth and refine the landmasses
-    smooth_plain_landmasses(map);
+    smooth_plain_landmasses(map, terrain_registry);
 }
 
-fn generate_plain_island_cluster(map: &mut WorldMap, width: u32, height: u32, rng: &mut impl Rng) {
+fn generate_plain_island_cluster(
+    map: &mut WorldMap,
+    width: u32,
+    height: u32,
+    terrain_registry: &TerrainRegistry,
+    rng: &mut impl Rng,
+) {
     // Pick a random center for the main island
     let center_x = rng.gen_range(width / map_generation::ISLAND_CENTER_MARGIN..(map_generation::ISLAND_CENTER_MARGIN - 1) * width / map_generation::ISLAND_CENTER_MARGIN);
     let center_y = rng.gen_range(height / map_generation::ISLAND_CENTER_MARGIN..(map_generation::ISLAND_CENTER_MARGIN - 1) * height / map_generation::ISLAND_CENTER_MARGIN);
 
     // Generate main island
     let main_radius = rng.gen_range(map_generation::MAJOR_ISLAND_RADIUS_MIN..map_generation::MAJOR_ISLAND_RADIUS_MAX);
-    generate_plain_island_at(map, center_x, center_y, main_radius, rng);
+    generate_plain_island_at(map, center_x, center_y, main_radius, terrain_registry, rng);
 
     // Generate satellite islands around the main one
     let num_satellites = rng.gen_range(map_generation::SATELLITE_ISLANDS_MIN..=map_generation::SATELLITE_ISLANDS_MAX);
@@ -87,17 +99,30 @@ fn generate_plain_island_cluster(map: &mut WorldMap, width: u32, height: u32, rn
 
         if sat_x >= 0.0 && sat_x < width as f32 && sat_y >= 0.0 && sat_y < height as f32 {
             let sat_radius = rng.gen_range(4..8);
-            generate_plain_island_at(map, sat_x as u32, sat_y as u32, sat_radius, rng);
+            generate_plain_island_at(
+                map,
+                sat_x as u32,
+                sat_y as u32,
+                sat_radius,
+                terrain_registry,
+                rng,
+            );
         }
     }
 }
 
-fn generate_small_plain_island(map: &mut WorldMap, width: u32, height: u32, rng: &mut impl Rng) {
+fn generate_small_plain_island(
+    map: &mut WorldMap,
+    width: u32,
+    height: u32,
+    terrain_registry: &TerrainRegistry,
+    rng: &mut impl Rng,
+) {
     let center_x = rng.gen_range(0..width);
     let center_y = rng.gen_range(0..height);
     let radius = rng.gen_range(2..5);
 
-    generate_plain_island_at(map, center_x, center_y, radius, rng);
+    generate_plain_island_at(map, center_x, center_y, radius, terrain_registry, rng);
 }
 
 fn generate_plain_island_at(
@@ -105,6 +130,7 @@ fn generate_plain_island_at(
     center_x: u32,
     center_y: u32,
     radius: u32,
+    terrain_registry: &TerrainRegistry,
     rng: &mut impl Rng,
 ) {
     let radius_f = radius as f32;
@@ -127,34 +153,19 @@ fn generate_plain_island_at(
                 if let Some(tile) = map.get_tile_mut(pos) {
                     // Create ONLY plains - no hills or other terrain types yet
                     // Hills and other terrain will be added in later generation passes
-                    *tile = MapTile {
-                        terrain: TerrainType::Plains,
-                        owner: None,
-                        city: None,
-                        resource: None,
-                        movement_cost: 1.0,
-                        defense_bonus: 0.0,
-                    };
+                    *tile = MapTile::new(TerrainType::Plains, terrain_registry);
                 }
             }
         }
     }
 }
 
-fn smooth_plain_landmasses(map: &mut WorldMap) {
+fn smooth_plain_landmasses(map: &mut WorldMap, terrain_registry: &TerrainRegistry) {
     let changes = Vec::new();
     // Apply changes
     for (pos, new_terrain) in changes {
         if let Some(tile) = map.get_tile_mut(pos) {
-            let (movement_cost, defense_bonus) = match new_terrain {
-                TerrainType::Ocean => (terrain_stats::OCEAN_MOVEMENT_COST, terrain_stats::OCEAN_DEFENSE_BONUS),
-                TerrainType::Plains => (terrain_stats::BASE_MOVEMENT_COST, terrain_stats::BASE_DEFENSE_BONUS),
-                _ => (terrain_stats::BASE_MOVEMENT_COST, terrain_stats::BASE_DEFENSE_BONUS),
-            };
-
-            tile.terrain = new_terrain;
-            tile.movement_cost = movement_cost;
-            tile.defense_bonus = defense_bonus;
+            tile.set_terrain(new_terrain, terrain_registry);
         }
     }
 }
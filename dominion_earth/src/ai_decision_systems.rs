@@ -8,7 +8,8 @@ use ai_planner::ai_coordinator::AICoordinatorSystem;
 use bevy::prelude::*;
 use core_sim::{
     resources::CurrentTurn, AIAction, ActionQueue, CivId, Civilization, CivilizationData,
-    GameState as CoreGameState, PlayerControlled, ProcessAITurn,
+    GameState as CoreGameState, GlobalEconomy, PlayerControlled, Position, ProcessAITurn,
+    Territory, WorldMap,
 };
 
 /// System to generate AI decisions only when it's an AI turn
@@ -17,12 +18,20 @@ pub fn generate_ai_decisions_on_ai_turn(
     mut game_state: ResMut<GameState>,
     mut action_queues: Query<(&mut ActionQueue, &CivId)>,
     civs: Query<&Civilization, Without<PlayerControlled>>,
+    territories: Query<(&Territory, &Position)>,
+    world_map: Res<WorldMap>,
+    global_economy: Res<GlobalEconomy>,
     current_turn: Res<CurrentTurn>,
 ) {
     // Only generate AI decisions when we receive a ProcessAITurn event
     for _ai_turn_event in ai_turn_events.read() {
-        let civilization_data = collect_civilization_data(&civs);
-        let ai_game_state = create_ai_game_state(&current_turn, civilization_data);
+        let civilization_data = collect_civilization_data(&civs, &territories);
+        let ai_game_state = create_ai_game_state(
+            &current_turn,
+            civilization_data,
+            &world_map,
+            &global_economy,
+        );
 
         let ai_decisions = game_state
             ._ai_coordinator
@@ -38,6 +47,7 @@ pub fn generate_ai_decisions_on_ai_turn(
 /// Collect civilization data for AI coordinator
 fn collect_civilization_data(
     civs: &Query<&Civilization, Without<PlayerControlled>>,
+    territories: &Query<(&Territory, &Position)>,
 ) -> std::collections::HashMap<CivId, CivilizationData> {
     let mut civilization_data = std::collections::HashMap::new();
 
@@ -45,7 +55,11 @@ fn collect_civilization_data(
         let civ_data = CivilizationData {
             civilization: civilization.clone(),
             cities: Vec::new(), // TODO: populate with actual city data when available
-            territories: Vec::new(), // TODO: populate with actual territory data when available
+            territories: territories
+                .iter()
+                .filter(|(territory, _)| territory.owner == civilization.id)
+                .map(|(territory, position)| (*position, territory.clone()))
+                .collect(),
             diplomatic_relations: Vec::new(), // TODO: populate with actual diplomatic relations when available
         };
         civilization_data.insert(civilization.id, civ_data);
@@ -58,11 +72,15 @@ fn collect_civilization_data(
 fn create_ai_game_state(
     current_turn: &CurrentTurn,
     civilization_data: std::collections::HashMap<CivId, CivilizationData>,
+    world_map: &WorldMap,
+    global_economy: &GlobalEconomy,
 ) -> CoreGameState {
     CoreGameState {
         turn: current_turn.0,
         civilizations: civilization_data,
         current_player: None,
+        resource_sites: world_map.unclaimed_resource_sites(),
+        resource_prices: global_economy.resource_prices.clone(),
     }
 }
 
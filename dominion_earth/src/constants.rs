@@ -14,6 +14,7 @@ pub mod rendering {
     /// Z-layer offsets for different entity types
     pub mod z_layers {
         pub const TERRAIN_Z: f32 = 0.0;
+        pub const MOVEMENT_OVERLAY_Z: f32 = 5.0; // Movement overlays tint terrain below capitals
         pub const CAPITAL_Z: f32 = 10.0; // Capitals render above terrain but below units
         pub const UNIT_Z: f32 = 15.0; // Units render above capitals
     }
@@ -48,6 +49,20 @@ pub mod rendering {
         pub const CAPITAL_OUTER_BORDER_HALF_HEIGHT_FACTOR: f32 = 0.5;
         pub const CAPITAL_INNER_BORDER_HALF_WIDTH_FACTOR: f32 = 0.4;
         pub const CAPITAL_INNER_BORDER_HALF_HEIGHT_FACTOR: f32 = 0.4;
+        pub const TERRITORY_EDGE_HALF_WIDTH_FACTOR: f32 = 0.5;
+        pub const TERRITORY_EDGE_HALF_HEIGHT_FACTOR: f32 = 0.5;
+    }
+
+    /// Reachable-tile overlay and path preview for the selected unit
+    pub mod movement_preview {
+        use bevy::color::{Color, Srgba};
+
+        pub const REACHABLE_TILE_COLOR: Color = Color::Srgba(Srgba::new(0.3, 0.6, 1.0, 0.3));
+        pub const REACHABLE_TILE_SIZE_FACTOR: f32 = 0.9;
+        pub const PATH_COLOR: Color = Color::Srgba(Srgba::new(1.0, 1.0, 1.0, 0.9));
+        pub const THIS_TURN_BREAKPOINT_COLOR: Color = Color::Srgba(Srgba::new(0.3, 0.6, 1.0, 1.0));
+        pub const LATER_TURN_BREAKPOINT_COLOR: Color = Color::Srgba(Srgba::new(1.0, 0.6, 0.2, 1.0));
+        pub const BREAKPOINT_RADIUS_FACTOR: f32 = 0.2;
     }
 }
 
@@ -123,6 +138,7 @@ pub mod debug {
 
 pub mod audio {
     pub const DEFAULT_MUSIC_VOLUME: f32 = 1.0;
+    pub const ERA_ADVANCED_SOUND: &str = "sounds/click.ogg";
 }
 
 pub mod settings {
@@ -104,6 +104,9 @@ pub fn setup_game(
     mut rng: ResMut<GameRng>,
     game_config: Res<GameConfig>,
     game_state: Res<GameState>,
+    unit_registry: Res<core_sim::UnitRegistry>,
+    terrain_registry: Res<core_sim::TerrainRegistry>,
+    building_registry: Res<core_sim::BuildingRegistry>,
     save_load_state: Res<crate::plugins::save_load::SaveLoadState>,
 ) {
     // Skip setup if we're loading from a save file
@@ -117,8 +120,12 @@ pub fn setup_game(
     DebugUtils::log_world_generation(game_config.random_seed);
 
     // Generate the world map
-    *world_map =
-        world_gen::generate_island_map(map::DEFAULT_WIDTH, map::DEFAULT_HEIGHT, &mut rng.0);
+    *world_map = world_gen::generate_island_map(
+        map::DEFAULT_WIDTH,
+        map::DEFAULT_HEIGHT,
+        &terrain_registry,
+        &mut rng.0,
+    );
 
     println!(
         "World map generated with size {}x{}",
@@ -135,10 +142,14 @@ pub fn setup_game(
         &mut commands,
         &mut world_map,
         &mut rng.0,
+        &unit_registry,
+        &building_registry,
         game_state.ai_only,
         game_state.total_civilizations,
     );
 
+    commands.insert_resource(core_sim::CivTerritories::from_world_map(&world_map));
+
     println!("Finished spawning civilizations");
     DebugUtils::log_world_initialization(world_map.width, world_map.height);
 }
@@ -276,3 +287,242 @@ pub fn game_update_system(
         game_state.next_turn_requested = false;
     }
 }
+
+/// Report finished battles and drop the selection if the selected unit did not survive
+pub fn handle_combat_results(
+    mut combat_results: MessageReader<core_sim::CombatResolved>,
+    mut selected_unit: ResMut<core_sim::SelectedUnit>,
+    units: Query<(), With<core_sim::MilitaryUnit>>,
+) {
+    for result in combat_results.read() {
+        DebugUtils::log_info(&format!(
+            "Combat at ({}, {}): civ {} vs civ {} - {:?} ({} defenders destroyed, attacker destroyed: {})",
+            result.position.x,
+            result.position.y,
+            result.attacker_civ.0,
+            result.defender_civ.0,
+            result.winner,
+            result.defenders_destroyed,
+            result.attacker_destroyed
+        ));
+
+        if let Some(unit_entity) = selected_unit.unit_entity {
+            if !units.contains(unit_entity) {
+                *selected_unit = core_sim::SelectedUnit::default();
+            }
+        }
+    }
+}
+
+/// Report ranged attacks and bombardments, and drop the selection if the selected unit was
+/// destroyed by one
+pub fn handle_ranged_attack_results(
+    mut attack_results: MessageReader<core_sim::RangedAttackResolved>,
+    mut selected_unit: ResMut<core_sim::SelectedUnit>,
+    units: Query<(), With<core_sim::MilitaryUnit>>,
+) {
+    for result in attack_results.read() {
+        let outcome = match &result.target {
+            core_sim::RangedAttackTarget::Units {
+                casualty_rate,
+                defenders_destroyed,
+            } => format!(
+                "defenders lost {:.0}% health ({} destroyed)",
+                casualty_rate * 100.0,
+                defenders_destroyed
+            ),
+            core_sim::RangedAttackTarget::City {
+                city_name,
+                defense_lost,
+            } => format!("{} lost {:.1} defense", city_name, defense_lost),
+        };
+        DebugUtils::log_info(&format!(
+            "Ranged attack at ({}, {}): civ {} vs civ {} - {}",
+            result.position.x,
+            result.position.y,
+            result.attacker_civ.0,
+            result.defender_civ.0,
+            outcome
+        ));
+
+        if let Some(unit_entity) = selected_unit.unit_entity {
+            if !units.contains(unit_entity) {
+                *selected_unit = core_sim::SelectedUnit::default();
+            }
+        }
+    }
+}
+
+/// Report captured cities and close the production menu of a city the player just lost
+pub fn handle_city_captures(
+    mut captured_events: MessageReader<core_sim::CityCaptured>,
+    mut selected_capital: ResMut<crate::production_input::SelectedCapital>,
+) {
+    for captured in captured_events.read() {
+        DebugUtils::log_info(&format!(
+            "{} at ({}, {}) {} civ {} (previously civ {})",
+            captured.city_name,
+            captured.position.x,
+            captured.position.y,
+            if captured.surrendered {
+                "surrendered to"
+            } else {
+                "was captured by"
+            },
+            captured.new_owner.0,
+            captured.previous_owner.0
+        ));
+
+        if selected_capital.capital_entity == Some(captured.city_entity) {
+            *selected_capital = crate::production_input::SelectedCapital::default();
+        }
+    }
+}
+
+/// Report technologies the player's civilization has discovered
+pub fn handle_technology_discoveries(
+    mut researched_events: MessageReader<core_sim::TechnologyResearched>,
+    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+) {
+    for researched in researched_events.read() {
+        if player_civs.iter().any(|civ| civ.id == researched.civ_id) {
+            DebugUtils::log_info(&format!("Discovered {}", researched.technology));
+        }
+    }
+}
+
+/// Report capitals entering a new age and play a fanfare when it is the player's capital
+pub fn handle_capital_evolutions(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut evolved_events: MessageReader<core_sim::CapitalEvolved>,
+    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+) {
+    for evolved in evolved_events.read() {
+        DebugUtils::log_info(&format!(
+            "{} (civ {}) advanced from the {:?} age to the {:?} age",
+            evolved.city_name, evolved.civ_id.0, evolved.previous_age, evolved.new_age
+        ));
+
+        if player_civs.iter().any(|civ| civ.id == evolved.civ_id) {
+            crate::audio::play_sound_effect(
+                &mut commands,
+                &asset_server,
+                crate::constants::audio::ERA_ADVANCED_SOUND,
+            );
+        }
+    }
+}
+
+/// Report newly founded cities
+pub fn handle_city_foundings(mut founded_events: MessageReader<core_sim::CityFounded>) {
+    for founded in founded_events.read() {
+        DebugUtils::log_info(&format!(
+            "{} was founded by civ {} at ({}, {})",
+            founded.city_name, founded.civ_id.0, founded.position.x, founded.position.y
+        ));
+    }
+}
+
+/// Report the player's cities growing or starving
+pub fn handle_population_changes(
+    mut population_events: MessageReader<core_sim::PopulationChanged>,
+    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+) {
+    for change in population_events.read() {
+        if !player_civs.iter().any(|civ| civ.id == change.civ_id) {
+            continue;
+        }
+
+        let verb = if change.new_population > change.previous_population {
+            "grew"
+        } else {
+            "is starving and shrank"
+        };
+        DebugUtils::log_info(&format!(
+            "{} {} from {} to {}",
+            change.city_name, verb, change.previous_population, change.new_population
+        ));
+    }
+}
+
+/// Report trade routes cut and looted by hostile units
+pub fn handle_trade_route_pillages(
+    mut pillage_events: MessageReader<core_sim::TradeRoutePillaged>,
+) {
+    for pillage in pillage_events.read() {
+        DebugUtils::log_info(&format!(
+            "Civ {} pillaged the trade route between civ {} and civ {}, looting {:.0} gold",
+            pillage.pillager.0, pillage.from_civ.0, pillage.to_civ.0, pillage.loot
+        ));
+    }
+}
+
+/// Report booms, crashes and trade disruptions on the world market
+pub fn handle_economic_events(mut economic_events: MessageReader<core_sim::EconomicEventStarted>) {
+    for event in economic_events.read() {
+        let affected = event
+            .affected_resource
+            .map_or("all trade routes", |resource| resource.name());
+        DebugUtils::log_info(&format!(
+            "{:?} hits {} (x{:.2} for {} turns)",
+            event.event_type, affected, event.magnitude, event.duration
+        ));
+    }
+}
+
+/// Report units that wasted away outside their supply lines
+pub fn handle_attrition_losses(mut attrition_events: MessageReader<core_sim::UnitLostToAttrition>) {
+    for event in attrition_events.read() {
+        DebugUtils::log_info(&format!(
+            "Civ {} lost a {} to attrition at ({}, {})",
+            event.civ_id.0, event.unit_type, event.position.x, event.position.y
+        ));
+    }
+}
+
+/// Report units that deserted or defected to another civilization
+pub fn handle_unit_mutinies(mut mutiny_events: MessageReader<core_sim::UnitMutinied>) {
+    for event in mutiny_events.read() {
+        match event.defected_to {
+            Some(new_owner) => DebugUtils::log_info(&format!(
+                "A {} of civ {} defected to civ {} at ({}, {})",
+                event.unit_type, event.civ_id.0, new_owner.0, event.position.x, event.position.y
+            )),
+            None => DebugUtils::log_info(&format!(
+                "A {} of civ {} deserted at ({}, {})",
+                event.unit_type, event.civ_id.0, event.position.x, event.position.y
+            )),
+        }
+    }
+}
+
+/// Report promotions AI units picked for themselves
+pub fn handle_unit_promotions(mut promotion_events: MessageReader<core_sim::UnitPromoted>) {
+    for event in promotion_events.read() {
+        DebugUtils::log_info(&format!(
+            "A {} of civ {} was promoted with {}",
+            event.unit_type, event.civ_id.0, event.promotion
+        ));
+    }
+}
+
+/// Report sentries that spotted an enemy and are ready for new orders
+pub fn handle_sentry_alerts(mut sentry_events: MessageReader<core_sim::SentryAlerted>) {
+    for event in sentry_events.read() {
+        DebugUtils::log_info(&format!(
+            "A {} of civ {} on sentry at ({}, {}) spotted units of civ {}",
+            event.unit_type, event.civ_id.0, event.position.x, event.position.y, event.enemy.0
+        ));
+    }
+}
+
+/// Report embarked units that went down with no transport left to carry them
+pub fn handle_units_lost_at_sea(mut lost_events: MessageReader<core_sim::UnitLostAtSea>) {
+    for event in lost_events.read() {
+        DebugUtils::log_info(&format!(
+            "A {} of civ {} was lost at sea at ({}, {})",
+            event.unit_type, event.civ_id.0, event.position.x, event.position.y
+        ));
+    }
+}
@@ -9,7 +9,3 @@ pub mod movement {
     pub const DEFAULT_MOVEMENT_COST: u32 = 1;
     pub const NO_MOVEMENT_REMAINING: u32 = 0;
 }
-
-pub mod unit_actions {
-    pub const SKIP_TURN_MOVEMENT_REMAINING: u32 = 0;
-}
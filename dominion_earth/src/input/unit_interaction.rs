@@ -3,6 +3,7 @@ use super::coordinates::convert_cursor_position_to_tile_coordinates;
 use crate::debug_utils::DebugUtils;
 use crate::game::GameState;
 use crate::production_input::SelectedCapital;
+use crate::ui::resources::RangedAttackMode;
 use crate::ui::utilities::{is_cursor_over_ui_panel, UiPanelBounds};
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
@@ -18,11 +19,12 @@ pub fn handle_player_unit_interaction(
     mut selected_capital: ResMut<SelectedCapital>,
     mut units_query: Query<(Entity, &mut core_sim::MilitaryUnit, &core_sim::Position)>,
     pending_movements_query: Query<Entity, With<core_sim::PlayerMovementOrder>>,
-    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+    player_civs: Query<(Entity, &core_sim::Civilization), With<core_sim::PlayerControlled>>,
     world_map: Res<core_sim::resources::WorldMap>,
+    terrain_registry: Res<core_sim::TerrainRegistry>,
+    occupancy: Res<core_sim::TileOccupancy>,
     game_state: Res<GameState>,
     capitals_query: Query<(Entity, &core_sim::Capital, &core_sim::Position)>,
-    player_civilizations_query: Query<Entity, With<core_sim::PlayerControlled>>,
     asset_server: Res<AssetServer>,
 ) {
     if game_state.ai_only {
@@ -33,7 +35,7 @@ pub fn handle_player_unit_interaction(
         return;
     }
 
-    let player_civ = player_civs.iter().next().unwrap();
+    let (_, player_civ) = player_civs.iter().next().unwrap();
     let player_civ_id = player_civ.id;
     let player_sound_theme = player_civ.sound_theme.clone();
 
@@ -56,6 +58,8 @@ pub fn handle_player_unit_interaction(
             &pending_movements_query,
             &selected_unit,
             &world_map,
+            &terrain_registry,
+            &occupancy,
             player_civ_id,
             &player_sound_theme,
             &asset_server,
@@ -83,13 +87,14 @@ pub fn handle_player_unit_interaction(
             &mut selected_capital,
             player_civ_id,
             &capitals_query,
-            &player_civilizations_query,
+            &player_civs,
         );
     }
 
     handle_unit_keyboard_actions(
+        &mut commands,
         &keyboard_input,
-        &mut units_query,
+        &units_query,
         &selected_unit,
         player_civ_id,
     );
@@ -103,10 +108,12 @@ fn handle_unit_movement_command(
     pending_movements_query: &Query<Entity, With<core_sim::PlayerMovementOrder>>,
     selected_unit: &ResMut<core_sim::SelectedUnit>,
     world_map: &Res<core_sim::resources::WorldMap>,
+    terrain_registry: &Res<core_sim::TerrainRegistry>,
+    occupancy: &core_sim::TileOccupancy,
     player_civ_id: CivId,
     sound_theme: &str,
     asset_server: &Res<AssetServer>,
-    player_civs: &Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+    player_civs: &Query<(Entity, &core_sim::Civilization), With<core_sim::PlayerControlled>>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -122,8 +129,31 @@ fn handle_unit_movement_command(
 
     match convert_cursor_position_to_tile_coordinates(cursor_pos, camera, camera_transform) {
         Ok(target_position) => {
+            let transport_at_target = units_query.iter().any(|(_, other, position)| {
+                *position == target_position
+                    && other.owner == player_civ_id
+                    && other.unit_class.domain() == core_sim::MovementDomain::Sea
+            });
+
             if let Some(selected_entity) = selected_unit.unit_entity {
                 if let Ok((entity, unit, current_pos)) = units_query.get_mut(selected_entity) {
+                    if unit.owner == player_civ_id
+                        && current_pos.manhattan_distance_to(&target_position)
+                            > constants::movement::MINIMUM_ADJACENT_DISTANCE
+                    {
+                        order_unit_to_distant_tile(
+                            commands,
+                            entity,
+                            &unit,
+                            *current_pos,
+                            target_position,
+                            world_map,
+                            terrain_registry,
+                            occupancy,
+                        );
+                        return;
+                    }
+
                     if unit.owner == player_civ_id && unit.can_move() {
                         if pending_movements_query.get(entity).is_ok() {
                             DebugUtils::log_info(&format!(
@@ -136,12 +166,16 @@ fn handle_unit_movement_command(
                         match validate_movement_target_and_get_cost(
                             &target_position,
                             current_pos,
+                            unit.unit_class.domain(),
+                            transport_at_target,
                             world_map,
+                            terrain_registry,
                         ) {
                             Ok(movement_cost) => {
                                 if unit.movement_remaining >= movement_cost {
                                     commands
                                         .entity(entity)
+                                        .remove::<core_sim::MovementOrder>()
                                         .insert(core_sim::PlayerMovementOrder { target_position });
 
                                     DebugUtils::log_info(&format!(
@@ -176,6 +210,43 @@ fn handle_unit_movement_command(
     }
 }
 
+/// Give the selected unit a goto order along the cheapest path to `target_position`, which it
+/// follows over as many turns as it takes, routing around tiles it cannot stack onto
+fn order_unit_to_distant_tile(
+    commands: &mut Commands,
+    entity: Entity,
+    unit: &core_sim::MilitaryUnit,
+    current_position: core_sim::Position,
+    target_position: core_sim::Position,
+    world_map: &core_sim::resources::WorldMap,
+    terrain_registry: &core_sim::TerrainRegistry,
+    occupancy: &core_sim::TileOccupancy,
+) {
+    match core_sim::plan_movement_order(
+        world_map,
+        terrain_registry,
+        current_position,
+        target_position,
+        unit.unit_class.domain(),
+        &occupancy.blocked_tiles(unit.owner),
+    ) {
+        Some(movement_order) => {
+            DebugUtils::log_info(&format!(
+                "Ordered unit {} to go to ({}, {}) - {} steps",
+                unit.id,
+                target_position.x,
+                target_position.y,
+                movement_order.path.len()
+            ));
+            commands.entity(entity).insert(movement_order);
+        }
+        None => DebugUtils::log_info(&format!(
+            "Unit {} has no path to ({}, {})",
+            unit.id, target_position.x, target_position.y
+        )),
+    }
+}
+
 fn handle_unit_selection(
     commands: &mut Commands,
     windows: &Query<&Window, With<PrimaryWindow>>,
@@ -185,7 +256,7 @@ fn handle_unit_selection(
     selected_capital: &mut ResMut<SelectedCapital>,
     player_civ_id: CivId,
     capitals_query: &Query<(Entity, &core_sim::Capital, &core_sim::Position)>,
-    player_civilizations_query: &Query<Entity, With<core_sim::PlayerControlled>>,
+    player_civs: &Query<(Entity, &core_sim::Civilization), With<core_sim::PlayerControlled>>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -202,7 +273,7 @@ fn handle_unit_selection(
     match convert_cursor_position_to_tile_coordinates(cursor_pos, camera, camera_transform) {
         Ok(click_position) => {
             let player_civilization_entities: Vec<Entity> =
-                player_civilizations_query.iter().collect();
+                player_civs.iter().map(|(entity, _)| entity).collect();
             let capital_at_position = capitals_query.iter().find(|(_, capital, capital_pos)| {
                 capital_pos.x == click_position.x
                     && capital_pos.y == click_position.y
@@ -277,27 +348,207 @@ fn handle_unit_selection(
 }
 
 fn handle_unit_keyboard_actions(
+    commands: &mut Commands,
     keyboard_input: &Res<ButtonInput<KeyCode>>,
-    units_query: &mut Query<(Entity, &mut core_sim::MilitaryUnit, &core_sim::Position)>,
+    units_query: &Query<(Entity, &mut core_sim::MilitaryUnit, &core_sim::Position)>,
     selected_unit: &ResMut<core_sim::SelectedUnit>,
     player_civ_id: CivId,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Space) {
-        if let Some(selected_entity) = selected_unit.unit_entity {
-            if let Ok((_, mut unit, _)) = units_query.get_mut(selected_entity) {
-                if unit.owner == player_civ_id {
-                    unit.movement_remaining = constants::unit_actions::SKIP_TURN_MOVEMENT_REMAINING;
-                    DebugUtils::log_info(&format!("Unit {} skipped turn", unit.id));
-                }
+    let order = if keyboard_input.just_pressed(KeyCode::KeyF) {
+        core_sim::UnitOrder::Fortify { level: 0 }
+    } else if keyboard_input.just_pressed(KeyCode::KeyG) {
+        core_sim::UnitOrder::Sentry
+    } else if keyboard_input.just_pressed(KeyCode::KeyZ) {
+        core_sim::UnitOrder::Sleep
+    } else if keyboard_input.just_pressed(KeyCode::Space) {
+        core_sim::UnitOrder::Skip
+    } else {
+        return;
+    };
+
+    let Some(selected_entity) = selected_unit.unit_entity else {
+        return;
+    };
+    let Ok((entity, unit, _)) = units_query.get(selected_entity) else {
+        return;
+    };
+    if unit.owner != player_civ_id {
+        return;
+    }
+
+    // Settlers found a city on the fortify key instead
+    if matches!(order, core_sim::UnitOrder::Fortify { .. })
+        && unit.unit_class == core_sim::UnitClass::Civilian
+    {
+        return;
+    }
+
+    give_unit_order(commands, entity, unit, order);
+}
+
+/// Give the player's `unit` a standing order if it will take it
+pub fn give_unit_order(
+    commands: &mut Commands,
+    entity: Entity,
+    unit: &core_sim::MilitaryUnit,
+    order: core_sim::UnitOrder,
+) {
+    match core_sim::validate_unit_order(unit, &order) {
+        Ok(()) => {
+            commands.entity(entity).insert(order);
+            DebugUtils::log_info(&format!("Unit {} ordered to {}", unit.id, order.name()));
+        }
+        Err(reason) => DebugUtils::log_info(reason),
+    }
+}
+
+/// Toggle ranged attack mode for the player's selected ranged unit on the R key, and while it
+/// is on, make a right click strike the clicked tile instead of moving there
+pub fn handle_ranged_attack_input(
+    mut mouse_button: ResMut<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera>>,
+    mut ranged_attack_mode: ResMut<RangedAttackMode>,
+    selected_unit: Res<core_sim::SelectedUnit>,
+    units_query: Query<(&core_sim::MilitaryUnit, &core_sim::Position)>,
+    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+    world_map: Res<core_sim::resources::WorldMap>,
+    terrain_registry: Res<core_sim::TerrainRegistry>,
+    game_state: Res<GameState>,
+    mut attack_requests: MessageWriter<core_sim::RangedAttackRequested>,
+) {
+    if game_state.ai_only {
+        return;
+    }
+
+    let ranged_unit = selected_unit.unit_entity.and_then(|entity| {
+        let (unit, position) = units_query.get(entity).ok()?;
+        (unit.has_ranged_attack() && player_civs.iter().any(|civ| civ.id == unit.owner))
+            .then_some((entity, unit, *position))
+    });
+    let Some((entity, unit, position)) = ranged_unit else {
+        if ranged_attack_mode.active {
+            ranged_attack_mode.active = false;
+        }
+        return;
+    };
+
+    if keyboard_input.just_pressed(KeyCode::KeyR) {
+        ranged_attack_mode.active = !ranged_attack_mode.active;
+        DebugUtils::log_info(if ranged_attack_mode.active {
+            "Ranged attack: right-click a target in range"
+        } else {
+            "Ranged attack cancelled"
+        });
+    }
+
+    if !ranged_attack_mode.active || !mouse_button.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let Some(cursor_pos) = window.cursor_position() else {
+        return;
+    };
+    if is_cursor_over_ui_panel(cursor_pos, &UiPanelBounds::from_window(window)) {
+        return;
+    }
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+
+    // The click picks the target, so the unit must not also move towards it
+    mouse_button.clear_just_pressed(MouseButton::Right);
+
+    let target_position =
+        match convert_cursor_position_to_tile_coordinates(cursor_pos, camera, camera_transform) {
+            Ok(target_position) => target_position,
+            Err(error_msg) => {
+                DebugUtils::log_info(error_msg);
+                return;
             }
+        };
+
+    match core_sim::validate_ranged_attack(
+        unit,
+        position,
+        target_position,
+        &world_map,
+        &terrain_registry,
+    ) {
+        Ok(()) => {
+            attack_requests.write(core_sim::RangedAttackRequested {
+                attacker: entity,
+                target_position,
+            });
+            ranged_attack_mode.active = false;
+            DebugUtils::log_info(&format!(
+                "Unit {} fires on ({}, {})",
+                unit.id, target_position.x, target_position.y
+            ));
+        }
+        Err(reason) => DebugUtils::log_info(reason),
+    }
+}
+
+/// Order the selected settler to found a city on the tile it stands on
+pub fn handle_found_city_input(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    selected_unit: Res<core_sim::SelectedUnit>,
+    units_query: Query<(&core_sim::MilitaryUnit, &core_sim::Position)>,
+    cities_query: Query<&core_sim::Position, With<core_sim::City>>,
+    player_civs: Query<&core_sim::Civilization, With<core_sim::PlayerControlled>>,
+    world_map: Res<core_sim::resources::WorldMap>,
+    unit_registry: Res<core_sim::UnitRegistry>,
+    game_state: Res<GameState>,
+) {
+    if game_state.ai_only || !keyboard_input.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    let Some(player_civ) = player_civs.iter().next() else {
+        return;
+    };
+    let Some(selected_entity) = selected_unit.unit_entity else {
+        return;
+    };
+    let Ok((unit, position)) = units_query.get(selected_entity) else {
+        return;
+    };
+
+    // Other units fortify on the same key
+    if unit.owner != player_civ.id || !core_sim::is_settler(unit, &unit_registry) {
+        return;
+    }
+
+    let city_positions: Vec<core_sim::Position> = cities_query.iter().copied().collect();
+    match core_sim::validate_city_site(&world_map, unit.owner, *position, &city_positions) {
+        Ok(()) => {
+            commands
+                .entity(selected_entity)
+                .insert(core_sim::SettleOrder { target: *position });
+            DebugUtils::log_info(&format!(
+                "Settler {} is founding a city at ({}, {})",
+                unit.id, position.x, position.y
+            ));
         }
+        Err(reason) => DebugUtils::log_info(reason),
     }
 }
 
+/// Check the adjacent tile a unit travelling in `domain` was ordered onto, letting land units
+/// board a friendly ship there when `transport_at_target` is set
 fn validate_movement_target_and_get_cost(
     target_position: &core_sim::Position,
     current_position: &core_sim::Position,
+    domain: core_sim::MovementDomain,
+    transport_at_target: bool,
     world_map: &core_sim::resources::WorldMap,
+    terrain_registry: &core_sim::TerrainRegistry,
 ) -> Result<u32, &'static str> {
     let horizontal_distance = (target_position.x - current_position.x).abs();
     let vertical_distance = (target_position.y - current_position.y).abs();
@@ -308,16 +559,18 @@ fn validate_movement_target_and_get_cost(
     }
 
     if let Some(tile) = world_map.get_tile(*target_position) {
-        match tile.terrain {
-            core_sim::TerrainType::Ocean => Err("Cannot move into ocean"),
-            _ => {
-                let movement_cost = tile.movement_cost as u32;
-                if movement_cost == constants::movement::NO_MOVEMENT_REMAINING {
-                    Ok(constants::movement::DEFAULT_MOVEMENT_COST)
-                } else {
-                    Ok(movement_cost)
-                }
+        if !terrain_registry.can_enter(&tile.terrain, domain) {
+            if domain == core_sim::MovementDomain::Land && transport_at_target {
+                return Ok(constants::movement::DEFAULT_MOVEMENT_COST);
             }
+            return Err("Cannot move into impassable terrain");
+        }
+
+        let movement_cost = terrain_registry.movement_cost(&tile.terrain) as u32;
+        if movement_cost == constants::movement::NO_MOVEMENT_REMAINING {
+            Ok(constants::movement::DEFAULT_MOVEMENT_COST)
+        } else {
+            Ok(movement_cost)
         }
     } else {
         Err("Target position is outside map boundaries")
@@ -1,6 +1,14 @@
+use crate::debug_utils::DebugUtils;
 use crate::game;
 use crate::screens::Screen;
 use bevy::prelude::*;
+use core_sim::{
+    constants::{
+        buildings::BUILDINGS_DATA_PATH, research::TECHNOLOGIES_DATA_PATH,
+        terrain::TERRAIN_DATA_PATH, units::UNITS_DATA_PATH,
+    },
+    BuildingRegistry, TechTree, TerrainRegistry, UnitRegistry,
+};
 
 pub struct CoreSimulationPlugin;
 
@@ -11,9 +19,33 @@ impl Plugin for CoreSimulationPlugin {
             .add_message::<core_sim::AITurnComplete>()
             .add_message::<core_sim::AllAITurnsComplete>()
             .add_message::<core_sim::StartPlayerTurn>()
+            .add_message::<core_sim::AttackRequested>()
+            .add_message::<core_sim::CombatResolved>()
+            .add_message::<core_sim::RangedAttackRequested>()
+            .add_message::<core_sim::RangedAttackResolved>()
+            .add_message::<core_sim::CityCaptured>()
+            .add_message::<core_sim::TechnologyResearched>()
+            .add_message::<core_sim::CapitalEvolved>()
+            .add_message::<core_sim::CityFounded>()
+            .add_message::<core_sim::PopulationChanged>()
+            .add_message::<core_sim::TradeRoutePillaged>()
+            .add_message::<core_sim::EconomicEventStarted>()
+            .add_message::<core_sim::UnitLostToAttrition>()
+            .add_message::<core_sim::UnitMutinied>()
+            .add_message::<core_sim::UnitPromoted>()
+            .add_message::<core_sim::SentryAlerted>()
+            .add_message::<core_sim::UnitLostAtSea>()
             .init_resource::<core_sim::TurnPhase>()
             .init_resource::<core_sim::TurnOrder>()
             .init_resource::<core_sim::FogOfWarMaps>()
+            .init_resource::<core_sim::DiplomaticState>()
+            .init_resource::<core_sim::GlobalEconomy>()
+            .init_resource::<core_sim::CivTerritories>()
+            .init_resource::<core_sim::TileOccupancy>()
+            .insert_resource(load_tech_tree())
+            .insert_resource(load_unit_registry())
+            .insert_resource(load_terrain_registry())
+            .insert_resource(load_building_registry())
             .add_systems(
                 OnEnter(Screen::Gameplay),
                 (
@@ -25,31 +57,155 @@ impl Plugin for CoreSimulationPlugin {
             .add_systems(
                 Update,
                 (
-                    game::initialize_active_civ_turn
-                        .run_if(resource_exists::<core_sim::resources::ActiveCivTurn>),
-                    game::initialize_turn_order.run_if(resource_exists::<core_sim::TurnOrder>),
-                    core_sim::spawn_action_queues_for_new_civilizations,
-                    crate::ai_decision_systems::generate_ai_decisions_on_ai_turn,
-                    core_sim::process_civilization_action_queues,
-                    core_sim::initialize_production_queues,
-                    core_sim::handle_player_production_orders,
-                    core_sim::handle_skip_production,
-                    core_sim::execute_movement_orders,
-                    core_sim::execute_ai_movement_orders,
-                    core_sim::clear_completed_movement_orders,
-                    core_sim::check_player_actions_complete,
-                    core_sim::handle_turn_advance_requests,
-                    core_sim::handle_ai_turn_processing,
-                    core_sim::handle_ai_turn_completion,
-                    core_sim::handle_turn_transition_complete,
-                    core_sim::auto_advance_turn_system,
+                    (
+                        game::initialize_active_civ_turn
+                            .run_if(resource_exists::<core_sim::resources::ActiveCivTurn>),
+                        game::initialize_turn_order.run_if(resource_exists::<core_sim::TurnOrder>),
+                        core_sim::spawn_action_queues_for_new_civilizations,
+                        core_sim::initialize_diplomatic_relations,
+                        crate::ai_decision_systems::generate_ai_decisions_on_ai_turn,
+                        core_sim::process_civilization_action_queues,
+                    )
+                        .chain(),
+                    (
+                        core_sim::initialize_production_queues,
+                        core_sim::handle_player_production_orders,
+                        core_sim::handle_skip_production,
+                    )
+                        .chain(),
+                    (
+                        core_sim::update_tile_occupancy,
+                        core_sim::convert_hostile_moves_into_attacks,
+                        core_sim::resolve_attack_requests,
+                        core_sim::resolve_ranged_attacks,
+                        core_sim::execute_movement_orders,
+                        core_sim::follow_movement_orders,
+                        core_sim::advance_settlers,
+                        core_sim::clear_completed_movement_orders,
+                        core_sim::wake_sentries,
+                        core_sim::carry_embarked_units,
+                        core_sim::sync_tile_contents,
+                    )
+                        .chain(),
+                    (
+                        core_sim::check_player_actions_complete,
+                        core_sim::handle_turn_advance_requests,
+                        core_sim::handle_ai_turn_processing,
+                        core_sim::handle_ai_turn_completion,
+                        core_sim::update_diplomacy,
+                        core_sim::resolve_sieges,
+                        core_sim::update_territory,
+                        core_sim::update_resource_access,
+                        (
+                            core_sim::update_unit_logistics,
+                            core_sim::update_unit_loyalty,
+                            core_sim::promote_ai_units,
+                            core_sim::heal_promoted_units,
+                            core_sim::advance_unit_orders,
+                        )
+                            .chain(),
+                        core_sim::grow_populations,
+                        core_sim::update_market,
+                        core_sim::update_trade_routes,
+                        core_sim::update_civilization_economies,
+                        core_sim::advance_research,
+                        core_sim::evolve_capitals,
+                        core_sim::handle_turn_transition_complete,
+                        core_sim::auto_advance_turn_system,
+                    )
+                        .chain(),
                 )
                     .chain()
                     .run_if(in_state(Screen::Gameplay)),
             )
             .add_systems(
                 Update,
-                core_sim::update_fog_of_war.run_if(in_state(Screen::Gameplay)),
+                (
+                    core_sim::update_fog_of_war,
+                    game::handle_combat_results.after(core_sim::resolve_attack_requests),
+                    game::handle_ranged_attack_results.after(core_sim::resolve_ranged_attacks),
+                    game::handle_city_captures.after(core_sim::resolve_sieges),
+                    game::handle_technology_discoveries.after(core_sim::advance_research),
+                    game::handle_capital_evolutions.after(core_sim::evolve_capitals),
+                    game::handle_city_foundings.after(core_sim::advance_settlers),
+                    game::handle_population_changes.after(core_sim::grow_populations),
+                    game::handle_trade_route_pillages.after(core_sim::update_trade_routes),
+                    game::handle_economic_events.after(core_sim::update_market),
+                    game::handle_attrition_losses.after(core_sim::update_unit_logistics),
+                    game::handle_unit_mutinies.after(core_sim::update_unit_loyalty),
+                    game::handle_unit_promotions.after(core_sim::promote_ai_units),
+                    game::handle_sentry_alerts.after(core_sim::wake_sentries),
+                    game::handle_units_lost_at_sea.after(core_sim::carry_embarked_units),
+                )
+                    .run_if(in_state(Screen::Gameplay)),
+            );
+    }
+}
+
+fn load_tech_tree() -> TechTree {
+    match TechTree::load_from_ron(TECHNOLOGIES_DATA_PATH) {
+        Ok(tech_tree) => {
+            println!(
+                "Successfully loaded {} technologies from RON file",
+                tech_tree.technologies.len()
+            );
+            tech_tree
+        }
+        Err(e) => {
+            println!("Failed to load technology data: {}", e);
+            DebugUtils::log_info(&format!("Failed to load technology data: {}", e));
+            TechTree::default()
+        }
+    }
+}
+
+fn load_unit_registry() -> UnitRegistry {
+    match UnitRegistry::load_from_ron(UNITS_DATA_PATH) {
+        Ok(unit_registry) => {
+            println!(
+                "Successfully loaded {} unit types from RON file",
+                unit_registry.unit_types.len()
+            );
+            unit_registry
+        }
+        Err(e) => {
+            println!("Failed to load unit data: {}", e);
+            DebugUtils::log_info(&format!("Failed to load unit data: {}", e));
+            UnitRegistry::default()
+        }
+    }
+}
+
+fn load_terrain_registry() -> TerrainRegistry {
+    match TerrainRegistry::load_from_ron(TERRAIN_DATA_PATH) {
+        Ok(terrain_registry) => {
+            println!(
+                "Successfully loaded {} terrain types from RON file",
+                terrain_registry.terrain_types.len()
+            );
+            terrain_registry
+        }
+        Err(e) => {
+            println!("Failed to load terrain data: {}", e);
+            DebugUtils::log_info(&format!("Failed to load terrain data: {}", e));
+            TerrainRegistry::default()
+        }
+    }
+}
+
+fn load_building_registry() -> BuildingRegistry {
+    match BuildingRegistry::load_from_ron(BUILDINGS_DATA_PATH) {
+        Ok(building_registry) => {
+            println!(
+                "Successfully loaded {} building types from RON file",
+                building_registry.building_types.len()
             );
+            building_registry
+        }
+        Err(e) => {
+            println!("Failed to load building data: {}", e);
+            DebugUtils::log_info(&format!("Failed to load building data: {}", e));
+            BuildingRegistry::default()
+        }
     }
 }
@@ -15,7 +15,9 @@ impl Plugin for InputHandlingPlugin {
                     input::handle_mouse_input,
                     input::handle_tile_selection_on_mouse_click,
                     input::handle_tile_hover_on_mouse_move,
+                    input::handle_ranged_attack_input.before(input::handle_player_unit_interaction),
                     input::handle_player_unit_interaction,
+                    input::handle_found_city_input,
                 )
                     .run_if(in_state(Screen::Gameplay)),
             )
@@ -50,7 +50,12 @@ impl Plugin for RenderingPlugin {
                     rendering::units::update_unit_sprites,
                     rendering::capitals::update_capital_sprites,
                     rendering::capitals::update_animated_capital_sprites,
+                    rendering::capitals::spawn_city_sprites,
                     rendering::borders::render_civilization_borders,
+                    rendering::borders::render_territory_borders,
+                    // Movement preview for the selected unit
+                    rendering::movement_preview::update_reachable_tile_overlay,
+                    rendering::movement_preview::render_movement_path_preview,
                     // Fog of War rendering
                     rendering::fog_of_war::apply_fog_of_war_to_tiles,
                     rendering::fog_of_war::hide_entities_in_fog,
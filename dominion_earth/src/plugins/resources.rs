@@ -18,6 +18,7 @@ impl Plugin for ResourcesPlugin {
             .init_resource::<ui::SelectedTile>()
             .init_resource::<ui::HoveredTile>()
             .init_resource::<ui::LastLoggedTile>()
+            .init_resource::<ui::RangedAttackMode>()
             .insert_resource(ui::UiSystemResource::new(Box::new(ui::BevyUiSystem)))
             // Core Simulation Resources
             .init_resource::<CurrentTurn>()
@@ -2,11 +2,17 @@ use bevy::audio::{GlobalVolume, Volume};
 use bevy::prelude::*;
 use core_sim::components::military::FacingDirection;
 use core_sim::components::turn_phases::TurnPhase;
-use core_sim::resources::{ActiveCivTurn, CurrentTurn, GameConfig, MapTile, Resource, WorldMap};
+use core_sim::resources::{
+    ActiveCivTurn, CurrentTurn, DiplomaticEvent, DiplomaticEventType, DiplomaticProposal,
+    DiplomaticState, EconomicEvent, EconomicEventType, GameConfig, GlobalEconomy, GlobalTradeRoute,
+    MapTile, Negotiation, Resource, WorldMap,
+};
 use core_sim::{
-    Building, BuildingType, Capital, CapitalAge, City, CivId, CivPersonality, CivStats,
-    Civilization, Direction, Economy, FogOfWarMaps, Military, MilitaryUnit, PlayerControlled,
-    PlayerMovementOrder, Position, ProvidesVision, Technologies, TerrainType, TradeRoute, UnitType,
+    BorderPressure, Building, BuildingRegistry, BuildingType, Capital, CapitalAge, City,
+    CityGrowth, CivId, CivPersonality, CivStats, Civilization, DiplomaticRelation, Direction,
+    Economy, Embarked, FogOfWarMaps, Military, MilitaryUnit, MovementOrder, PlayerControlled,
+    PlayerMovementOrder, Position, ProvidesVision, SettleOrder, Siege, Technologies, TerrainType,
+    Territory, TradeRoute, Transport, Treaty, UnitClass, UnitOrder, UnitRegistry, UnitType,
     VisibilityMap, VisibilityState,
 };
 use moonshine_save::prelude::*;
@@ -51,16 +57,26 @@ impl Plugin for SaveLoadPlugin {
             .register_type::<TradeRoute>()
             .register_type::<MilitaryUnit>()
             .register_type::<UnitType>()
+            .register_type::<UnitClass>()
             .register_type::<FacingDirection>()
             .register_type::<TerrainType>()
             .register_type::<City>()
             .register_type::<Capital>()
+            .register_type::<Siege>()
+            .register_type::<BorderPressure>()
+            .register_type::<CityGrowth>()
+            .register_type::<Territory>()
             .register_type::<CapitalAge>()
             .register_type::<Building>()
             .register_type::<BuildingType>()
             .register_type::<CivStats>()
             .register_type::<PlayerControlled>()
             .register_type::<PlayerMovementOrder>()
+            .register_type::<MovementOrder>()
+            .register_type::<SettleOrder>()
+            .register_type::<UnitOrder>()
+            .register_type::<Transport>()
+            .register_type::<Embarked>()
             .register_type::<ProvidesVision>()
             .register_type::<WorldMap>()
             .register_type::<CurrentTurn>()
@@ -69,6 +85,17 @@ impl Plugin for SaveLoadPlugin {
             .register_type::<GameConfig>()
             .register_type::<Resource>()
             .register_type::<MapTile>()
+            .register_type::<DiplomaticState>()
+            .register_type::<DiplomaticRelation>()
+            .register_type::<Treaty>()
+            .register_type::<Negotiation>()
+            .register_type::<DiplomaticProposal>()
+            .register_type::<DiplomaticEvent>()
+            .register_type::<DiplomaticEventType>()
+            .register_type::<GlobalEconomy>()
+            .register_type::<GlobalTradeRoute>()
+            .register_type::<EconomicEvent>()
+            .register_type::<EconomicEventType>()
             .register_type::<FogOfWarMaps>()
             .register_type::<VisibilityMap>()
             .register_type::<VisibilityState>()
@@ -127,6 +154,8 @@ fn handle_save_requests(
                 .include_resource::<TurnPhase>()
                 .include_resource::<GameConfig>()
                 .include_resource::<FogOfWarMaps>()
+                .include_resource::<DiplomaticState>()
+                .include_resource::<GlobalEconomy>()
                 .include_resource::<SavedMusicVolume>(),
         );
 
@@ -303,7 +332,12 @@ fn refresh_fog_of_war_after_load(
     save_state.fog_of_war_needs_refresh = false;
 }
 
-fn respawn_ui_after_load(mut commands: Commands, mut save_state: ResMut<SaveLoadState>) {
+fn respawn_ui_after_load(
+    mut commands: Commands,
+    mut save_state: ResMut<SaveLoadState>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
+) {
     if !save_state.ui_needs_respawn {
         return;
     }
@@ -312,7 +346,7 @@ fn respawn_ui_after_load(mut commands: Commands, mut save_state: ResMut<SaveLoad
 
     crate::ui::top_panel::spawn_top_panel(commands.reborrow());
     crate::ui::right_panel::spawn_right_panel(commands.reborrow());
-    crate::ui::left_panel::spawn_left_panel(commands.reborrow());
+    crate::ui::left_panel::spawn_left_panel(commands.reborrow(), unit_registry, building_registry);
 
     save_state.ui_needs_respawn = false;
     info!("UI respawn complete after load");
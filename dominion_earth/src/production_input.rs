@@ -56,7 +56,7 @@ pub fn handle_production_input(
                     production_orders.write(PlayerProductionOrder {
                         capital_entity,
                         civ_entity,
-                        item: ProductionItem::Unit(UnitType::Infantry),
+                        item: ProductionItem::Unit(UnitType::new("Infantry")),
                     });
                 }
             }
@@ -68,7 +68,7 @@ pub fn handle_production_input(
                     production_orders.write(PlayerProductionOrder {
                         capital_entity,
                         civ_entity,
-                        item: ProductionItem::Unit(UnitType::Archer),
+                        item: ProductionItem::Unit(UnitType::new("Archer")),
                     });
                 }
             }
@@ -80,7 +80,7 @@ pub fn handle_production_input(
                     production_orders.write(PlayerProductionOrder {
                         capital_entity,
                         civ_entity,
-                        item: ProductionItem::Unit(UnitType::Cavalry),
+                        item: ProductionItem::Unit(UnitType::new("Cavalry")),
                     });
                 }
             }
@@ -5,7 +5,7 @@ use bevy_ecs_tilemap::prelude::*;
 use core_sim::components::{
     city::Capital, civilization::Civilization, military::MilitaryUnit, position::Position,
 };
-use core_sim::{FogOfWarMaps, PlayerControlled, VisibilityState};
+use core_sim::{CivTerritories, FogOfWarMaps, PlayerControlled, VisibilityState};
 
 pub fn render_civilization_borders(
     mut gizmos: Gizmos,
@@ -119,3 +119,74 @@ pub fn render_civilization_borders(
         }
     }
 }
+
+/// Outline each civilization's territory, drawing only the tile edges that face another owner
+pub fn render_territory_borders(
+    mut gizmos: Gizmos,
+    fog_of_war: Res<FogOfWarMaps>,
+    civ_territories: Res<CivTerritories>,
+    player_query: Query<&Civilization, With<PlayerControlled>>,
+    tilemap_q: Query<(
+        &TileStorage,
+        &TilemapSize,
+        &TilemapTileSize,
+        &TilemapGridSize,
+        &TilemapType,
+        &TilemapAnchor,
+    )>,
+    civilizations: Query<&Civilization>,
+) {
+    let Ok((_tile_storage, map_size, tile_size, grid_size, map_type, anchor)) = tilemap_q.single()
+    else {
+        return;
+    };
+
+    let player_civ_id = player_query.single().ok().map(|civ| civ.id);
+    let visibility_map = player_civ_id.and_then(|id| fog_of_war.get(id));
+
+    for civ in civilizations.iter() {
+        let border_color = Color::srgb(civ.color[0], civ.color[1], civ.color[2]);
+        let half_width = tile_size.x * borders::TERRITORY_EDGE_HALF_WIDTH_FACTOR;
+        let half_height = tile_size.y * borders::TERRITORY_EDGE_HALF_HEIGHT_FACTOR;
+
+        for position in civ_territories.tiles_of(civ.id) {
+            let should_render = if let Some(map) = visibility_map {
+                // Player mode: only show borders on tiles the player has seen
+                let tile_visibility = map.get(*position).unwrap_or(VisibilityState::Unexplored);
+                Some(civ.id) == player_civ_id
+                    || !matches!(tile_visibility, VisibilityState::Unexplored)
+            } else {
+                // AI-only mode: show all borders
+                true
+            };
+
+            if !should_render {
+                continue;
+            }
+
+            let center = calculate_world_position_for_gizmo(
+                *position, map_size, tile_size, grid_size, map_type, anchor,
+            )
+            .truncate();
+
+            let top_left = center + Vec2::new(-half_width, half_height);
+            let top_right = center + Vec2::new(half_width, half_height);
+            let bottom_left = center + Vec2::new(-half_width, -half_height);
+            let bottom_right = center + Vec2::new(half_width, -half_height);
+
+            let edges = [
+                (0, 1, top_left, top_right),
+                (0, -1, bottom_left, bottom_right),
+                (1, 0, bottom_right, top_right),
+                (-1, 0, bottom_left, top_left),
+            ];
+
+            for (dx, dy, start, end) in edges {
+                let neighbor = Position::new(position.x + dx, position.y + dy);
+                if !civ_territories.owns(civ.id, neighbor) {
+                    gizmos.line_2d(start, end, border_color);
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,10 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 use bevy_ecs_tilemap::tiles::AnimatedTile;
 use core_sim::tile::tile_assets::TileAssets;
-use core_sim::components::{city::Capital, position::Position};
+use core_sim::components::{
+    city::{Capital, City},
+    position::Position,
+};
 use crate::constants::rendering::{animation, z_layers};
 use crate::screens::{LoadingState, Screen};
 use super::tilemap::spawn_entity_on_tile;
@@ -227,6 +230,58 @@ pub fn spawn_capital_sprites(
     }
 }
 
+/// Give cities founded after setup, which have no capital age to animate, a static sprite
+pub fn spawn_city_sprites(
+    mut commands: Commands,
+    tile_assets: Option<Res<TileAssets>>,
+    tilemap_q: Query<(
+        &TileStorage,
+        &TilemapSize,
+        &TilemapTileSize,
+        &TilemapGridSize,
+        &TilemapType,
+        &TilemapAnchor,
+    )>,
+    cities: Query<
+        (Entity, &Position),
+        (
+            With<City>,
+            Without<Capital>,
+            Without<core_sim::components::rendering::SpriteEntityReference>,
+        ),
+    >,
+) {
+    let Some(tile_assets) = tile_assets else {
+        return;
+    };
+
+    let Ok((tile_storage, map_size, tile_size, grid_size, map_type, anchor)) = tilemap_q.single()
+    else {
+        return;
+    };
+
+    for (city_entity, pos) in cities.iter() {
+        if let Some(sprite_entity) = spawn_entity_on_tile(
+            &mut commands,
+            &tile_assets,
+            tile_storage,
+            map_size,
+            tile_size,
+            grid_size,
+            map_type,
+            anchor,
+            *pos,
+            core_sim::constants::sprite_indices::CAPITAL_ANCIENT,
+            z_layers::CAPITAL_Z,
+        ) {
+            commands
+                .entity(city_entity)
+                .insert(core_sim::components::rendering::SpriteEntityReference { sprite_entity });
+        }
+    }
+}
+
+/// Swap a capital's sprite when its age (and with it `sprite_index`) changes
 pub fn update_capital_sprites(
     mut commands: Commands,
     tile_assets: Option<Res<TileAssets>>,
@@ -238,7 +293,14 @@ pub fn update_capital_sprites(
         &TilemapType,
         &TilemapAnchor,
     )>,
-    capitals: Query<(&Capital, &Position), bevy::ecs::query::Changed<Capital>>,
+    mut capitals: Query<
+        (
+            &Capital,
+            &Position,
+            &mut core_sim::components::rendering::SpriteEntityReference,
+        ),
+        bevy::ecs::query::Changed<Capital>,
+    >,
 ) {
     // Wait for TileAssets to be loaded
     let Some(tile_assets) = tile_assets else {
@@ -250,8 +312,10 @@ pub fn update_capital_sprites(
         return;
     };
 
-    for (capital, pos) in capitals.iter() {
-        spawn_entity_on_tile(
+    for (capital, pos, mut sprite_reference) in capitals.iter_mut() {
+        commands.entity(sprite_reference.sprite_entity).despawn();
+
+        if let Some(sprite_entity) = spawn_animated_capital_sprite(
             &mut commands,
             &tile_assets,
             tile_storage,
@@ -261,9 +325,11 @@ pub fn update_capital_sprites(
             map_type,
             anchor,
             *pos,
-            capital.sprite_index as usize,
+            capital.sprite_index,
             z_layers::CAPITAL_Z,
-        );
+        ) {
+            sprite_reference.sprite_entity = sprite_entity;
+        }
     }
 }
 
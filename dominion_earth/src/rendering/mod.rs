@@ -2,6 +2,7 @@ pub mod borders;
 pub mod capitals;
 pub mod common;
 pub mod fog_of_war;
+pub mod movement_preview;
 pub mod tilemap;
 pub mod units;
 
@@ -9,5 +10,6 @@ pub use borders::*;
 pub use capitals::*;
 pub use common::*;
 pub use fog_of_war::*;
+pub use movement_preview::*;
 pub use tilemap::*;
 pub use units::*;
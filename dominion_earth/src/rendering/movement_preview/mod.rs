@@ -0,0 +1,211 @@
+use super::common::calculate_world_position_for_gizmo;
+use crate::constants::rendering::{movement_preview, z_layers};
+use crate::screens::Screen;
+use crate::ui::resources::HoveredTile;
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use core_sim::{
+    hostile_zone_of_control, path_arrival_turns, plan_movement_order, reachable_tiles,
+    Civilization, DiplomaticState, MilitaryUnit, PlayerControlled, Position, SelectedUnit,
+    TerrainRegistry, TileOccupancy, WorldMap,
+};
+
+/// Marker for the tinted sprites covering the tiles the selected unit can reach this turn
+#[derive(Component, Debug, Clone)]
+pub struct ReachableTileOverlay;
+
+/// Path planned for the last unit, start, hovered tile and movement points previewed, so
+/// the search only reruns when one of them or the occupancy of the map changes
+#[derive(Default)]
+pub struct PlannedPathPreview {
+    key: Option<(Entity, Position, Position, u32)>,
+    path: Vec<Position>,
+    arrival_turns: Vec<u32>,
+}
+
+/// The selected unit and its position, if it belongs to the player
+fn selected_player_unit<'a>(
+    selected_unit: &SelectedUnit,
+    units: &'a Query<(&MilitaryUnit, &Position)>,
+    player_civs: &Query<&Civilization, With<PlayerControlled>>,
+) -> Option<(&'a MilitaryUnit, Position)> {
+    let (unit, position) = units.get(selected_unit.unit_entity?).ok()?;
+    player_civs
+        .iter()
+        .any(|civ| civ.id == unit.owner)
+        .then_some((unit, *position))
+}
+
+/// Tint the tiles the player's selected unit can still reach this turn, rebuilding the
+/// overlay whenever the selection, the unit's position or its movement points change, or
+/// units elsewhere on the map move
+pub fn update_reachable_tile_overlay(
+    mut commands: Commands,
+    selected_unit: Res<SelectedUnit>,
+    units: Query<(&MilitaryUnit, &Position)>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    occupancy: Res<TileOccupancy>,
+    diplomatic_state: Res<DiplomaticState>,
+    tilemap_q: Query<(
+        &TilemapSize,
+        &TilemapTileSize,
+        &TilemapGridSize,
+        &TilemapType,
+        &TilemapAnchor,
+    )>,
+    overlays: Query<Entity, With<ReachableTileOverlay>>,
+    mut displayed_for: Local<Option<(Entity, Position, u32)>>,
+) {
+    let Ok((map_size, tile_size, grid_size, map_type, anchor)) = tilemap_q.single() else {
+        return;
+    };
+
+    let unit = selected_player_unit(&selected_unit, &units, &player_civs);
+    let shown = selected_unit
+        .unit_entity
+        .zip(unit)
+        .map(|(entity, (unit, position))| (entity, position, unit.movement_remaining));
+    if *displayed_for == shown && !occupancy.is_changed() {
+        return;
+    }
+    *displayed_for = shown;
+
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+
+    let Some((unit, start)) = unit else {
+        return;
+    };
+
+    let overlay_size =
+        Vec2::new(tile_size.x, tile_size.y) * movement_preview::REACHABLE_TILE_SIZE_FACTOR;
+    for position in reachable_tiles(
+        &world_map,
+        &terrain_registry,
+        start,
+        unit.unit_class.domain(),
+        unit.movement_remaining,
+        &occupancy.blocked_tiles(unit.owner),
+        &hostile_zone_of_control(&occupancy, &diplomatic_state, unit.owner),
+    )
+    .into_keys()
+    {
+        if position == start {
+            continue;
+        }
+
+        let center = calculate_world_position_for_gizmo(
+            position, map_size, tile_size, grid_size, map_type, anchor,
+        )
+        .truncate();
+        commands.spawn((
+            ReachableTileOverlay,
+            Sprite::from_color(movement_preview::REACHABLE_TILE_COLOR, overlay_size),
+            Transform::from_translation(center.extend(z_layers::MOVEMENT_OVERLAY_Z)),
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Draw the path the player's selected unit would take to the hovered tile, circling the
+/// tile where each turn's movement ends
+pub fn render_movement_path_preview(
+    mut gizmos: Gizmos,
+    selected_unit: Res<SelectedUnit>,
+    hovered_tile: Res<HoveredTile>,
+    units: Query<(&MilitaryUnit, &Position)>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    occupancy: Res<TileOccupancy>,
+    diplomatic_state: Res<DiplomaticState>,
+    tilemap_q: Query<(
+        &TilemapSize,
+        &TilemapTileSize,
+        &TilemapGridSize,
+        &TilemapType,
+        &TilemapAnchor,
+    )>,
+    mut planned: Local<PlannedPathPreview>,
+) {
+    let Ok((map_size, tile_size, grid_size, map_type, anchor)) = tilemap_q.single() else {
+        return;
+    };
+    let Some(entity) = selected_unit.unit_entity else {
+        return;
+    };
+    let Some((unit, start)) = selected_player_unit(&selected_unit, &units, &player_civs) else {
+        return;
+    };
+    let Some(destination) = hovered_tile.position else {
+        return;
+    };
+
+    let key = Some((entity, start, destination, unit.movement_remaining));
+    if planned.key != key || occupancy.is_changed() {
+        let path = plan_movement_order(
+            &world_map,
+            &terrain_registry,
+            start,
+            destination,
+            unit.unit_class.domain(),
+            &occupancy.blocked_tiles(unit.owner),
+        )
+        .map(|order| order.path)
+        .unwrap_or_default();
+        planned.arrival_turns = path_arrival_turns(
+            &world_map,
+            &terrain_registry,
+            unit,
+            start,
+            &path,
+            &hostile_zone_of_control(&occupancy, &diplomatic_state, unit.owner),
+        );
+        planned.path = path;
+        planned.key = key;
+    }
+    if planned.path.is_empty() {
+        return;
+    }
+    let PlannedPathPreview {
+        path,
+        arrival_turns,
+        ..
+    } = &*planned;
+    let world_position = |position: Position| {
+        calculate_world_position_for_gizmo(
+            position, map_size, tile_size, grid_size, map_type, anchor,
+        )
+        .truncate()
+    };
+
+    gizmos.linestrip_2d(
+        std::iter::once(start)
+            .chain(path.iter().copied())
+            .map(world_position),
+        movement_preview::PATH_COLOR,
+    );
+
+    for (index, (step, turn)) in path.iter().zip(arrival_turns).enumerate() {
+        let ends_turn = arrival_turns
+            .get(index + 1)
+            .is_none_or(|next_turn| next_turn > turn);
+        if !ends_turn {
+            continue;
+        }
+
+        let color = if *turn == 1 {
+            movement_preview::THIS_TURN_BREAKPOINT_COLOR
+        } else {
+            movement_preview::LATER_TURN_BREAKPOINT_COLOR
+        };
+        gizmos.circle_2d(
+            world_position(*step),
+            tile_size.x * movement_preview::BREAKPOINT_RADIUS_FACTOR,
+            color,
+        );
+    }
+}
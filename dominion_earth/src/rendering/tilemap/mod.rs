@@ -5,12 +5,13 @@ use crate::screens::{LoadingState, Screen};
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 use core_sim::tile::tile_assets::TileAssets;
-use core_sim::WorldMap;
+use core_sim::{TerrainRegistry, WorldMap};
 
 pub fn setup_tilemap(
     mut commands: Commands,
     tile_assets: Option<Res<TileAssets>>,
     mut world_map: ResMut<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
     tilemap_id_resource: Option<Res<TilemapIdResource>>,
 ) {
     // Only run once - if tilemap already exists, skip
@@ -35,6 +36,7 @@ pub fn setup_tilemap(
         tilemap_id,
         &*tile_assets,
         &mut *world_map,
+        &terrain_registry,
     );
 
     let tile_size = TilemapTileSize {
@@ -144,10 +144,10 @@ fn spawn_unit_sprite(
     pos: &Position,
     transforms: &mut Query<&mut Transform>,
 ) {
-    let sprite_index = match unit.unit_type {
-        core_sim::components::military::UnitType::Infantry => tile_assets.ancient_infantry_index,
-        core_sim::components::military::UnitType::Archer => tile_assets.ancient_infantry_index,
-        core_sim::components::military::UnitType::Cavalry => tile_assets.ancient_infantry_index,
+    let sprite_index = match unit.unit_type.name() {
+        "Infantry" => tile_assets.ancient_infantry_index,
+        "Archer" => tile_assets.ancient_infantry_index,
+        "Cavalry" => tile_assets.ancient_infantry_index,
         _ => tile_assets.ancient_infantry_index,
     };
 
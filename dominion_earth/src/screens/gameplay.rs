@@ -134,6 +134,7 @@ fn reset_all_game_resources(
     mut active_civ_turn: ResMut<core_sim::resources::ActiveCivTurn>,
     mut turn_phase: ResMut<core_sim::TurnPhase>,
     mut fog_of_war: ResMut<core_sim::FogOfWarMaps>,
+    mut diplomatic_state: ResMut<core_sim::DiplomaticState>,
     mut turn_advance: ResMut<core_sim::resources::TurnAdvanceRequest>,
     mut game_state: ResMut<crate::game::GameState>,
     mut player_actions: ResMut<core_sim::PlayerActionsComplete>,
@@ -146,6 +147,7 @@ fn reset_all_game_resources(
     *active_civ_turn = core_sim::resources::ActiveCivTurn::default();
     *turn_phase = core_sim::TurnPhase::default();
     *fog_of_war = core_sim::FogOfWarMaps::new();
+    *diplomatic_state = core_sim::DiplomaticState::default();
     *turn_advance = core_sim::resources::TurnAdvanceRequest::default();
     *player_actions = core_sim::PlayerActionsComplete::default();
     *selected_capital = crate::production_input::SelectedCapital::default();
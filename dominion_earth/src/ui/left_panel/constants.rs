@@ -46,8 +46,13 @@ pub const PRODUCTION_MENU_MAX_HEIGHT: Val = Val::Px(500.0);
 pub const NEXT_TURN_BUTTON_HEIGHT: Val = Val::Px(50.0);
 pub const GAME_PANEL_MIN_HEIGHT: Val = Val::Px(200.0);
 
+/// Unit types offered in the production menu
+pub const PRODUCTION_MENU_UNIT_TYPES: [&str; 4] = ["Infantry", "Archer", "Cavalry", "Galley"];
+
 // Button text constants
 pub const BUTTON_TEXT_END_TURN: &str = "End Turn";
 pub const BUTTON_TEXT_START_YOUR_TURN: &str = "Start Your Turn";
 pub const BUTTON_TEXT_NEXT_TURN: &str = "Next Turn";
 pub const BUTTON_TEXT_PROCESSING: &str = "Processing...";
+pub const BUTTON_TEXT_RANGED_ATTACK: &str = "Ranged Attack (R)";
+pub const BUTTON_TEXT_CHOOSE_TARGET: &str = "Right-click a Target";
@@ -3,7 +3,7 @@ pub mod production_section;
 pub mod unit_info_section;
 
 use bevy::prelude::*;
-use core_sim::RequestTurnAdvance;
+use core_sim::{BuildingRegistry, RequestTurnAdvance, UnitRegistry};
 use moonshine_save::prelude::*;
 
 use crate::ui::constants::display_layout;
@@ -28,8 +28,16 @@ pub struct NextTurnButton;
 pub struct NextTurnButtonText;
 
 /// Spawns the main left panel with all sections
-pub fn spawn_left_panel(mut commands: Commands) {
-    let production_panel = production_section::spawn_production_menu_panel(&mut commands);
+pub fn spawn_left_panel(
+    mut commands: Commands,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
+) {
+    let production_panel = production_section::spawn_production_menu_panel(
+        &mut commands,
+        &unit_registry,
+        &building_registry,
+    );
     let unit_info_panel = unit_info_section::spawn_unit_info_panel(&mut commands);
 
     commands
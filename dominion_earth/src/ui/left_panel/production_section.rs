@@ -1,7 +1,11 @@
 use bevy::prelude::*;
-use core_sim::{Civilization, PlayerProductionOrder, ProductionQueue, UnitType};
+use core_sim::{
+    BuildingRegistry, BuildingType, City, Civilization, PlayerProductionOrder, Position,
+    ProductionItem, ProductionQueue, TechTree, TerrainRegistry, UnitRegistry, UnitType, WorldMap,
+};
 
 use super::constants::*;
+use super::unit_info_section::{PromotionOptionButton, RangedAttackButton, UnitOrderButton};
 use crate::production_input::SelectedCapital;
 
 // Component markers for production menu UI elements
@@ -30,16 +34,17 @@ pub struct CurrentProductionProgressText;
 pub struct ProductionQueueLengthText;
 
 #[derive(Component)]
-pub struct InfantryButton;
+pub struct UnitButton(pub UnitType);
 
 #[derive(Component)]
-pub struct ArcherButton;
-
-#[derive(Component)]
-pub struct CavalryButton;
+pub struct BuildingButton(pub BuildingType);
 
 /// Spawns the complete production menu panel and returns its entity
-pub fn spawn_production_menu_panel(commands: &mut Commands) -> Entity {
+pub fn spawn_production_menu_panel(
+    commands: &mut Commands,
+    unit_registry: &UnitRegistry,
+    building_registry: &BuildingRegistry,
+) -> Entity {
     commands
         .spawn((
             ProductionMenuPanel,
@@ -174,125 +179,116 @@ pub fn spawn_production_menu_panel(commands: &mut Commands) -> Entity {
                         },
                     ));
 
-                    // Infantry button
-                    units_parent
-                        .spawn((
-                            InfantryButton,
-                            Button,
-                            Node {
-                                height: BUTTON_HEIGHT,
-                                width: Val::Percent(100.0),
-                                justify_content: JustifyContent::SpaceBetween,
-                                align_items: AlignItems::Center,
-                                padding: UiRect::all(BUTTON_PADDING),
-                                margin: UiRect::bottom(BUTTON_MARGIN),
-                                border: UiRect::all(BUTTON_BORDER_WIDTH),
-                                ..default()
-                            },
-                            BackgroundColor(BUTTON_BACKGROUND),
-                            BorderColor::from(BUTTON_BORDER),
-                            BorderRadius::all(BUTTON_BORDER_RADIUS),
-                            Name::new("Infantry Button"),
-                        ))
-                        .with_children(|button_parent| {
-                            button_parent.spawn((
-                                Text::new("Infantry"),
-                                TextFont {
-                                    font_size: BODY_FONT_SIZE,
-                                    ..default()
-                                },
-                                TextColor(TEXT_PRIMARY),
-                            ));
-
-                            button_parent.spawn((
-                                Text::new("20 gold, 15 production"),
-                                TextFont {
-                                    font_size: SMALL_FONT_SIZE,
-                                    ..default()
-                                },
-                                TextColor(TEXT_TERTIARY),
-                            ));
-                        });
-
-                    // Archer button
-                    units_parent
-                        .spawn((
-                            ArcherButton,
-                            Button,
-                            Node {
-                                height: BUTTON_HEIGHT,
-                                width: Val::Percent(100.0),
-                                justify_content: JustifyContent::SpaceBetween,
-                                align_items: AlignItems::Center,
-                                padding: UiRect::all(BUTTON_PADDING),
-                                margin: UiRect::bottom(BUTTON_MARGIN),
-                                border: UiRect::all(BUTTON_BORDER_WIDTH),
-                                ..default()
-                            },
-                            BackgroundColor(BUTTON_BACKGROUND),
-                            BorderColor::from(BUTTON_BORDER),
-                            BorderRadius::all(BUTTON_BORDER_RADIUS),
-                            Name::new("Archer Button"),
-                        ))
-                        .with_children(|button_parent| {
-                            button_parent.spawn((
-                                Text::new("Archer"),
-                                TextFont {
-                                    font_size: BODY_FONT_SIZE,
+                    for unit_name in PRODUCTION_MENU_UNIT_TYPES {
+                        units_parent
+                            .spawn((
+                                UnitButton(UnitType::new(unit_name)),
+                                Button,
+                                Node {
+                                    height: BUTTON_HEIGHT,
+                                    width: Val::Percent(100.0),
+                                    justify_content: JustifyContent::SpaceBetween,
+                                    align_items: AlignItems::Center,
+                                    padding: UiRect::all(BUTTON_PADDING),
+                                    margin: UiRect::bottom(BUTTON_MARGIN),
+                                    border: UiRect::all(BUTTON_BORDER_WIDTH),
                                     ..default()
                                 },
-                                TextColor(TEXT_PRIMARY),
-                            ));
+                                BackgroundColor(BUTTON_BACKGROUND),
+                                BorderColor::from(BUTTON_BORDER),
+                                BorderRadius::all(BUTTON_BORDER_RADIUS),
+                                Name::new(format!("{} Button", unit_name)),
+                            ))
+                            .with_children(|button_parent| {
+                                button_parent.spawn((
+                                    Text::new(unit_name),
+                                    TextFont {
+                                        font_size: BODY_FONT_SIZE,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_PRIMARY),
+                                ));
+
+                                button_parent.spawn((
+                                    Text::new(unit_cost_label(unit_registry, unit_name)),
+                                    TextFont {
+                                        font_size: SMALL_FONT_SIZE,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_TERTIARY),
+                                ));
+                            });
+                    }
+                });
 
-                            button_parent.spawn((
-                                Text::new("25 gold, 20 production"),
-                                TextFont {
-                                    font_size: SMALL_FONT_SIZE,
-                                    ..default()
-                                },
-                                TextColor(TEXT_TERTIARY),
-                            ));
-                        });
-
-                    // Cavalry button
-                    units_parent
-                        .spawn((
-                            CavalryButton,
-                            Button,
-                            Node {
-                                height: BUTTON_HEIGHT,
-                                width: Val::Percent(100.0),
-                                justify_content: JustifyContent::SpaceBetween,
-                                align_items: AlignItems::Center,
-                                padding: UiRect::all(BUTTON_PADDING),
-                                margin: UiRect::bottom(BUTTON_MARGIN),
-                                border: UiRect::all(BUTTON_BORDER_WIDTH),
-                                ..default()
-                            },
-                            BackgroundColor(BUTTON_BACKGROUND),
-                            BorderColor::from(BUTTON_BORDER),
-                            BorderRadius::all(BUTTON_BORDER_RADIUS),
-                            Name::new("Cavalry Button"),
-                        ))
-                        .with_children(|button_parent| {
-                            button_parent.spawn((
-                                Text::new("Cavalry"),
-                                TextFont {
-                                    font_size: BODY_FONT_SIZE,
-                                    ..default()
-                                },
-                                TextColor(TEXT_PRIMARY),
-                            ));
+            // Available buildings section
+            menu_parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        margin: UiRect::bottom(TITLE_MARGIN_BOTTOM),
+                        ..default()
+                    },
+                    Name::new("Available Buildings"),
+                ))
+                .with_children(|buildings_parent| {
+                    buildings_parent.spawn((
+                        Text::new("Available Buildings:"),
+                        TextFont {
+                            font_size: SUBTITLE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TITLE_COLOR),
+                        Node {
+                            margin: UiRect::bottom(SECTION_MARGIN_BOTTOM),
+                            ..default()
+                        },
+                    ));
 
-                            button_parent.spawn((
-                                Text::new("40 gold, 30 production"),
-                                TextFont {
-                                    font_size: SMALL_FONT_SIZE,
+                    for definition in &building_registry.building_types {
+                        buildings_parent
+                            .spawn((
+                                BuildingButton(BuildingType::new(&definition.name)),
+                                Button,
+                                Node {
+                                    height: BUTTON_HEIGHT,
+                                    width: Val::Percent(100.0),
+                                    justify_content: JustifyContent::SpaceBetween,
+                                    align_items: AlignItems::Center,
+                                    padding: UiRect::all(BUTTON_PADDING),
+                                    margin: UiRect::bottom(BUTTON_MARGIN),
+                                    border: UiRect::all(BUTTON_BORDER_WIDTH),
                                     ..default()
                                 },
-                                TextColor(TEXT_TERTIARY),
-                            ));
-                        });
+                                BackgroundColor(BUTTON_BACKGROUND),
+                                BorderColor::from(BUTTON_BORDER),
+                                BorderRadius::all(BUTTON_BORDER_RADIUS),
+                                Name::new(format!("{} Button", definition.name)),
+                            ))
+                            .with_children(|button_parent| {
+                                button_parent.spawn((
+                                    Text::new(definition.name.clone()),
+                                    TextFont {
+                                        font_size: BODY_FONT_SIZE,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_PRIMARY),
+                                ));
+
+                                button_parent.spawn((
+                                    Text::new(format!(
+                                        "{} gold, {} production",
+                                        definition.gold_cost as i32,
+                                        definition.production_cost as i32
+                                    )),
+                                    TextFont {
+                                        font_size: SMALL_FONT_SIZE,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_TERTIARY),
+                                ));
+                            });
+                    }
                 });
 
             // Current production section
@@ -393,7 +389,7 @@ pub fn spawn_production_menu_panel(commands: &mut Commands) -> Entity {
 
             // Help text
             menu_parent.spawn((
-                Text::new("Press [Esc] to close | Click buttons to queue units"),
+                Text::new("Press [Esc] to close | Click buttons to queue units and buildings"),
                 TextFont {
                     font_size: SMALL_FONT_SIZE,
                     ..default()
@@ -404,114 +400,84 @@ pub fn spawn_production_menu_panel(commands: &mut Commands) -> Entity {
         .id()
 }
 
-// Systems for handling production menu interactions
-
-pub fn handle_infantry_button(
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<InfantryButton>)>,
-    mut production_orders: MessageWriter<PlayerProductionOrder>,
-    selected_capital: Res<SelectedCapital>,
-    mut civilizations: Query<&mut Civilization>,
-    mut production_queues: Query<&mut ProductionQueue>,
-) {
-    for interaction in &interaction_query {
-        if *interaction == Interaction::Pressed {
-            handle_unit_production(
-                UnitType::Infantry,
-                &mut production_orders,
-                &selected_capital,
-                &mut civilizations,
-                &mut production_queues,
-            );
-        }
-    }
+fn unit_cost_label(unit_registry: &UnitRegistry, unit_name: &str) -> String {
+    let definition = unit_registry.definition(&UnitType::new(unit_name));
+    format!(
+        "{} gold, {} production",
+        definition.gold_cost as i32, definition.production_cost as i32
+    )
 }
 
-pub fn handle_archer_button(
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ArcherButton>)>,
-    mut production_orders: MessageWriter<PlayerProductionOrder>,
-    selected_capital: Res<SelectedCapital>,
-    mut civilizations: Query<&mut Civilization>,
-    mut production_queues: Query<&mut ProductionQueue>,
-) {
-    for interaction in &interaction_query {
-        if *interaction == Interaction::Pressed {
-            handle_unit_production(
-                UnitType::Archer,
-                &mut production_orders,
-                &selected_capital,
-                &mut civilizations,
-                &mut production_queues,
-            );
-        }
-    }
-}
+// Systems for handling production menu interactions
 
-pub fn handle_cavalry_button(
-    interaction_query: Query<&Interaction, (Changed<Interaction>, With<CavalryButton>)>,
+/// Queue the unit or building whose button was pressed in the selected capital, through the
+/// same `core_sim::queue_production` checks the AI goes through
+pub fn handle_production_buttons(
+    unit_buttons: Query<(&Interaction, &UnitButton), Changed<Interaction>>,
+    building_buttons: Query<(&Interaction, &BuildingButton), Changed<Interaction>>,
     mut production_orders: MessageWriter<PlayerProductionOrder>,
     selected_capital: Res<SelectedCapital>,
     mut civilizations: Query<&mut Civilization>,
-    mut production_queues: Query<&mut ProductionQueue>,
+    mut production_queues: Query<(&City, &Position, &mut ProductionQueue)>,
+    world_map: Res<WorldMap>,
+    terrain_registry: Res<TerrainRegistry>,
+    tech_tree: Res<TechTree>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
 ) {
-    for interaction in &interaction_query {
-        if *interaction == Interaction::Pressed {
-            handle_unit_production(
-                UnitType::Cavalry,
-                &mut production_orders,
-                &selected_capital,
-                &mut civilizations,
-                &mut production_queues,
-            );
-        }
-    }
-}
-
-fn handle_unit_production(
-    unit_type: UnitType,
-    production_orders: &mut MessageWriter<PlayerProductionOrder>,
-    selected_capital: &SelectedCapital,
-    civilizations: &mut Query<&mut Civilization>,
-    production_queues: &mut Query<&mut ProductionQueue>,
-) {
-    if !selected_capital.show_production_menu {
+    let pressed_items: Vec<ProductionItem> = unit_buttons
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, button)| ProductionItem::Unit(button.0.clone()))
+        .chain(
+            building_buttons
+                .iter()
+                .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+                .map(|(_, button)| ProductionItem::Building(button.0.clone())),
+        )
+        .collect();
+    if pressed_items.is_empty() || !selected_capital.show_production_menu {
         return;
     }
 
-    let (capital_entity, civ_entity) =
-        match (selected_capital.capital_entity, selected_capital.civ_entity) {
-            (Some(cap), Some(civ)) => (cap, civ),
-            _ => return,
-        };
-
-    let unit_production_item = core_sim::ProductionItem::Unit(unit_type);
-    let unit_cost = unit_production_item.gold_cost();
+    let (Some(capital_entity), Some(civ_entity)) =
+        (selected_capital.capital_entity, selected_capital.civ_entity)
+    else {
+        return;
+    };
 
-    let (mut civilization, mut production_queue) = match (
+    let (Ok(mut civilization), Ok((city, position, mut production_queue))) = (
         civilizations.get_mut(civ_entity),
         production_queues.get_mut(capital_entity),
-    ) {
-        (Ok(civ), Ok(queue)) => (civ, queue),
-        _ => return,
+    ) else {
+        return;
     };
 
-    if civilization.economy.gold < unit_cost as f32 {
-        warn!(
-            "Insufficient gold to queue {:?}. Cost: {}, Available: {}",
-            unit_type, unit_cost, civilization.economy.gold
-        );
-        return;
+    for item in pressed_items {
+        let item_name = item.name().to_string();
+        match core_sim::queue_production(
+            item.clone(),
+            &mut civilization,
+            city,
+            *position,
+            &mut production_queue,
+            &world_map,
+            &terrain_registry,
+            &tech_tree,
+            &unit_registry,
+            &building_registry,
+        ) {
+            Ok(()) => {
+                production_orders.write(PlayerProductionOrder {
+                    capital_entity,
+                    civ_entity,
+                    item,
+                });
+                info!("Queued {} for production", item_name);
+            }
+            Err(error) => warn!("Cannot queue {}: {}", item_name, error),
+        }
     }
-
-    civilization.economy.gold -= unit_cost as f32;
-    production_queue.add_to_queue(unit_production_item.clone());
-
-    production_orders.write(PlayerProductionOrder {
-        capital_entity,
-        civ_entity,
-        item: unit_production_item,
-    });
-
-    info!("Queued {:?} for production", unit_type);
 }
 
 pub fn update_production_button_visuals(
@@ -520,9 +486,11 @@ pub fn update_production_button_visuals(
         (
             Changed<Interaction>,
             Or<(
-                With<InfantryButton>,
-                With<ArcherButton>,
-                With<CavalryButton>,
+                With<UnitButton>,
+                With<BuildingButton>,
+                With<PromotionOptionButton>,
+                With<UnitOrderButton>,
+                With<RangedAttackButton>,
             )>,
         ),
     >,
@@ -548,6 +516,8 @@ pub fn update_production_menu(
     selected_capital: Res<SelectedCapital>,
     civilizations: Query<&Civilization>,
     production_queues: Query<&ProductionQueue>,
+    unit_registry: Res<UnitRegistry>,
+    building_registry: Res<BuildingRegistry>,
     mut menu_query: Query<&mut Node, With<ProductionMenuPanel>>,
     mut capital_name_text: Query<
         &mut Text,
@@ -675,7 +645,9 @@ pub fn update_production_menu(
                     }
 
                     if let Some(mut text) = current_prod_progress_text.iter_mut().next() {
-                        let progress = (queue.get_progress_percentage() * 100.0) as i32;
+                        let progress = (queue
+                            .get_progress_percentage(&unit_registry, &building_registry)
+                            * 100.0) as i32;
                         **text = format!("Progress: {}%", progress);
                     }
 
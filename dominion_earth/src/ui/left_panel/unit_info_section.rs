@@ -1,7 +1,8 @@
 use bevy::prelude::*;
-use core_sim::MilitaryUnit;
+use core_sim::{Civilization, MilitaryUnit, PlayerControlled, UnitOrder, UnitRegistry};
 
 use super::constants::*;
+use crate::ui::resources::RangedAttackMode;
 
 // Component markers for unit info UI elements
 #[derive(Component)]
@@ -49,6 +50,33 @@ pub struct UnitEffectiveAttackText;
 #[derive(Component)]
 pub struct UnitEffectiveDefenseText;
 
+#[derive(Component)]
+pub struct UnitVeterancyText;
+
+/// Container the promotion buttons of the selected unit are spawned into
+#[derive(Component)]
+pub struct UnitPromotionOptionsList;
+
+#[derive(Component)]
+pub struct PromotionOptionButton {
+    pub promotion: String,
+}
+
+#[derive(Component)]
+pub struct UnitOrderText;
+
+#[derive(Component)]
+pub struct UnitOrderButton {
+    pub order: UnitOrder,
+}
+
+/// Button that switches ranged attack mode on and off, shown only for ranged units
+#[derive(Component)]
+pub struct RangedAttackButton;
+
+#[derive(Component)]
+pub struct RangedAttackButtonText;
+
 /// Spawns the complete unit info panel and returns its entity
 pub fn spawn_unit_info_panel(commands: &mut Commands) -> Entity {
     commands
@@ -134,6 +162,123 @@ pub fn spawn_unit_info_panel(commands: &mut Commands) -> Entity {
                     ..default()
                 },
                 TextColor(TEXT_SECONDARY),
+                Node {
+                    margin: UiRect::bottom(TEXT_MARGIN_BOTTOM),
+                    ..default()
+                },
+            ));
+
+            unit_parent.spawn((
+                UnitVeterancyText,
+                Text::new("Recruit"),
+                TextFont {
+                    font_size: BODY_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(TEXT_SECONDARY),
+            ));
+
+            unit_parent.spawn((
+                UnitOrderText,
+                Text::new("Orders: None"),
+                TextFont {
+                    font_size: BODY_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(TEXT_SECONDARY),
+                Node {
+                    margin: UiRect::top(TEXT_MARGIN_BOTTOM),
+                    ..default()
+                },
+            ));
+
+            unit_parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Row,
+                        column_gap: BUTTON_MARGIN,
+                        margin: UiRect::top(BUTTON_MARGIN),
+                        ..default()
+                    },
+                    Name::new("Unit Order Buttons"),
+                ))
+                .with_children(|orders_parent| {
+                    for order in [
+                        UnitOrder::Fortify { level: 0 },
+                        UnitOrder::Sentry,
+                        UnitOrder::Sleep,
+                        UnitOrder::Skip,
+                    ] {
+                        orders_parent
+                            .spawn((
+                                UnitOrderButton { order },
+                                Button,
+                                Node {
+                                    height: BUTTON_HEIGHT,
+                                    flex_grow: 1.0,
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    border: UiRect::all(BUTTON_BORDER_WIDTH),
+                                    ..default()
+                                },
+                                BackgroundColor(BUTTON_BACKGROUND),
+                                BorderColor::from(BUTTON_BORDER),
+                                BorderRadius::all(BUTTON_BORDER_RADIUS),
+                                Name::new(format!("{} Order Button", order.name())),
+                            ))
+                            .with_children(|button_parent| {
+                                button_parent.spawn((
+                                    Text::new(order.name()),
+                                    TextFont {
+                                        font_size: SMALL_FONT_SIZE,
+                                        ..default()
+                                    },
+                                    TextColor(TEXT_PRIMARY),
+                                ));
+                            });
+                    }
+                });
+
+            unit_parent
+                .spawn((
+                    RangedAttackButton,
+                    Button,
+                    Node {
+                        display: Display::None,
+                        height: BUTTON_HEIGHT,
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(BUTTON_MARGIN),
+                        border: UiRect::all(BUTTON_BORDER_WIDTH),
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_BACKGROUND),
+                    BorderColor::from(BUTTON_BORDER),
+                    BorderRadius::all(BUTTON_BORDER_RADIUS),
+                    Name::new("Ranged Attack Button"),
+                ))
+                .with_children(|button_parent| {
+                    button_parent.spawn((
+                        RangedAttackButtonText,
+                        Text::new(BUTTON_TEXT_RANGED_ATTACK),
+                        TextFont {
+                            font_size: BODY_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_PRIMARY),
+                    ));
+                });
+
+            unit_parent.spawn((
+                UnitPromotionOptionsList,
+                Node {
+                    width: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                Name::new("Unit Promotion Options"),
             ));
         })
         .id()
@@ -360,3 +505,229 @@ pub fn update_unit_info(
         }
     }
 }
+
+/// Show the selected unit's veterancy and rebuild its promotion buttons whenever the
+/// promotions it can pick change
+pub fn update_unit_promotions(
+    mut commands: Commands,
+    selected_unit: Res<core_sim::SelectedUnit>,
+    unit_registry: Res<UnitRegistry>,
+    units_query: Query<&MilitaryUnit>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+    mut veterancy_text: Query<&mut Text, With<UnitVeterancyText>>,
+    options_list: Query<Entity, With<UnitPromotionOptionsList>>,
+    mut displayed_options: Local<Option<(Entity, Vec<String>)>>,
+) {
+    let unit = selected_unit
+        .unit_entity
+        .and_then(|entity| units_query.get(entity).ok());
+
+    if let (Some(unit), Some(mut text)) = (unit, veterancy_text.iter_mut().next()) {
+        let mut veterancy = format!(
+            "{} ({:.0}% experience)",
+            unit.veterancy_level().name(),
+            unit.experience * 100.0
+        );
+        if !unit.promotions.is_empty() {
+            veterancy.push_str(&format!(" - {}", unit.promotions.join(", ")));
+        }
+        **text = veterancy;
+    }
+
+    let is_player_unit =
+        unit.is_some_and(|unit| player_civs.iter().any(|civ| civ.id == unit.owner));
+    let available: Vec<String> = match unit {
+        Some(unit) if is_player_unit && unit.pending_promotions() > 0 => unit_registry
+            .available_promotions(unit)
+            .map(|promotion| promotion.name.clone())
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let Ok(list_entity) = options_list.single() else {
+        return;
+    };
+    if displayed_options
+        .as_ref()
+        .is_some_and(|(entity, options)| *entity == list_entity && *options == available)
+    {
+        return;
+    }
+
+    commands
+        .entity(list_entity)
+        .despawn_related::<Children>()
+        .with_children(|list_parent| {
+            for promotion in &available {
+                let description = unit_registry
+                    .promotion(promotion)
+                    .map(|definition| definition.description.clone())
+                    .unwrap_or_default();
+                list_parent
+                    .spawn((
+                        PromotionOptionButton {
+                            promotion: promotion.clone(),
+                        },
+                        Button,
+                        Node {
+                            height: BUTTON_HEIGHT,
+                            width: Val::Percent(100.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(BUTTON_PADDING),
+                            margin: UiRect::top(BUTTON_MARGIN),
+                            border: UiRect::all(BUTTON_BORDER_WIDTH),
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_BACKGROUND),
+                        BorderColor::from(BUTTON_BORDER),
+                        BorderRadius::all(BUTTON_BORDER_RADIUS),
+                        Name::new(format!("Promote {} Button", promotion)),
+                    ))
+                    .with_children(|button_parent| {
+                        button_parent.spawn((
+                            Text::new(promotion.clone()),
+                            TextFont {
+                                font_size: BODY_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(TEXT_PRIMARY),
+                        ));
+
+                        button_parent.spawn((
+                            Text::new(description),
+                            TextFont {
+                                font_size: SMALL_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(TEXT_TERTIARY),
+                        ));
+                    });
+            }
+        });
+
+    *displayed_options = Some((list_entity, available));
+}
+
+pub fn handle_promotion_buttons(
+    interaction_query: Query<(&Interaction, &PromotionOptionButton), Changed<Interaction>>,
+    selected_unit: Res<core_sim::SelectedUnit>,
+    unit_registry: Res<UnitRegistry>,
+    mut units_query: Query<&mut MilitaryUnit>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(mut unit) = selected_unit
+            .unit_entity
+            .and_then(|entity| units_query.get_mut(entity).ok())
+        else {
+            return;
+        };
+
+        match core_sim::promote_unit(&mut unit, &unit_registry, &button.promotion) {
+            Ok(()) => info!("Unit #{} promoted with {}", unit.id, button.promotion),
+            Err(reason) => warn!("Cannot promote with {}: {}", button.promotion, reason),
+        }
+    }
+}
+
+/// Show the standing order the selected unit is following
+pub fn update_unit_order_status(
+    selected_unit: Res<core_sim::SelectedUnit>,
+    orders_query: Query<Option<&UnitOrder>, With<MilitaryUnit>>,
+    mut order_text: Query<&mut Text, With<UnitOrderText>>,
+) {
+    let Some(Ok(order)) = selected_unit
+        .unit_entity
+        .map(|entity| orders_query.get(entity))
+    else {
+        return;
+    };
+    let Some(mut text) = order_text.iter_mut().next() else {
+        return;
+    };
+
+    **text = match order {
+        Some(UnitOrder::Fortify { level }) => format!("Orders: Fortify (level {})", level),
+        Some(order) => format!("Orders: {}", order.name()),
+        None => "Orders: None".to_string(),
+    };
+}
+
+pub fn handle_unit_order_buttons(
+    mut commands: Commands,
+    interaction_query: Query<(&Interaction, &UnitOrderButton), Changed<Interaction>>,
+    selected_unit: Res<core_sim::SelectedUnit>,
+    units_query: Query<&MilitaryUnit>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some((entity, unit)) = selected_unit
+            .unit_entity
+            .and_then(|entity| units_query.get(entity).ok().map(|unit| (entity, unit)))
+        else {
+            return;
+        };
+        if !player_civs.iter().any(|civ| civ.id == unit.owner) {
+            return;
+        }
+
+        crate::input::give_unit_order(&mut commands, entity, unit, button.order);
+    }
+}
+
+/// Show the ranged attack button while a ranged unit of the player is selected, and whether
+/// it is waiting for a target
+pub fn update_ranged_attack_button(
+    selected_unit: Res<core_sim::SelectedUnit>,
+    ranged_attack_mode: Res<RangedAttackMode>,
+    units_query: Query<&MilitaryUnit>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+    mut button_query: Query<&mut Node, With<RangedAttackButton>>,
+    mut text_query: Query<&mut Text, With<RangedAttackButtonText>>,
+) {
+    let is_player_ranged_unit = selected_unit
+        .unit_entity
+        .and_then(|entity| units_query.get(entity).ok())
+        .is_some_and(|unit| {
+            unit.has_ranged_attack() && player_civs.iter().any(|civ| civ.id == unit.owner)
+        });
+
+    if let Some(mut node) = button_query.iter_mut().next() {
+        let display = if is_player_ranged_unit {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    if ranged_attack_mode.is_changed() {
+        if let Some(mut text) = text_query.iter_mut().next() {
+            **text = if ranged_attack_mode.active {
+                BUTTON_TEXT_CHOOSE_TARGET
+            } else {
+                BUTTON_TEXT_RANGED_ATTACK
+            }
+            .to_string();
+        }
+    }
+}
+
+pub fn handle_ranged_attack_button(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<RangedAttackButton>)>,
+    mut ranged_attack_mode: ResMut<RangedAttackMode>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            ranged_attack_mode.active = !ranged_attack_mode.active;
+        }
+    }
+}
@@ -12,6 +12,12 @@ pub struct HoveredTile {
     pub terrain_type: Option<TerrainType>,
 }
 
+/// Set while the player is picking a target for the selected unit's ranged attack
+#[derive(Resource, Default, Clone)]
+pub struct RangedAttackMode {
+    pub active: bool,
+}
+
 #[derive(Resource, Default, Clone)]
 pub struct LastLoggedTile {
     pub position: Option<Position>,
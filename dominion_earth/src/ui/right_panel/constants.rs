@@ -24,3 +24,19 @@ pub const STATISTICS_PANEL_MIN_HEIGHT: Val = Val::Px(150.0);
 pub const HOVERED_TILE_PANEL_MIN_HEIGHT: Val = Val::Px(120.0);
 pub const CIVILIZATIONS_PANEL_MIN_HEIGHT: Val = Val::Px(200.0);
 pub const MINIMAP_PANEL_MIN_HEIGHT: Val = Val::Px(150.0);
+pub const RESEARCH_PANEL_MIN_HEIGHT: Val = Val::Px(150.0);
+pub const MARKET_PANEL_MIN_HEIGHT: Val = Val::Px(150.0);
+
+pub const SMALL_FONT_SIZE: f32 = 12.0;
+
+pub const BUTTON_HEIGHT: Val = Val::Px(28.0);
+pub const BUTTON_PADDING: Val = Val::Px(6.0);
+pub const BUTTON_MARGIN: Val = Val::Px(4.0);
+pub const BUTTON_BORDER_WIDTH: Val = Val::Px(2.0);
+pub const BUTTON_BORDER_RADIUS: Val = Val::Px(5.0);
+
+pub const BUTTON_BACKGROUND: Color = Color::srgba(0.176, 0.176, 0.176, 1.0);
+pub const BUTTON_BORDER: Color = Color::srgba(0.4, 0.4, 0.4, 1.0);
+pub const BUTTON_HOVER_BACKGROUND: Color = Color::srgba(0.251, 0.251, 0.251, 1.0);
+pub const BUTTON_HOVER_BORDER: Color = Color::srgba(1.0, 0.8, 0.0, 1.0);
+pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgba(0.0, 0.667, 0.667, 1.0);
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use core_sim::components::TerrainType;
+use core_sim::{Position, TileOccupancy};
 
 use crate::ui::resources::HoveredTile;
 
@@ -12,11 +13,46 @@ pub struct HoveredPositionText;
 #[derive(Component)]
 pub struct HoveredTerrainText;
 
+#[derive(Component)]
+pub struct HoveredOccupancyText;
+
 pub fn update_hovered_tile_info(
     hovered_tile: Res<HoveredTile>,
-    mut position_text: Query<&mut Text, (With<HoveredPositionText>, Without<HoveredTerrainText>)>,
-    mut terrain_text: Query<&mut Text, (With<HoveredTerrainText>, Without<HoveredPositionText>)>,
+    occupancy: Res<TileOccupancy>,
+    mut position_text: Query<
+        &mut Text,
+        (
+            With<HoveredPositionText>,
+            Without<HoveredTerrainText>,
+            Without<HoveredOccupancyText>,
+        ),
+    >,
+    mut terrain_text: Query<
+        &mut Text,
+        (
+            With<HoveredTerrainText>,
+            Without<HoveredPositionText>,
+            Without<HoveredOccupancyText>,
+        ),
+    >,
+    mut occupancy_text: Query<
+        &mut Text,
+        (
+            With<HoveredOccupancyText>,
+            Without<HoveredPositionText>,
+            Without<HoveredTerrainText>,
+        ),
+    >,
 ) {
+    if hovered_tile.is_changed() || occupancy.is_changed() {
+        if let Some(mut text) = occupancy_text.iter_mut().next() {
+            **text = match hovered_tile.position {
+                Some(position) => format_tile_occupancy(&occupancy, position),
+                None => "Units: None".to_string(),
+            };
+        }
+    }
+
     if hovered_tile.is_changed() {
         match hovered_tile.position {
             Some(position) => {
@@ -45,6 +81,22 @@ pub fn update_hovered_tile_info(
     }
 }
 
+/// Describe the units on `position` against the stacking limit, and the city there, if any
+fn format_tile_occupancy(occupancy: &TileOccupancy, position: Position) -> String {
+    let units = occupancy.units_at(position);
+    let military = units.iter().filter(|occupant| occupant.military).count();
+    let mut description = format!(
+        "Units: {} ({} military, stack limit {})",
+        units.len(),
+        military,
+        occupancy.max_units_per_tile
+    );
+    if let Some((_, owner)) = occupancy.city_at(position) {
+        description.push_str(&format!(" - City of civ {}", owner.0));
+    }
+    description
+}
+
 fn format_terrain_type(terrain: &TerrainType) -> String {
     match terrain {
         TerrainType::Plains => "Plains".to_string(),
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use core_sim::{EconomicEventType, GameResource, GlobalEconomy};
+
+#[derive(Component)]
+pub struct MarketPanel;
+
+#[derive(Component)]
+pub struct MarketPricesText;
+
+#[derive(Component)]
+pub struct MarketEventsText;
+
+/// Show the current world market price of every resource and the economic events
+/// affecting them
+pub fn update_market_panel(
+    global_economy: Res<GlobalEconomy>,
+    mut prices_text: Query<&mut Text, (With<MarketPricesText>, Without<MarketEventsText>)>,
+    mut events_text: Query<&mut Text, (With<MarketEventsText>, Without<MarketPricesText>)>,
+) {
+    if !global_economy.is_changed() {
+        return;
+    }
+
+    if let Some(mut text) = prices_text.iter_mut().next() {
+        **text = GameResource::ALL
+            .into_iter()
+            .map(|resource| {
+                format!(
+                    "{}: {:.1}",
+                    resource.name(),
+                    global_economy.resource_price(resource)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    if let Some(mut text) = events_text.iter_mut().next() {
+        **text = if global_economy.economic_events.is_empty() {
+            "No market events".to_string()
+        } else {
+            global_economy
+                .economic_events
+                .iter()
+                .map(|event| {
+                    let label = match event.event_type {
+                        EconomicEventType::ResourceBoom => "Boom",
+                        EconomicEventType::ResourceCrash => "Crash",
+                        EconomicEventType::TradeDisruption => "Trade disruption",
+                        EconomicEventType::TechnologicalAdvancement => "Breakthrough",
+                    };
+                    let affected = event
+                        .affected_resource
+                        .map_or("trade routes", |resource| resource.name());
+                    format!(
+                        "{} in {} (x{:.2}, {} turns left)",
+                        label, affected, event.magnitude, event.duration
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+    }
+}
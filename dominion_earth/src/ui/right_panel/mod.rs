@@ -1,6 +1,8 @@
 pub mod civilizations_section;
 pub mod constants;
 pub mod hovered_tile_section;
+pub mod market_section;
+pub mod research_section;
 pub mod statistics_section;
 
 use bevy::prelude::*;
@@ -11,6 +13,8 @@ use constants::*;
 
 pub use civilizations_section::*;
 pub use hovered_tile_section::*;
+pub use market_section::*;
+pub use research_section::*;
 pub use statistics_section::*;
 
 #[derive(Component)]
@@ -180,8 +184,23 @@ pub fn spawn_right_panel(mut commands: Commands) {
                             ..default()
                         },
                         TextColor(TEXT_PRIMARY),
+                        Node {
+                            margin: UiRect::bottom(TEXT_MARGIN_BOTTOM),
+                            ..default()
+                        },
                         Name::new("Hovered Terrain Text"),
                     ));
+
+                    tile_parent.spawn((
+                        HoveredOccupancyText,
+                        Text::new("Units: None"),
+                        TextFont {
+                            font_size: SUBTITLE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_PRIMARY),
+                        Name::new("Hovered Occupancy Text"),
+                    ));
                 });
 
             parent
@@ -228,6 +247,123 @@ pub fn spawn_right_panel(mut commands: Commands) {
                     ));
                 });
 
+            parent
+                .spawn((
+                    ResearchPanel,
+                    Node {
+                        width: Val::Percent(100.0),
+                        min_height: RESEARCH_PANEL_MIN_HEIGHT,
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(PANEL_PADDING),
+                        margin: UiRect::all(PANEL_MARGIN),
+                        border: UiRect::all(PANEL_BORDER_WIDTH),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_BACKGROUND),
+                    BorderColor::from(PANEL_BORDER),
+                    BorderRadius::all(PANEL_BORDER_RADIUS),
+                    Name::new("Research Panel"),
+                ))
+                .with_children(|research_parent| {
+                    research_parent.spawn((
+                        Text::new("Research"),
+                        TextFont {
+                            font_size: TITLE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TITLE_COLOR),
+                        Node {
+                            margin: UiRect::bottom(TITLE_MARGIN_BOTTOM),
+                            ..default()
+                        },
+                        Name::new("Research Title"),
+                    ));
+
+                    research_parent.spawn((
+                        ResearchStatusText,
+                        Text::new("Researching: Nothing"),
+                        TextFont {
+                            font_size: BODY_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_SECONDARY),
+                        Node {
+                            margin: UiRect::bottom(SECTION_MARGIN_BOTTOM),
+                            ..default()
+                        },
+                        Name::new("Research Status Text"),
+                    ));
+
+                    research_parent.spawn((
+                        ResearchOptionsList,
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            ..default()
+                        },
+                        Name::new("Research Options List"),
+                    ));
+                });
+
+            parent
+                .spawn((
+                    MarketPanel,
+                    Node {
+                        width: Val::Percent(100.0),
+                        min_height: MARKET_PANEL_MIN_HEIGHT,
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(PANEL_PADDING),
+                        margin: UiRect::all(PANEL_MARGIN),
+                        border: UiRect::all(PANEL_BORDER_WIDTH),
+                        ..default()
+                    },
+                    BackgroundColor(PANEL_BACKGROUND),
+                    BorderColor::from(PANEL_BORDER),
+                    BorderRadius::all(PANEL_BORDER_RADIUS),
+                    Name::new("Market Panel"),
+                ))
+                .with_children(|market_parent| {
+                    market_parent.spawn((
+                        Text::new("World Market"),
+                        TextFont {
+                            font_size: TITLE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TITLE_COLOR),
+                        Node {
+                            margin: UiRect::bottom(TITLE_MARGIN_BOTTOM),
+                            ..default()
+                        },
+                        Name::new("Market Title"),
+                    ));
+
+                    market_parent.spawn((
+                        MarketPricesText,
+                        Text::new("Loading..."),
+                        TextFont {
+                            font_size: BODY_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_SECONDARY),
+                        Node {
+                            margin: UiRect::bottom(SECTION_MARGIN_BOTTOM),
+                            ..default()
+                        },
+                        Name::new("Market Prices Text"),
+                    ));
+
+                    market_parent.spawn((
+                        MarketEventsText,
+                        Text::new("No market events"),
+                        TextFont {
+                            font_size: BODY_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_TERTIARY),
+                        Name::new("Market Events Text"),
+                    ));
+                });
+
             parent
                 .spawn((
                     Node {
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use core_sim::{Civilization, PlayerControlled, TechTree};
+
+use super::constants::*;
+
+#[derive(Component)]
+pub struct ResearchPanel;
+
+#[derive(Component)]
+pub struct ResearchStatusText;
+
+/// Container the available technology buttons are spawned into
+#[derive(Component)]
+pub struct ResearchOptionsList;
+
+#[derive(Component)]
+pub struct ResearchOptionButton {
+    pub technology: String,
+}
+
+/// Update the research status and rebuild the option buttons whenever the
+/// set of researchable technologies changes
+pub fn update_research_panel(
+    mut commands: Commands,
+    tech_tree: Res<TechTree>,
+    player_civs: Query<&Civilization, With<PlayerControlled>>,
+    mut status_text: Query<&mut Text, With<ResearchStatusText>>,
+    options_list: Query<Entity, With<ResearchOptionsList>>,
+    mut displayed_options: Local<Option<(Entity, Vec<String>)>>,
+) {
+    let Some(player_civ) = player_civs.iter().next() else {
+        return;
+    };
+    let technologies = &player_civ.technologies;
+
+    if let Some(mut text) = status_text.iter_mut().next() {
+        **text = match &technologies.current_research {
+            Some(current) => format!(
+                "Researching: {} ({}/{})",
+                current,
+                technologies.research_points as i32,
+                tech_tree.research_cost(current).unwrap_or_default() as i32
+            ),
+            None => format!(
+                "Researching: Nothing ({} points banked)",
+                technologies.research_points as i32
+            ),
+        };
+    }
+
+    let available: Vec<String> = tech_tree
+        .available_technologies(technologies)
+        .into_iter()
+        .map(|tech| tech.name.clone())
+        .collect();
+
+    let Ok(list_entity) = options_list.single() else {
+        return;
+    };
+    if displayed_options
+        .as_ref()
+        .is_some_and(|(entity, options)| *entity == list_entity && *options == available)
+    {
+        return;
+    }
+
+    commands
+        .entity(list_entity)
+        .despawn_related::<Children>()
+        .with_children(|list_parent| {
+            for technology in &available {
+                let cost = tech_tree.research_cost(technology).unwrap_or_default();
+                list_parent
+                    .spawn((
+                        ResearchOptionButton {
+                            technology: technology.clone(),
+                        },
+                        Button,
+                        Node {
+                            height: BUTTON_HEIGHT,
+                            width: Val::Percent(100.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(BUTTON_PADDING),
+                            margin: UiRect::bottom(BUTTON_MARGIN),
+                            border: UiRect::all(BUTTON_BORDER_WIDTH),
+                            ..default()
+                        },
+                        BackgroundColor(BUTTON_BACKGROUND),
+                        BorderColor::from(BUTTON_BORDER),
+                        BorderRadius::all(BUTTON_BORDER_RADIUS),
+                        Name::new(format!("Research {} Button", technology)),
+                    ))
+                    .with_children(|button_parent| {
+                        button_parent.spawn((
+                            Text::new(technology.clone()),
+                            TextFont {
+                                font_size: BODY_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(TEXT_PRIMARY),
+                        ));
+
+                        button_parent.spawn((
+                            Text::new(format!("{} research", cost as i32)),
+                            TextFont {
+                                font_size: SMALL_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(TEXT_TERTIARY),
+                        ));
+                    });
+            }
+        });
+
+    *displayed_options = Some((list_entity, available));
+}
+
+pub fn handle_research_buttons(
+    interaction_query: Query<(&Interaction, &ResearchOptionButton), Changed<Interaction>>,
+    mut player_civs: Query<&mut Civilization, With<PlayerControlled>>,
+    tech_tree: Res<TechTree>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(mut player_civ) = player_civs.iter_mut().next() else {
+            return;
+        };
+
+        if tech_tree.start_research(&mut player_civ.technologies, &button.technology) {
+            info!("Researching {}", button.technology);
+        } else {
+            warn!("{} cannot be researched yet", button.technology);
+        }
+    }
+}
+
+pub fn update_research_button_visuals(
+    mut query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ResearchOptionButton>),
+    >,
+) {
+    for (interaction, mut background, mut border) in &mut query {
+        match *interaction {
+            Interaction::Pressed => {
+                *background = BackgroundColor(BUTTON_PRESSED_BACKGROUND);
+            }
+            Interaction::Hovered => {
+                *background = BackgroundColor(BUTTON_HOVER_BACKGROUND);
+                *border = BorderColor::all(BUTTON_HOVER_BORDER);
+            }
+            Interaction::None => {
+                *background = BackgroundColor(BUTTON_BACKGROUND);
+                *border = BorderColor::all(BUTTON_BORDER);
+            }
+        }
+    }
+}
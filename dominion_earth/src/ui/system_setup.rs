@@ -32,14 +32,26 @@ impl BevyUiSystem {
                 crate::ui::right_panel::update_statistics_panel,
                 crate::ui::right_panel::update_hovered_tile_info,
                 crate::ui::right_panel::update_civilizations_list,
+                (
+                    crate::ui::right_panel::update_research_panel,
+                    crate::ui::right_panel::handle_research_buttons,
+                    crate::ui::right_panel::update_research_button_visuals,
+                    crate::ui::right_panel::update_market_panel,
+                ),
                 crate::ui::left_panel::update_next_turn_button_text,
                 crate::ui::left_panel::handle_next_turn_button,
-                crate::ui::left_panel::handle_infantry_button,
-                crate::ui::left_panel::handle_archer_button,
-                crate::ui::left_panel::handle_cavalry_button,
+                crate::ui::left_panel::handle_production_buttons,
                 crate::ui::left_panel::update_production_button_visuals,
                 crate::ui::left_panel::update_production_menu,
-                crate::ui::left_panel::update_unit_info,
+                (
+                    crate::ui::left_panel::update_unit_info,
+                    crate::ui::left_panel::update_unit_promotions,
+                    crate::ui::left_panel::handle_promotion_buttons,
+                    crate::ui::left_panel::update_unit_order_status,
+                    crate::ui::left_panel::handle_unit_order_buttons,
+                    crate::ui::left_panel::update_ranged_attack_button,
+                    crate::ui::left_panel::handle_ranged_attack_button,
+                ),
             ),
         );
     }
@@ -72,14 +84,26 @@ impl BevyUiSystem {
                 crate::ui::right_panel::update_statistics_panel,
                 crate::ui::right_panel::update_hovered_tile_info,
                 crate::ui::right_panel::update_civilizations_list,
+                (
+                    crate::ui::right_panel::update_research_panel,
+                    crate::ui::right_panel::handle_research_buttons,
+                    crate::ui::right_panel::update_research_button_visuals,
+                    crate::ui::right_panel::update_market_panel,
+                ),
                 crate::ui::left_panel::update_next_turn_button_text,
                 crate::ui::left_panel::handle_next_turn_button,
-                crate::ui::left_panel::handle_infantry_button,
-                crate::ui::left_panel::handle_archer_button,
-                crate::ui::left_panel::handle_cavalry_button,
+                crate::ui::left_panel::handle_production_buttons,
                 crate::ui::left_panel::update_production_button_visuals,
                 crate::ui::left_panel::update_production_menu,
-                crate::ui::left_panel::update_unit_info,
+                (
+                    crate::ui::left_panel::update_unit_info,
+                    crate::ui::left_panel::update_unit_promotions,
+                    crate::ui::left_panel::handle_promotion_buttons,
+                    crate::ui::left_panel::update_unit_order_status,
+                    crate::ui::left_panel::handle_unit_order_buttons,
+                    crate::ui::left_panel::update_ranged_attack_button,
+                    crate::ui::left_panel::handle_ranged_attack_button,
+                ),
             )
                 .run_if(in_state(screen)),
         );
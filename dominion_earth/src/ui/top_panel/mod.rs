@@ -108,6 +108,18 @@ pub fn spawn_top_panel(mut commands: Commands) {
                         Name::new("Production Display"),
                     ));
 
+                    // Strategic Resources Display
+                    stats_parent.spawn((
+                        StrategicResourcesDisplayText,
+                        Text::new("Resources: none"),
+                        TextFont {
+                            font_size: STATS_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(TEXT_PRIMARY),
+                        Name::new("Strategic Resources Display"),
+                    ));
+
                     // Turn Display
                     stats_parent.spawn((
                         TurnDisplayText,
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use core_sim::{Civilization, PlayerControlled};
+use core_sim::{Civilization, EconomicSystem, GameResource, PlayerControlled};
 
 // ============================================================================
 // Marker Components
@@ -13,25 +13,67 @@ pub struct GoldDisplayText;
 #[derive(Component)]
 pub struct ProductionDisplayText;
 
+/// Marker component for the strategic resource stockpile text
+#[derive(Component)]
+pub struct StrategicResourcesDisplayText;
+
 // ============================================================================
 // Update Systems
 // ============================================================================
 
-/// Update gold and production displays from player civilization
+/// Update gold, production and resource stockpile displays from player civilization
 pub fn update_player_resources(
     player_query: Query<&Civilization, With<PlayerControlled>>,
-    mut gold_text: Query<&mut Text, (With<GoldDisplayText>, Without<ProductionDisplayText>)>,
-    mut production_text: Query<&mut Text, With<ProductionDisplayText>>,
+    mut gold_text: Query<
+        &mut Text,
+        (
+            With<GoldDisplayText>,
+            Without<ProductionDisplayText>,
+            Without<StrategicResourcesDisplayText>,
+        ),
+    >,
+    mut production_text: Query<
+        &mut Text,
+        (
+            With<ProductionDisplayText>,
+            Without<StrategicResourcesDisplayText>,
+        ),
+    >,
+    mut resources_text: Query<&mut Text, With<StrategicResourcesDisplayText>>,
 ) {
     if let Some(player_civ) = player_query.iter().next() {
-        // Update gold display
+        // Update gold display with the net change expected next turn
         if let Some(mut text) = gold_text.iter_mut().next() {
-            **text = format!("Gold: {}", player_civ.economy.gold);
+            **text = format!(
+                "Gold: {} ({:+})",
+                player_civ.economy.gold as i32,
+                EconomicSystem::net_income(&player_civ.economy) as i32
+            );
         }
 
         // Update production display
         if let Some(mut text) = production_text.iter_mut().next() {
-            **text = format!("Production: {}", player_civ.economy.production);
+            **text = format!("Production: {}", player_civ.economy.production as i32);
+        }
+
+        // Update resource stockpile display, one entry per resource with an owned source
+        if let Some(mut text) = resources_text.iter_mut().next() {
+            let stockpile: Vec<String> = GameResource::ALL
+                .iter()
+                .filter(|resource| player_civ.economy.has_resource(**resource))
+                .map(|resource| {
+                    format!(
+                        "{} {}",
+                        resource.name(),
+                        player_civ.economy.resource_count(*resource)
+                    )
+                })
+                .collect();
+            **text = if stockpile.is_empty() {
+                "Resources: none".to_string()
+            } else {
+                format!("Resources: {}", stockpile.join(", "))
+            };
         }
     }
 }
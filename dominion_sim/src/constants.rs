@@ -0,0 +1,23 @@
+//! Constants for the headless simulation runner
+
+/// Default command line values
+pub mod defaults {
+    /// Number of civilizations spawned when `--civs` is not given (matches the frontend)
+    pub const CIVILIZATIONS: u32 = 3;
+
+    /// Number of turns simulated when `--turns` is not given
+    pub const TURNS: u32 = 100;
+
+    /// Map width in tiles (matches the frontend's default map)
+    pub const MAP_WIDTH: u32 = 50;
+
+    /// Map height in tiles (matches the frontend's default map)
+    pub const MAP_HEIGHT: u32 = 25;
+}
+
+/// Runner loop limits
+pub mod runner {
+    /// Schedule runs allowed per civilization per turn before the runner gives up.
+    /// A healthy AI-only turn needs one run per civilization.
+    pub const MAX_FRAMES_PER_CIV_TURN: u32 = 4;
+}
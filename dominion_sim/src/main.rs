@@ -0,0 +1,86 @@
+mod constants;
+mod simulation;
+mod summary;
+
+use crate::constants::defaults;
+use crate::simulation::{Simulation, SimulationConfig};
+use clap::Parser;
+use core_sim::constants::{
+    buildings::BUILDINGS_DATA_PATH, civilization_management::CIVILIZATIONS_DATA_PATH, game_flow,
+    occupancy, research::TECHNOLOGIES_DATA_PATH, terrain::TERRAIN_DATA_PATH,
+    units::UNITS_DATA_PATH,
+};
+
+/// Run an AI-only Dominion Earth game without a window and print the final standings
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct CliArgs {
+    /// Random seed used for map generation and civilization placement
+    #[arg(long, default_value_t = game_flow::DEFAULT_DEBUG_SEED)]
+    seed: u64,
+
+    /// Number of civilizations to spawn
+    #[arg(long, default_value_t = defaults::CIVILIZATIONS)]
+    civs: u32,
+
+    /// Number of full turns to simulate
+    #[arg(long, default_value_t = defaults::TURNS)]
+    turns: u32,
+
+    /// Map width in tiles
+    #[arg(long, default_value_t = defaults::MAP_WIDTH)]
+    width: u32,
+
+    /// Map height in tiles
+    #[arg(long, default_value_t = defaults::MAP_HEIGHT)]
+    height: u32,
+
+    /// Military units of one civilization a tile can hold
+    #[arg(long, default_value_t = occupancy::DEFAULT_MAX_UNITS_PER_TILE)]
+    max_units_per_tile: u32,
+
+    /// Path to the civilization definitions RON file
+    #[arg(long, default_value = CIVILIZATIONS_DATA_PATH)]
+    data: String,
+
+    /// Path to the technology tree RON file
+    #[arg(long, default_value = TECHNOLOGIES_DATA_PATH)]
+    technologies: String,
+
+    /// Path to the unit definitions RON file
+    #[arg(long, default_value = UNITS_DATA_PATH)]
+    units: String,
+
+    /// Path to the terrain definitions RON file
+    #[arg(long, default_value = TERRAIN_DATA_PATH)]
+    terrain: String,
+
+    /// Path to the building definitions RON file
+    #[arg(long, default_value = BUILDINGS_DATA_PATH)]
+    buildings: String,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = CliArgs::parse();
+
+    let config = SimulationConfig {
+        seed: args.seed,
+        total_civilizations: args.civs,
+        turns: args.turns,
+        map_width: args.width,
+        map_height: args.height,
+        max_units_per_tile: args.max_units_per_tile,
+        civilization_data_path: args.data,
+        technologies_data_path: args.technologies,
+        units_data_path: args.units,
+        terrain_data_path: args.terrain,
+        buildings_data_path: args.buildings,
+    };
+
+    let mut simulation = Simulation::new(&config)?;
+    let report = simulation.run(config.turns)?;
+
+    summary::print_summary(&config, &report, simulation.world_mut());
+
+    Ok(())
+}
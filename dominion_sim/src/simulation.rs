@@ -0,0 +1,428 @@
+//! Headless world setup and turn loop
+//!
+//! Builds the same world the frontend builds for an AI-only game and drives the
+//! core_sim turn systems without rendering, input or UI.
+
+use crate::constants::runner::MAX_FRAMES_PER_CIV_TURN;
+use ai_planner::ai_coordinator::AICoordinatorSystem;
+use anyhow::{bail, Context};
+use bevy_ecs::message::{message_update_system, MessageRegistry};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ExecutorKind;
+use core_sim::{
+    resources::{CurrentTurn, GameConfig, GameRng},
+    world_gen::generate_island_map,
+    AIAction, AITurnComplete, ActionQueue, AllAITurnsComplete, AttackRequested, BuildingRegistry,
+    CapitalEvolved, CityCaptured, CityFounded, CivId, CivTerritories, Civilization,
+    CivilizationData, CivilizationDataLoader, CombatResolved, DiplomaticState,
+    EconomicEventStarted, FogOfWarMaps, GameState, GlobalEconomy, PlayerActionsComplete,
+    PlayerControlled, PopulationChanged, Position, ProcessAITurn, ProductionUpdated,
+    RangedAttackRequested, RangedAttackResolved, RequestTurnAdvance, StartPlayerTurn, TechTree,
+    TechnologyResearched, TerrainRegistry, Territory, TileOccupancy, TradeRoutePillaged, TurnOrder,
+    TurnPhase, UnitLostAtSea, UnitLostToAttrition, UnitMutinied, UnitPromoted, UnitRegistry,
+    WorldMap,
+};
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Settings for a single headless run
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub seed: u64,
+    pub total_civilizations: u32,
+    pub turns: u32,
+    pub map_width: u32,
+    pub map_height: u32,
+    pub max_units_per_tile: u32,
+    pub civilization_data_path: String,
+    pub technologies_data_path: String,
+    pub units_data_path: String,
+    pub terrain_data_path: String,
+    pub buildings_data_path: String,
+}
+
+/// Outcome of [`Simulation::run`]
+#[derive(Debug, Clone)]
+pub struct RunReport {
+    pub turns_completed: u32,
+    pub frames: u32,
+    pub elapsed: Duration,
+}
+
+/// AI coordinator owned by the headless world
+#[derive(Resource, Default)]
+pub struct AiCoordinator(pub AICoordinatorSystem);
+
+pub struct Simulation {
+    world: World,
+    schedule: Schedule,
+    civilization_count: u32,
+}
+
+impl Simulation {
+    pub fn new(config: &SimulationConfig) -> anyhow::Result<Self> {
+        let civilization_data =
+            CivilizationDataLoader::load_from_ron(&config.civilization_data_path)
+                .map_err(|e| anyhow::anyhow!("{}", e))
+                .with_context(|| {
+                    format!(
+                        "failed to load civilization data from {}",
+                        config.civilization_data_path
+                    )
+                })?;
+
+        let tech_tree = TechTree::load_from_ron(&config.technologies_data_path)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+            .with_context(|| {
+                format!(
+                    "failed to load technology data from {}",
+                    config.technologies_data_path
+                )
+            })?;
+
+        let unit_registry = UnitRegistry::load_from_ron(&config.units_data_path)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+            .with_context(|| format!("failed to load unit data from {}", config.units_data_path))?;
+
+        let terrain_registry = TerrainRegistry::load_from_ron(&config.terrain_data_path)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+            .with_context(|| {
+                format!(
+                    "failed to load terrain data from {}",
+                    config.terrain_data_path
+                )
+            })?;
+
+        let building_registry = BuildingRegistry::load_from_ron(&config.buildings_data_path)
+            .map_err(|e| anyhow::anyhow!("{}", e))
+            .with_context(|| {
+                format!(
+                    "failed to load building data from {}",
+                    config.buildings_data_path
+                )
+            })?;
+
+        let mut world = World::new();
+        register_messages(&mut world);
+
+        let mut rng = rand_pcg::Pcg64::seed_from_u64(config.seed);
+        let mut world_map = generate_island_map(
+            config.map_width,
+            config.map_height,
+            &terrain_registry,
+            &mut rng,
+        );
+
+        let spawned_count = {
+            let mut commands = world.commands();
+            core_sim::spawn_initial_civilizations(
+                &mut commands,
+                &mut world_map,
+                &mut rng,
+                civilization_data,
+                &unit_registry,
+                &building_registry,
+                true,
+                config.total_civilizations,
+            )
+        };
+        world.flush();
+
+        if spawned_count == 0 {
+            bail!("no civilizations could be placed on the generated map");
+        }
+
+        world.insert_resource(CivTerritories::from_world_map(&world_map));
+        world.insert_resource(world_map);
+        world.insert_resource(tech_tree);
+        world.insert_resource(unit_registry);
+        world.insert_resource(terrain_registry);
+        world.insert_resource(building_registry);
+        world.insert_resource(GameRng(rng));
+        world.insert_resource(GameConfig {
+            random_seed: config.seed,
+            ai_only: true,
+            max_units_per_tile: config.max_units_per_tile,
+            ..Default::default()
+        });
+        world.init_resource::<CurrentTurn>();
+        world.init_resource::<FogOfWarMaps>();
+        world.init_resource::<DiplomaticState>();
+        world.init_resource::<GlobalEconomy>();
+        world.init_resource::<TileOccupancy>();
+        world.init_resource::<PlayerActionsComplete>();
+        world.init_resource::<AiCoordinator>();
+        initialize_turn_order(&mut world);
+
+        Ok(Self {
+            world,
+            schedule: build_schedule(),
+            civilization_count: spawned_count as u32,
+        })
+    }
+
+    /// Advance the game until `turns` full turns have been processed.
+    pub fn run(&mut self, turns: u32) -> anyhow::Result<RunReport> {
+        let started = Instant::now();
+        let start_turn = self.current_turn();
+        let target_turn = start_turn + turns;
+        let max_frames = turns
+            .saturating_mul(self.civilization_count + 1)
+            .saturating_mul(MAX_FRAMES_PER_CIV_TURN);
+
+        let mut frames = 0;
+        while self.current_turn() < target_turn {
+            if frames >= max_frames {
+                bail!(
+                    "simulation stalled on turn {} after {} frames",
+                    self.current_turn(),
+                    frames
+                );
+            }
+
+            self.world.write_message(RequestTurnAdvance);
+            self.schedule.run(&mut self.world);
+            frames += 1;
+        }
+
+        Ok(RunReport {
+            turns_completed: self.current_turn() - start_turn,
+            frames,
+            elapsed: started.elapsed(),
+        })
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        &mut self.world
+    }
+
+    fn current_turn(&self) -> u32 {
+        self.world.resource::<CurrentTurn>().0
+    }
+}
+
+fn register_messages(world: &mut World) {
+    MessageRegistry::register_message::<RequestTurnAdvance>(world);
+    MessageRegistry::register_message::<ProcessAITurn>(world);
+    MessageRegistry::register_message::<AITurnComplete>(world);
+    MessageRegistry::register_message::<AllAITurnsComplete>(world);
+    MessageRegistry::register_message::<StartPlayerTurn>(world);
+    MessageRegistry::register_message::<ProductionUpdated>(world);
+    MessageRegistry::register_message::<AttackRequested>(world);
+    MessageRegistry::register_message::<CombatResolved>(world);
+    MessageRegistry::register_message::<RangedAttackRequested>(world);
+    MessageRegistry::register_message::<RangedAttackResolved>(world);
+    MessageRegistry::register_message::<CityCaptured>(world);
+    MessageRegistry::register_message::<TechnologyResearched>(world);
+    MessageRegistry::register_message::<CapitalEvolved>(world);
+    MessageRegistry::register_message::<CityFounded>(world);
+    MessageRegistry::register_message::<PopulationChanged>(world);
+    MessageRegistry::register_message::<TradeRoutePillaged>(world);
+    MessageRegistry::register_message::<EconomicEventStarted>(world);
+    MessageRegistry::register_message::<UnitLostToAttrition>(world);
+    MessageRegistry::register_message::<UnitMutinied>(world);
+    MessageRegistry::register_message::<UnitPromoted>(world);
+    MessageRegistry::register_message::<UnitLostAtSea>(world);
+}
+
+/// Same ordering the frontend uses: civilizations act in id order, starting with the lowest.
+fn initialize_turn_order(world: &mut World) {
+    let mut civ_ids: Vec<CivId> = world
+        .query::<&Civilization>()
+        .iter(world)
+        .map(|civ| civ.id)
+        .collect();
+    civ_ids.sort_by_key(|id| id.0);
+
+    let turn_order = TurnOrder::new(civ_ids);
+    if let Some(first_civ) = turn_order.current_civ() {
+        world.insert_resource(TurnPhase::WaitingForNextTurn {
+            next_civ: first_civ,
+        });
+    }
+    world.insert_resource(turn_order);
+}
+
+/// The subset of the frontend's core simulation systems that does not touch player input.
+fn build_schedule() -> Schedule {
+    let mut schedule = Schedule::default();
+    schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    schedule.add_systems(
+        (
+            core_sim::spawn_action_queues_for_new_civilizations,
+            core_sim::initialize_diplomatic_relations,
+            core_sim::process_civilization_action_queues,
+            core_sim::initialize_production_queues,
+            (
+                core_sim::update_tile_occupancy,
+                core_sim::convert_hostile_moves_into_attacks,
+            )
+                .chain(),
+            (
+                core_sim::resolve_attack_requests,
+                core_sim::resolve_ranged_attacks,
+            )
+                .chain(),
+            (
+                core_sim::follow_movement_orders,
+                core_sim::carry_embarked_units,
+            )
+                .chain(),
+            core_sim::advance_settlers,
+            core_sim::handle_turn_advance_requests,
+            generate_ai_decisions,
+            core_sim::handle_ai_turn_processing,
+            core_sim::handle_ai_turn_completion,
+            core_sim::update_diplomacy,
+            (
+                core_sim::resolve_sieges,
+                core_sim::update_territory,
+                core_sim::update_resource_access,
+                core_sim::update_unit_logistics,
+                core_sim::update_unit_loyalty,
+                core_sim::promote_ai_units,
+                core_sim::heal_promoted_units,
+                core_sim::grow_populations,
+                core_sim::update_market,
+                core_sim::update_trade_routes,
+            )
+                .chain(),
+            core_sim::update_civilization_economies,
+            core_sim::advance_research,
+            core_sim::evolve_capitals,
+            core_sim::handle_turn_transition_complete,
+            core_sim::update_fog_of_war,
+            message_update_system,
+        )
+            .chain(),
+    );
+    schedule
+}
+
+/// Headless counterpart of the frontend's `generate_ai_decisions_on_ai_turn`
+fn generate_ai_decisions(
+    mut ai_turn_events: MessageReader<ProcessAITurn>,
+    mut coordinator: ResMut<AiCoordinator>,
+    action_queues: Query<(&mut ActionQueue, &CivId)>,
+    civs: Query<&Civilization, Without<PlayerControlled>>,
+    territories: Query<(&Territory, &Position)>,
+    world_map: Res<WorldMap>,
+    global_economy: Res<GlobalEconomy>,
+    current_turn: Res<CurrentTurn>,
+) {
+    if ai_turn_events.read().last().is_none() {
+        return;
+    }
+
+    let civilizations: HashMap<CivId, CivilizationData> = civs
+        .iter()
+        .map(|civilization| {
+            let civ_data = CivilizationData {
+                civilization: civilization.clone(),
+                cities: Vec::new(),
+                territories: territories
+                    .iter()
+                    .filter(|(territory, _)| territory.owner == civilization.id)
+                    .map(|(territory, position)| (*position, territory.clone()))
+                    .collect(),
+                diplomatic_relations: Vec::new(),
+            };
+            (civilization.id, civ_data)
+        })
+        .collect();
+
+    let game_state = GameState {
+        turn: current_turn.0,
+        civilizations,
+        current_player: None,
+        resource_sites: world_map.unclaimed_resource_sites(),
+        resource_prices: global_economy.resource_prices.clone(),
+    };
+
+    let ai_decisions: Vec<(CivId, Vec<AIAction>)> = coordinator
+        .0
+        .generate_turn_decisions(&game_state)
+        .into_iter()
+        .collect();
+
+    core_sim::populate_action_queues_from_ai_decisions(ai_decisions, action_queues, current_turn);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::defaults;
+    use core_sim::MilitaryUnit;
+
+    const SMOKE_TEST_SEED: u64 = 42;
+    const SMOKE_TEST_TURNS: u32 = 3;
+
+    fn data_path(relative: &str) -> String {
+        format!("{}/../{}", env!("CARGO_MANIFEST_DIR"), relative)
+    }
+
+    fn smoke_test_config() -> SimulationConfig {
+        use core_sim::constants::{
+            buildings::BUILDINGS_DATA_PATH, civilization_management::CIVILIZATIONS_DATA_PATH,
+            occupancy, research::TECHNOLOGIES_DATA_PATH, terrain::TERRAIN_DATA_PATH,
+            units::UNITS_DATA_PATH,
+        };
+
+        SimulationConfig {
+            seed: SMOKE_TEST_SEED,
+            total_civilizations: defaults::CIVILIZATIONS,
+            turns: SMOKE_TEST_TURNS,
+            map_width: defaults::MAP_WIDTH,
+            map_height: defaults::MAP_HEIGHT,
+            max_units_per_tile: occupancy::DEFAULT_MAX_UNITS_PER_TILE,
+            civilization_data_path: data_path(CIVILIZATIONS_DATA_PATH),
+            technologies_data_path: data_path(TECHNOLOGIES_DATA_PATH),
+            units_data_path: data_path(UNITS_DATA_PATH),
+            terrain_data_path: data_path(TERRAIN_DATA_PATH),
+            buildings_data_path: data_path(BUILDINGS_DATA_PATH),
+        }
+    }
+
+    fn unit_positions(world: &mut World) -> Vec<(CivId, Position)> {
+        let mut query = world.query::<(&MilitaryUnit, &Position)>();
+        let mut positions: Vec<_> = query
+            .iter(world)
+            .map(|(unit, position)| (unit.owner, *position))
+            .collect();
+        positions.sort_by_key(|(owner, position)| (owner.0, position.y, position.x));
+        positions
+    }
+
+    #[test]
+    fn test_seeded_run_completes_turns() {
+        let config = smoke_test_config();
+        let mut simulation = Simulation::new(&config).expect("simulation should set up");
+        let report = simulation
+            .run(config.turns)
+            .expect("simulation should not stall");
+
+        assert_eq!(report.turns_completed, SMOKE_TEST_TURNS);
+        let world = simulation.world_mut();
+        let civilizations = world.query::<&Civilization>().iter(world).count();
+        assert_eq!(civilizations, defaults::CIVILIZATIONS as usize);
+    }
+
+    #[test]
+    fn test_same_seed_replays_the_same_game() {
+        let config = smoke_test_config();
+        let mut first = Simulation::new(&config).expect("simulation should set up");
+        let mut second = Simulation::new(&config).expect("simulation should set up");
+        first
+            .run(config.turns)
+            .expect("simulation should not stall");
+        second
+            .run(config.turns)
+            .expect("simulation should not stall");
+
+        let first_units = unit_positions(first.world_mut());
+        let second_units = unit_positions(second.world_mut());
+        assert!(!first_units.is_empty());
+        assert_eq!(first_units, second_units);
+    }
+}
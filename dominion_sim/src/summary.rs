@@ -0,0 +1,143 @@
+//! End-of-run report printed by the headless runner
+
+use crate::simulation::{RunReport, SimulationConfig};
+use bevy_ecs::prelude::*;
+use core_sim::{
+    resources::CurrentTurn, Capital, City, CivId, Civilization, MilitaryUnit, WorldMap,
+};
+use std::collections::HashMap;
+
+/// Final standing of a single civilization
+struct CivSummary {
+    id: CivId,
+    name: String,
+    gold: f32,
+    income: f32,
+    production: f32,
+    known_technologies: usize,
+    cities: usize,
+    population: u32,
+    units: usize,
+    military_strength: f32,
+    territory: usize,
+    resources: usize,
+    trade_routes: usize,
+    capital_age: Option<String>,
+}
+
+pub fn print_summary(config: &SimulationConfig, report: &RunReport, world: &mut World) {
+    let summaries = collect_civ_summaries(world);
+    let final_turn = world.resource::<CurrentTurn>().0;
+
+    println!("=== Dominion Earth headless simulation ===");
+    println!(
+        "Seed: {}  Map: {}x{}  Civilizations: {}",
+        config.seed,
+        config.map_width,
+        config.map_height,
+        summaries.len()
+    );
+    println!(
+        "Simulated {} turns (now turn {}) in {} frames, {:.2?}",
+        report.turns_completed, final_turn, report.frames, report.elapsed
+    );
+    println!();
+    println!(
+        "{:<4} {:<20} {:>9} {:>8} {:>8} {:>6} {:>7} {:>7} {:>6} {:>9} {:>6} {:>4} {:>6}  Capital",
+        "ID",
+        "Civilization",
+        "Gold",
+        "Income",
+        "Prod",
+        "Techs",
+        "Cities",
+        "Pop",
+        "Units",
+        "Strength",
+        "Tiles",
+        "Res",
+        "Routes",
+    );
+
+    for civ in &summaries {
+        println!(
+            "{:<4} {:<20} {:>9.1} {:>8.1} {:>8.1} {:>6} {:>7} {:>7} {:>6} {:>9.1} {:>6} {:>4} {:>6}  {}",
+            civ.id.0,
+            civ.name,
+            civ.gold,
+            civ.income,
+            civ.production,
+            civ.known_technologies,
+            civ.cities,
+            civ.population,
+            civ.units,
+            civ.military_strength,
+            civ.territory,
+            civ.resources,
+            civ.trade_routes,
+            civ.capital_age.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn collect_civ_summaries(world: &mut World) -> Vec<CivSummary> {
+    let mut city_totals: HashMap<CivId, (usize, u32)> = HashMap::new();
+    for city in world.query::<&City>().iter(world) {
+        let totals = city_totals.entry(city.owner).or_default();
+        totals.0 += 1;
+        totals.1 += city.population;
+    }
+
+    let mut unit_totals: HashMap<CivId, (usize, f32)> = HashMap::new();
+    for unit in world.query::<&MilitaryUnit>().iter(world) {
+        let totals = unit_totals.entry(unit.owner).or_default();
+        totals.0 += 1;
+        totals.1 += unit.effective_attack() + unit.effective_defense();
+    }
+
+    let capital_ages: HashMap<CivId, String> = world
+        .query::<&Capital>()
+        .iter(world)
+        .map(|capital| (capital.owner, format!("{:?}", capital.age)))
+        .collect();
+
+    let territory_counts = count_owned_tiles(world.resource::<WorldMap>());
+
+    let mut summaries: Vec<CivSummary> = world
+        .query::<&Civilization>()
+        .iter(world)
+        .map(|civ| {
+            let (units, military_strength) = unit_totals.get(&civ.id).copied().unwrap_or_default();
+            let (cities, population) = city_totals.get(&civ.id).copied().unwrap_or_default();
+            CivSummary {
+                id: civ.id,
+                name: civ.name.clone(),
+                gold: civ.economy.gold,
+                income: civ.economy.income,
+                production: civ.economy.production,
+                known_technologies: civ.technologies.known.values().filter(|k| **k).count(),
+                cities,
+                population,
+                units,
+                military_strength,
+                territory: territory_counts.get(&civ.id).copied().unwrap_or_default(),
+                resources: civ.economy.resources.len(),
+                trade_routes: civ.economy.trade_routes.len(),
+                capital_age: capital_ages.get(&civ.id).cloned(),
+            }
+        })
+        .collect();
+
+    summaries.sort_by_key(|civ| civ.id.0);
+    summaries
+}
+
+fn count_owned_tiles(world_map: &WorldMap) -> HashMap<CivId, usize> {
+    let mut counts = HashMap::new();
+    for tile in world_map.tiles.iter().flatten() {
+        if let Some(owner) = tile.owner {
+            *counts.entry(owner).or_default() += 1;
+        }
+    }
+    counts
+}
@@ -13,12 +13,12 @@ fn test_ai_action_creation() {
             priority: 0.8,
         },
         AIAction::BuildUnit {
-            unit_type: UnitType::Infantry,
+            unit_type: UnitType::new("Infantry"),
             position: Position { x: 5, y: 5 },
             priority: 0.7,
         },
         AIAction::BuildBuilding {
-            building_type: BuildingType::Market,
+            building_type: BuildingType::new("Market"),
             position: Position { x: 3, y: 3 },
             priority: 0.6,
         },
@@ -65,6 +65,8 @@ fn test_ai_planner_integration() {
         turn: 1,
         civilizations: std::collections::HashMap::new(),
         current_player: CivId(0),
+        resource_sites: Vec::new(),
+        resource_prices: std::collections::HashMap::new(),
     };
     
     // Test that the coordinator can process empty state without errors